@@ -0,0 +1,211 @@
+//! Fixed-point prices, shared by the AMM, autocrat and the launchpad so that
+//! they all agree on how a price is scaled and rounded.
+//!
+//! A [`Price`] is the number of quote units per base unit, scaled by 1e12.
+//! Because it's in units rather than tokens, you can't simply divide by 1e12
+//! to get a price you can display in a UI when the base and quote mints have
+//! different decimals. Use [`Price::to_ui`] for that.
+//!
+//! Everything rounds down.
+
+pub const PRICE_SCALE: u128 = 1_000_000_000_000;
+pub const MAX_BPS: u16 = 10_000;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Price(u128);
+
+impl Price {
+    pub const ZERO: Price = Price(0);
+    /// The highest price that pools with `u64` reserves can have, which is
+    /// `u64::MAX` quote units for a single base unit.
+    pub const MAX: Price = Price(u64::MAX as u128 * PRICE_SCALE);
+
+    /// Wraps a price that's already scaled by 1e12, like the ones stored in
+    /// the AMM's oracle.
+    pub const fn from_scaled(scaled: u128) -> Self {
+        Self(scaled)
+    }
+
+    pub const fn to_scaled(self) -> u128 {
+        self.0
+    }
+
+    /// The price at which `base_amount` units trade for `quote_amount`
+    /// units, or `None` if `base_amount` is zero.
+    pub fn from_amounts(quote_amount: u64, base_amount: u64) -> Option<Self> {
+        // can't overflow because u64::MAX * 1e12 is ~1e31 and a u128 can
+        // hold ~3e38
+        (quote_amount as u128 * PRICE_SCALE)
+            .checked_div(base_amount as u128)
+            .map(Self)
+    }
+
+    /// Converts a price in Q32.32 fixed point, like the ones in Raydium's
+    /// oracles, to a price scaled by 1e12.
+    pub const fn from_x32(price_x32: u128) -> Self {
+        const FRACTION_MASK: u128 = u32::MAX as u128;
+
+        // splitting off the fraction first means that this can't overflow
+        // for any price that's at most `u64::MAX` units
+        let integer = (price_x32 >> 32).saturating_mul(PRICE_SCALE);
+        let fraction = ((price_x32 & FRACTION_MASK) * PRICE_SCALE) >> 32;
+
+        Self(integer.saturating_add(fraction))
+    }
+
+    /// Converts a UI price, i.e. quote tokens per base token scaled by 1e12,
+    /// to a price in units.
+    ///
+    /// For example, if META is $100, USDC has 6 decimals and META has 9, a
+    /// UI price of 100 * 1e12 is 0.1 USDC units per META unit, or 1e11.
+    pub fn from_ui(ui_price: u128, base_decimals: u8, quote_decimals: u8) -> Option<Self> {
+        rescale(ui_price, quote_decimals, base_decimals).map(Self)
+    }
+
+    /// Converts this price to quote tokens per base token, scaled by 1e12.
+    /// This is `price * 10^(base_decimals - quote_decimals)`.
+    pub fn to_ui(self, base_decimals: u8, quote_decimals: u8) -> Option<u128> {
+        rescale(self.0, base_decimals, quote_decimals)
+    }
+
+    /// How many quote units `base_amount` base units are worth at this price.
+    pub fn checked_mul_amount(self, base_amount: u128) -> Option<u128> {
+        base_amount
+            .checked_mul(self.0)
+            .map(|value| value / PRICE_SCALE)
+    }
+
+    /// How many base units `quote_amount` quote units buy at this price, or
+    /// `None` if the price is zero.
+    pub fn checked_div_amount(self, quote_amount: u128) -> Option<u128> {
+        quote_amount.checked_mul(PRICE_SCALE)?.checked_div(self.0)
+    }
+
+    /// This price raised by `bps` basis points, saturating at `u128::MAX`.
+    pub fn saturating_add_bps(self, bps: u16) -> Self {
+        // for any price up to `MAX`, this can't saturate: MAX_BPS + bps is
+        // at most ~7.5e4, `MAX` is ~1e31 and a u128 can hold ~3e38
+        let numerator = MAX_BPS.saturating_add(bps) as u128;
+
+        Self(self.0.saturating_mul(numerator) / MAX_BPS as u128)
+    }
+
+    /// Whether this price is more than `bps` basis points above `other`.
+    pub fn exceeds_by_bps(self, other: Price, bps: u16) -> bool {
+        self > other.saturating_add_bps(bps)
+    }
+}
+
+/// Multiplies `value` by `10^(multiply_decimals - divide_decimals)`.
+fn rescale(value: u128, multiply_decimals: u8, divide_decimals: u8) -> Option<u128> {
+    if multiply_decimals >= divide_decimals {
+        value.checked_mul(10u128.checked_pow((multiply_decimals - divide_decimals) as u32)?)
+    } else {
+        Some(value / 10u128.checked_pow((divide_decimals - multiply_decimals) as u32)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn ui_conversion() {
+        // 400 USDC and 4 META, from the AMM's TWAP docs
+        let price = Price::from_amounts(400_000_000, 4_000_000_000).unwrap();
+        assert_eq!(price.to_scaled(), 100_000_000_000);
+
+        assert_eq!(price.to_ui(9, 6), Some(100 * PRICE_SCALE));
+        assert_eq!(Price::from_ui(100 * PRICE_SCALE, 9, 6), Some(price));
+
+        assert_eq!(Price::from_amounts(1, 0), None);
+        assert_eq!(price.to_ui(255, 0), None);
+    }
+
+    #[test]
+    fn x32_conversion() {
+        assert_eq!(Price::from_x32(1 << 32), Price::from_scaled(PRICE_SCALE));
+        assert_eq!(
+            Price::from_x32(3 << 31),
+            Price::from_scaled(PRICE_SCALE * 3 / 2)
+        );
+        // 0.1 isn't exact in Q32.32
+        assert_eq!(
+            Price::from_x32((1 << 32) / 10),
+            Price::from_scaled(99_999_999_860)
+        );
+        assert_eq!(Price::from_x32((u64::MAX as u128) << 32), Price::MAX);
+    }
+
+    #[test]
+    fn bps() {
+        let price = Price::from_scaled(PRICE_SCALE);
+
+        assert_eq!(
+            price.saturating_add_bps(300),
+            Price::from_scaled(PRICE_SCALE * 103 / 100)
+        );
+        assert!(!Price::from_scaled(PRICE_SCALE * 103 / 100).exceeds_by_bps(price, 300));
+        assert!(Price::from_scaled(PRICE_SCALE * 103 / 100 + 1).exceeds_by_bps(price, 300));
+
+        assert_eq!(
+            Price::from_scaled(u128::MAX).saturating_add_bps(1),
+            Price::from_scaled(u128::MAX / MAX_BPS as u128)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn from_amounts_is_monotonic(quote in any::<u64>(), base in 1..=u64::MAX, extra in any::<u64>()) {
+            let price = Price::from_amounts(quote, base).unwrap();
+
+            prop_assert!(price <= Price::MAX);
+            prop_assert!(Price::from_amounts(quote.saturating_add(extra), base).unwrap() >= price);
+            prop_assert!(Price::from_amounts(quote, base.saturating_add(extra)).unwrap() <= price);
+        }
+
+        #[test]
+        fn amounts_round_down(quote in any::<u64>(), base in 1..=u64::MAX) {
+            let price = Price::from_amounts(quote, base).unwrap();
+
+            // trading at the price of a trade never gives back more than it
+            prop_assert!(price.checked_mul_amount(base as u128).unwrap() <= quote as u128);
+
+            if price > Price::ZERO {
+                let base_out = price.checked_div_amount(quote as u128).unwrap();
+                prop_assert!(price.checked_mul_amount(base_out).unwrap() <= quote as u128);
+            }
+        }
+
+        #[test]
+        fn ui_round_trips(scaled in 0..=Price::MAX.to_scaled(), base_decimals in 0..=12u8, quote_decimals in 0..=12u8) {
+            let price = Price::from_scaled(scaled);
+            let Some(ui_price) = price.to_ui(base_decimals, quote_decimals) else {
+                // UI prices can overflow when `to_ui` multiplies a high price
+                prop_assert!(base_decimals > quote_decimals);
+                return Ok(());
+            };
+            let round_tripped = Price::from_ui(ui_price, base_decimals, quote_decimals).unwrap();
+
+            if base_decimals >= quote_decimals {
+                prop_assert_eq!(round_tripped, price);
+            } else {
+                // only the digits that `to_ui` divided away are lost
+                let lost_precision = 10u128.pow((quote_decimals - base_decimals) as u32);
+                prop_assert!(round_tripped <= price);
+                prop_assert!(price.to_scaled() - round_tripped.to_scaled() < lost_precision);
+            }
+        }
+
+        #[test]
+        fn add_bps_is_monotonic(scaled in 0..=Price::MAX.to_scaled(), bps in any::<u16>(), extra in any::<u16>()) {
+            let price = Price::from_scaled(scaled);
+
+            prop_assert_eq!(price.saturating_add_bps(0), price);
+            prop_assert!(price.saturating_add_bps(bps) >= price);
+            prop_assert!(price.saturating_add_bps(bps.saturating_add(extra)) >= price.saturating_add_bps(bps));
+            prop_assert!(!price.exceeds_by_bps(price, bps));
+        }
+    }
+}
@@ -38,4 +38,78 @@ pub enum AmmError {
     ConstantProductInvariantFailed,
     #[msg("Casting has caused an overflow")]
     CastingOverflow,
+    #[msg("An AMM can't be closed while any of its LP tokens are outstanding")]
+    LpTokensOutstanding,
+    #[msg("This account isn't an AMM")]
+    InvalidAmm,
+    #[msg("This AMM has already been migrated")]
+    AmmAlreadyMigrated,
+    #[msg("The protocol fee can be at most `MAX_PROTOCOL_FEE_BPS`")]
+    InvalidProtocolFee,
+    #[msg("An AMM can't be closed until its protocol fees have been collected")]
+    ProtocolFeesUncollected,
+    #[msg("This AMM is paused")]
+    AmmPaused,
+    #[msg("This AMM isn't paused")]
+    AmmNotPaused,
+    #[msg("This AMM doesn't have an admin, or the signer isn't its admin")]
+    InvalidAdmin,
+    #[msg("This swap would move the price too far from the oracle's last observation")]
+    PriceImpactTooHigh,
+    #[msg("This order book already has `MAX_LIMIT_ORDERS` orders")]
+    OrderBookFull,
+    #[msg("No limit order with this id is in the order book")]
+    LimitOrderNotFound,
+    #[msg("Limit orders need a non-zero input amount and limit price")]
+    InvalidLimitOrder,
+    #[msg("None of the order book's orders could be filled")]
+    NoOrdersFilled,
+    #[msg("Limit orders must be at least `MIN_LIMIT_ORDER_BPS` of the AMM's input reserve")]
+    LimitOrderTooSmall,
+    #[msg("This user already has `MAX_LIMIT_ORDERS_PER_OWNER` orders in this order book")]
+    TooManyLimitOrders,
+    #[msg("Limit orders must expire within `MAX_LIMIT_ORDER_LIFETIME_SLOTS`")]
+    InvalidLimitOrderExpiry,
+    #[msg("Only the owner can remove a limit order before it expires")]
+    LimitOrderNotExpired,
+    #[msg(
+        "Each order left in the order book needs its owner's associated token account passed in"
+    )]
+    MissingOrderOwnerAccount,
+    #[msg("This AMM is in batch auction mode, so orders have to go through batches")]
+    BatchAuctionMode,
+    #[msg("This AMM isn't in batch auction mode")]
+    NotInBatchAuctionMode,
+    #[msg("Batched AMMs need a non-zero window and can't have a price impact limit")]
+    InvalidBatchAuctionConfig,
+    #[msg("Orders can only be submitted to the current batch")]
+    BatchClosed,
+    #[msg("This batch's window hasn't ended yet")]
+    BatchNotEnded,
+    #[msg("This batch has already been cleared")]
+    BatchAlreadyCleared,
+    #[msg("This batch hasn't been cleared yet")]
+    BatchNotCleared,
+    #[msg("An AMM can't be closed while any of its batch orders are unclaimed")]
+    BatchOrdersUnclaimed,
+    #[msg("This batch already has `MAX_BATCH_ORDERS` orders")]
+    BatchFull,
+    #[msg("Clearing a batch needs each of its orders passed in exactly once")]
+    InvalidBatchOrders,
+    #[msg("This AMM already has a flash loan outstanding")]
+    FlashLoanOutstanding,
+    #[msg("This AMM doesn't have a flash loan outstanding")]
+    NoFlashLoan,
+    #[msg("Flash loans must borrow something and at most the AMM's reserves")]
+    InvalidFlashLoanAmount,
+    #[msg("A flash loan must be followed by a `repay_flash_loan` for the same AMM")]
+    FlashLoanNotRepaid,
+    #[msg("The AMM's vaults don't hold its reserves and protocol fees")]
+    VaultsUndercollateralized,
+    #[msg("This position is locked")]
+    PositionLocked,
+    #[msg("This position isn't locked")]
+    PositionNotLocked,
+    #[msg("Only the position's locker can unlock it")]
+    InvalidLocker,
 }
@@ -13,21 +13,36 @@ pub struct CommonFields {
     pub oracle_last_price: u128,
     pub oracle_last_observation: u128,
     pub oracle_aggregator: u128,
+    pub cumulative_base_volume: u128,
+    pub cumulative_quote_volume: u128,
+    pub cumulative_base_fees: u128,
+    pub cumulative_quote_fees: u128,
+    pub swap_count: u64,
     pub seq_num: u64,
 }
 
 impl CommonFields {
     pub fn new(clock: &Clock, user: Pubkey, amm: &Account<'_, Amm>) -> Self {
+        Self::from_amm(clock, user, amm.key(), amm)
+    }
+
+    /// For when we only have the AMM's data, not its `Account`
+    pub fn from_amm(clock: &Clock, user: Pubkey, amm_key: Pubkey, amm: &Amm) -> Self {
         Self {
             slot: clock.slot,
             unix_timestamp: clock.unix_timestamp,
             user,
-            amm: amm.key(),
+            amm: amm_key,
             post_base_reserves: amm.base_amount,
             post_quote_reserves: amm.quote_amount,
             oracle_last_price: amm.oracle.last_price,
             oracle_last_observation: amm.oracle.last_observation,
             oracle_aggregator: amm.oracle.aggregator,
+            cumulative_base_volume: amm.cumulative_base_volume,
+            cumulative_quote_volume: amm.cumulative_quote_volume,
+            cumulative_base_fees: amm.cumulative_base_fees,
+            cumulative_quote_fees: amm.cumulative_quote_fees,
+            swap_count: amm.swap_count,
             seq_num: amm.seq_num,
         }
     }
@@ -61,6 +76,39 @@ pub struct RemoveLiquidityEvent {
     pub quote_amount: u64,
 }
 
+#[event]
+pub struct ZapInEvent {
+    pub common: CommonFields,
+    pub swap_type: SwapType,
+    pub input_amount: u64,
+    pub min_lp_tokens: u64,
+    /// How much of the input was swapped into the other token
+    pub swap_input_amount: u64,
+    pub swap_output_amount: u64,
+    pub input_deposited: u64,
+    pub output_deposited: u64,
+    /// Swap output that didn't fit in the deposit after rounding, which is
+    /// sent back to the user
+    pub output_refunded: u64,
+    pub lp_tokens_minted: u64,
+}
+
+#[event]
+pub struct ZapOutEvent {
+    pub common: CommonFields,
+    pub swap_type: SwapType,
+    pub lp_tokens_burned: u64,
+    pub min_output_amount: u64,
+    pub base_withdrawn: u64,
+    pub quote_withdrawn: u64,
+    /// How much of what was withdrawn was swapped into the other token
+    pub swap_input_amount: u64,
+    pub swap_output_amount: u64,
+    /// What the user received, which is the withdrawn amount of the token they
+    /// kept plus the swap's output
+    pub output_amount: u64,
+}
+
 #[event]
 pub struct CreateAmmEvent {
     pub common: CommonFields,
@@ -71,9 +119,200 @@ pub struct CreateAmmEvent {
     pub quote_mint: Pubkey,
     pub vault_ata_base: Pubkey,
     pub vault_ata_quote: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub admin: Option<Pubkey>,
+    pub max_price_impact_bps: Option<u16>,
+    pub batch_auction_slots: Option<u64>,
 }
 
 #[event]
 pub struct CrankThatTwapEvent {
     pub common: CommonFields,
 }
+
+#[event]
+pub struct CloseAmmEvent {
+    pub common: CommonFields,
+    pub leftover_base_amount: u64,
+    pub leftover_quote_amount: u64,
+    /// Orders that were still in the AMM's order book, which were paid out to
+    /// their owners
+    pub refunded_order_ids: Vec<u64>,
+}
+
+#[event]
+pub struct MigrateAmmEvent {
+    pub common: CommonFields,
+}
+
+#[event]
+pub struct CollectProtocolFeesEvent {
+    pub common: CommonFields,
+    pub protocol_fee_recipient: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct InitializeGlobalConfigEvent {
+    pub slot: u64,
+    pub unix_timestamp: i64,
+    pub upgrade_authority: Pubkey,
+    pub global_config: Pubkey,
+    pub fee_authority: Pubkey,
+    pub protocol_fee_recipient: Pubkey,
+    pub protocol_fee_bps: u16,
+}
+
+#[event]
+pub struct UpdateProtocolFeeEvent {
+    pub slot: u64,
+    pub unix_timestamp: i64,
+    pub user: Pubkey,
+    pub global_config: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub protocol_fee_recipient: Pubkey,
+    pub fee_authority: Pubkey,
+    pub seq_num: u64,
+}
+
+#[event]
+pub struct PauseAmmEvent {
+    pub common: CommonFields,
+}
+
+#[event]
+pub struct UnpauseAmmEvent {
+    pub common: CommonFields,
+    pub paused_slots: u64,
+}
+
+#[event]
+pub struct InitializeOrderBookEvent {
+    pub common: CommonFields,
+    pub order_book: Pubkey,
+}
+
+#[event]
+pub struct PlaceLimitOrderEvent {
+    pub common: CommonFields,
+    pub order_book: Pubkey,
+    pub order_id: u64,
+    pub swap_type: SwapType,
+    pub input_amount: u64,
+    pub limit_price: u128,
+    pub expires_at_slot: u64,
+}
+
+#[event]
+pub struct RemoveLimitOrderEvent {
+    pub common: CommonFields,
+    pub order_book: Pubkey,
+    pub order_id: u64,
+    pub filled: bool,
+    /// The order's output if it was filled, otherwise its input
+    pub amount: u64,
+}
+
+#[event]
+pub struct CancelExpiredLimitOrderEvent {
+    pub common: CommonFields,
+    pub order_book: Pubkey,
+    pub order_id: u64,
+    pub owner: Pubkey,
+    pub filled: bool,
+    /// What was paid out to the owner, which is the order's output if it was
+    /// filled and otherwise its input
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct FillOrdersEvent {
+    pub common: CommonFields,
+    pub order_book: Pubkey,
+    pub filled_order_ids: Vec<u64>,
+    pub settled_order_ids: Vec<u64>,
+    pub base_bounty: u64,
+    pub quote_bounty: u64,
+}
+
+#[event]
+pub struct SubmitBatchOrderEvent {
+    pub common: CommonFields,
+    pub batch: Pubkey,
+    pub batch_id: u64,
+    pub swap_type: SwapType,
+    pub input_amount: u64,
+    pub min_output_amount: u64,
+}
+
+#[event]
+pub struct ClearBatchEvent {
+    pub common: CommonFields,
+    pub batch: Pubkey,
+    pub batch_id: u64,
+    pub total_quote_in: u64,
+    pub total_base_in: u64,
+    pub clearing_price: u128,
+    pub base_owed: u64,
+    pub quote_owed: u64,
+    pub refunded_orders: Vec<Pubkey>,
+}
+
+#[event]
+pub struct ClaimBatchOrderEvent {
+    pub common: CommonFields,
+    pub batch: Pubkey,
+    pub clearing_price: u128,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub refunded: bool,
+}
+
+#[event]
+pub struct FlashLoanEvent {
+    pub common: CommonFields,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub base_fee: u64,
+    pub quote_fee: u64,
+}
+
+#[event]
+pub struct RepayFlashLoanEvent {
+    pub common: CommonFields,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub base_fee: u64,
+    pub quote_fee: u64,
+}
+
+#[event]
+pub struct CreatePositionEvent {
+    pub common: CommonFields,
+    pub position: Pubkey,
+    pub nonce: u64,
+}
+
+#[event]
+pub struct ClosePositionEvent {
+    pub common: CommonFields,
+    pub position: Pubkey,
+    pub lp_tokens: u64,
+}
+
+#[event]
+pub struct LockPositionEvent {
+    pub common: CommonFields,
+    pub position: Pubkey,
+    pub locker: Pubkey,
+    pub lp_tokens: u64,
+}
+
+#[event]
+pub struct UnlockPositionEvent {
+    pub common: CommonFields,
+    pub position: Pubkey,
+    pub locker: Pubkey,
+}
@@ -21,13 +21,17 @@ impl AddOrRemoveLiquidity<'_> {
         let AddOrRemoveLiquidity {
             user,
             amm,
+            trader_record: _,
             lp_mint,
             user_lp_account,
+            position,
+            position_lp_account,
             user_base_account,
             user_quote_account,
             vault_ata_base,
             vault_ata_quote,
             token_program,
+            system_program: _,
             program: _,
             event_authority: _,
         } = ctx.accounts;
@@ -49,6 +53,16 @@ impl AddOrRemoveLiquidity<'_> {
             AmmError::InsufficientBalance
         );
 
+        require!(!amm.is_paused(), AmmError::AmmPaused);
+
+        // when depositing into a position, the position holds the LP tokens
+        // and records what was deposited for them
+        let lp_account = match (&position, position_lp_account) {
+            (Some(_), Some(position_lp_account)) => position_lp_account.to_account_info(),
+            (None, None) => user_lp_account.to_account_info(),
+            _ => return err!(ErrorCode::ConstraintAccountIsNone),
+        };
+
         amm.update_twap(Clock::get()?.slot)?;
 
         // airlifted from uniswap v1:
@@ -57,7 +71,7 @@ impl AddOrRemoveLiquidity<'_> {
         require!(max_base_amount > 0, AmmError::ZeroLiquidityToAdd);
         require!(quote_amount > 0, AmmError::ZeroLiquidityToAdd);
 
-        let total_lp_supply = lp_mint.supply;
+        let total_lp_supply = amm.total_lp_supply(lp_mint.supply);
 
         let (lp_tokens_to_mint, base_amount) = if total_lp_supply > 0 {
             require!(min_lp_tokens > 0, AmmError::ZeroMinLpTokens);
@@ -93,7 +107,12 @@ impl AddOrRemoveLiquidity<'_> {
 
             let base_amount = max_base_amount;
 
-            let initial_lp_tokens = quote_amount;
+            // we lock some of the initial LP tokens so that the pool can never
+            // be drained, which would stop its TWAP and let the next first
+            // depositor set the LP token's value
+            amm.locked_lp_tokens = MINIMUM_LIQUIDITY;
+
+            let initial_lp_tokens = quote_amount - MINIMUM_LIQUIDITY;
 
             (initial_lp_tokens, base_amount)
         };
@@ -109,7 +128,7 @@ impl AddOrRemoveLiquidity<'_> {
                 token_program.to_account_info(),
                 MintTo {
                     mint: lp_mint.to_account_info(),
-                    to: user_lp_account.to_account_info(),
+                    to: lp_account,
                     authority: amm.to_account_info(),
                 },
                 signer,
@@ -137,6 +156,11 @@ impl AddOrRemoveLiquidity<'_> {
         amm.seq_num += 1;
 
         let clock = Clock::get()?;
+
+        if let Some(position) = position {
+            position.record_deposit(lp_tokens_to_mint, base_amount, quote_amount, clock.slot);
+        }
+
         emit_cpi!(AddLiquidityEvent {
             common: CommonFields::new(&clock, user.key(), amm),
             lp_tokens_minted: lp_tokens_to_mint,
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::events::{CancelExpiredLimitOrderEvent, CommonFields};
+use crate::generate_order_book_seeds;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CancelExpiredLimitOrderArgs {
+    pub order_id: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelExpiredLimitOrder<'info> {
+    pub amm: Account<'info, Amm>,
+    #[account(mut, has_one = amm)]
+    pub order_book: Box<Account<'info, OrderBook>>,
+    /// CHECK: checked against the order's owner
+    pub owner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = order_book,
+    )]
+    pub order_book_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = order_book,
+    )]
+    pub order_book_quote_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl CancelExpiredLimitOrder<'_> {
+    pub fn validate(&self, args: &CancelExpiredLimitOrderArgs) -> Result<()> {
+        let order = self.order_book.orders[self.order_book.find_order(args.order_id)?];
+
+        require_keys_eq!(order.owner, self.owner.key(), ErrorCode::ConstraintOwner);
+
+        require!(
+            order.is_expired(Clock::get()?.slot),
+            AmmError::LimitOrderNotExpired
+        );
+
+        Ok(())
+    }
+
+    /// Removes an expired order from the book, sending its owner the order's
+    /// output if it was filled or its input if it wasn't. Anyone can do this,
+    /// so that orders nobody removes don't take up the book forever.
+    pub fn handle(ctx: Context<Self>, args: CancelExpiredLimitOrderArgs) -> Result<()> {
+        let CancelExpiredLimitOrder {
+            amm,
+            order_book,
+            owner,
+            owner_base_account,
+            owner_quote_account,
+            order_book_base_account,
+            order_book_quote_account,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let order_index = order_book.find_order(args.order_id)?;
+        let order = order_book.orders[order_index];
+
+        let (base_amount, quote_amount) = order.amounts_owed();
+
+        let seeds = generate_order_book_seeds!(order_book);
+
+        for (amount, from, to) in [
+            (base_amount, order_book_base_account, owner_base_account),
+            (quote_amount, order_book_quote_account, owner_quote_account),
+        ] {
+            if amount > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: from.to_account_info(),
+                            to: to.to_account_info(),
+                            authority: order_book.to_account_info(),
+                        },
+                        &[seeds],
+                    ),
+                    amount,
+                )?;
+            }
+        }
+
+        order_book.orders.remove(order_index);
+        order_book.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(CancelExpiredLimitOrderEvent {
+            common: CommonFields::new(&clock, Pubkey::default(), amm),
+            order_book: order_book.key(),
+            order_id: order.id,
+            owner: owner.key(),
+            filled: order.output_amount.is_some(),
+            base_amount,
+            quote_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::events::{ClaimBatchOrderEvent, CommonFields};
+use crate::generate_amm_seeds;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimBatchOrder<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut)]
+    pub amm: Account<'info, Amm>,
+    #[account(mut, has_one = amm)]
+    pub batch: Box<Account<'info, Batch>>,
+    #[account(
+        mut,
+        has_one = batch,
+        has_one = user,
+        close = user,
+    )]
+    pub batch_order: Box<Account<'info, BatchOrder>>,
+    /// CHECK: only receives the batch's rent once every order has claimed
+    #[account(mut, address = batch.payer)]
+    pub batch_payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = user,
+    )]
+    pub user_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = user,
+    )]
+    pub user_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_base: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_quote: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl ClaimBatchOrder<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.batch.clearing_price.is_some(),
+            AmmError::BatchNotCleared
+        );
+
+        Ok(())
+    }
+
+    /// Sends a batch order its output at the batch's clearing price, or its
+    /// input if it was left out of the batch. The last order to claim closes
+    /// the batch.
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let ClaimBatchOrder {
+            user,
+            amm,
+            batch,
+            batch_order,
+            batch_payer,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let clearing_price = batch.clearing_price.unwrap();
+
+        let (base_amount, quote_amount) = if batch_order.refunded {
+            // its input was never added to the reserves, so it's still in
+            // the vaults
+            (batch_order.base_in, batch_order.quote_in)
+        } else {
+            let (base_amount, quote_amount) = batch_order.get_output(clearing_price);
+
+            // orders can't be owed more than the batch as a whole
+            let base_amount = std::cmp::min(base_amount, batch.base_owed);
+            let quote_amount = std::cmp::min(quote_amount, batch.quote_owed);
+
+            batch.base_owed -= base_amount;
+            batch.quote_owed -= quote_amount;
+
+            (base_amount, quote_amount)
+        };
+
+        let seeds = generate_amm_seeds!(amm);
+
+        for (amount, vault_from, user_to) in [
+            (base_amount, vault_ata_base, user_base_account),
+            (quote_amount, vault_ata_quote, user_quote_account),
+        ] {
+            if amount > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: vault_from.to_account_info(),
+                            to: user_to.to_account_info(),
+                            authority: amm.to_account_info(),
+                        },
+                        &[seeds],
+                    ),
+                    amount,
+                )?;
+            }
+        }
+
+        batch.unclaimed_order_count -= 1;
+        amm.unclaimed_batch_orders -= 1;
+        amm.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(ClaimBatchOrderEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            batch: batch.key(),
+            clearing_price,
+            base_amount,
+            quote_amount,
+            refunded: batch_order.refunded,
+        });
+
+        if batch.unclaimed_order_count == 0 {
+            batch.close(batch_payer.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::events::{ClearBatchEvent, CommonFields};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClearBatch<'info> {
+    #[account(mut)]
+    pub amm: Account<'info, Amm>,
+    #[account(mut, has_one = amm)]
+    pub batch: Box<Account<'info, Batch>>,
+}
+
+impl ClearBatch<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require!(!self.amm.is_paused(), AmmError::AmmPaused);
+
+        require!(
+            self.batch.clearing_price.is_none(),
+            AmmError::BatchAlreadyCleared
+        );
+
+        require_gte!(
+            Clock::get()?.slot,
+            self.batch.end_slot,
+            AmmError::BatchNotEnded
+        );
+
+        Ok(())
+    }
+
+    /// Clears a batch once its window has ended, trading its imbalance
+    /// against the reserves and feeding its clearing price to the TWAP
+    /// oracle. Orders that wouldn't get their minimum output at the clearing
+    /// price are left out and refunded when they claim. Anyone can clear a
+    /// batch, but they have to pass in all of its orders as remaining
+    /// accounts.
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let ClearBatch {
+            amm,
+            batch,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let order_infos = ctx.remaining_accounts;
+
+        // otherwise a clearer could leave out orders that would be refunded
+        // and trade them anyway
+        require_eq!(
+            order_infos.len(),
+            batch.unclaimed_order_count as usize,
+            AmmError::InvalidBatchOrders
+        );
+
+        let mut orders = vec![];
+        for (i, order_info) in order_infos.iter().enumerate() {
+            require!(
+                order_info.owner == &crate::ID
+                    && order_info.is_writable
+                    && !order_infos[..i]
+                        .iter()
+                        .any(|other| other.key == order_info.key),
+                AmmError::InvalidBatchOrders
+            );
+
+            let order = BatchOrder::try_deserialize(&mut &order_info.try_borrow_data()?[..])?;
+
+            require_keys_eq!(order.batch, batch.key(), AmmError::InvalidBatchOrders);
+
+            orders.push(order);
+        }
+
+        let clock = Clock::get()?;
+
+        let BatchOrdersClearing {
+            clearing:
+                BatchClearing {
+                    price,
+                    base_owed,
+                    quote_owed,
+                },
+            total_quote_in,
+            total_base_in,
+        } = clear_batch_orders(amm, &mut orders)?;
+
+        let mut refunded_orders = vec![];
+        for (order_info, order) in order_infos.iter().zip(&orders) {
+            if order.refunded {
+                order.try_serialize(&mut &mut order_info.try_borrow_mut_data()?[..])?;
+                refunded_orders.push(order_info.key());
+            }
+        }
+
+        amm.record_observation(clock.slot, price)?;
+
+        batch.clearing_price = Some(price);
+        batch.base_owed = base_owed;
+        batch.quote_owed = quote_owed;
+
+        amm.swap_count += (orders.len() - refunded_orders.len()) as u64;
+        amm.seq_num += 1;
+
+        emit_cpi!(ClearBatchEvent {
+            common: CommonFields::new(&clock, Pubkey::default(), amm),
+            batch: batch.key(),
+            batch_id: batch.id,
+            total_quote_in,
+            total_base_in,
+            clearing_price: price,
+            base_owed,
+            quote_owed,
+            refunded_orders,
+        });
+
+        Ok(())
+    }
+}
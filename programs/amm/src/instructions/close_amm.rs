@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::state::*;
+use crate::{generate_amm_seeds, generate_order_book_seeds};
+
+use crate::events::{CloseAmmEvent, CommonFields};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseAmm<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = lp_mint,
+        has_one = payer,
+        close = payer,
+    )]
+    pub amm: Account<'info, Amm>,
+    /// CHECK: not every AMM has an order book, so we only load it if it
+    /// exists
+    #[account(mut, seeds = [ORDER_BOOK_SEED_PREFIX, amm.key().as_ref()], bump)]
+    pub order_book: UncheckedAccount<'info>,
+    /// Only needed if the order book has orders left to refund
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = order_book,
+    )]
+    pub order_book_base_account: Option<Box<Account<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = order_book,
+    )]
+    pub order_book_quote_account: Option<Box<Account<'info, TokenAccount>>>,
+    pub lp_mint: Box<Account<'info, Mint>>,
+    /// Receives the locked minimum liquidity and any base tokens left over
+    /// from rounding
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = payer,
+    )]
+    pub payer_base_account: Box<Account<'info, TokenAccount>>,
+    /// Receives the locked minimum liquidity and any quote tokens left over
+    /// from rounding
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = payer,
+    )]
+    pub payer_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_base: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_quote: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info, 'c: 'info> CloseAmm<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require_eq!(self.lp_mint.supply, 0, AmmError::LpTokensOutstanding);
+
+        require!(
+            self.amm.protocol_base_fees_owed == 0 && self.amm.protocol_quote_fees_owed == 0,
+            AmmError::ProtocolFeesUncollected
+        );
+
+        require_eq!(
+            self.amm.unclaimed_batch_orders,
+            0,
+            AmmError::BatchOrdersUnclaimed
+        );
+
+        Ok(())
+    }
+
+    /// Closes an AMM and its vaults once all of its liquidity has been
+    /// removed. The LP mint stays open, because the token program doesn't
+    /// allow closing mints.
+    ///
+    /// The reserves backing the locked LP tokens go to the payer, since
+    /// there's no longer a pool for them to keep alive.
+    ///
+    /// Orders can only be removed while the AMM exists, so any orders left
+    /// in its order book are paid out to their owners, whose associated
+    /// token accounts have to be passed in as remaining accounts.
+    pub fn handle(ctx: Context<'_, '_, 'c, 'info, Self>) -> Result<()> {
+        let CloseAmm {
+            payer,
+            amm,
+            order_book,
+            order_book_base_account,
+            order_book_quote_account,
+            lp_mint: _,
+            payer_base_account,
+            payer_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let mut refunded_order_ids = vec![];
+
+        if let Some(mut book) = OrderBook::load_if_exists(order_book)? {
+            if !book.orders.is_empty() {
+                let (Some(order_book_base_account), Some(order_book_quote_account)) =
+                    (order_book_base_account, order_book_quote_account)
+                else {
+                    return err!(ErrorCode::ConstraintAccountIsNone);
+                };
+
+                let order_book_seeds = generate_order_book_seeds!(book);
+
+                for order in &book.orders {
+                    let (base_amount, quote_amount) = order.amounts_owed();
+
+                    for (amount, mint, order_book_account) in [
+                        (base_amount, amm.base_mint, &order_book_base_account),
+                        (quote_amount, amm.quote_mint, &order_book_quote_account),
+                    ] {
+                        if amount == 0 {
+                            continue;
+                        }
+
+                        let owner_account_address =
+                            get_associated_token_address(&order.owner, &mint);
+
+                        let owner_account = ctx
+                            .remaining_accounts
+                            .iter()
+                            .find(|account| account.key() == owner_account_address)
+                            .ok_or(error!(AmmError::MissingOrderOwnerAccount))?;
+
+                        token::transfer(
+                            CpiContext::new_with_signer(
+                                token_program.to_account_info(),
+                                Transfer {
+                                    from: order_book_account.to_account_info(),
+                                    to: owner_account.clone(),
+                                    authority: order_book.to_account_info(),
+                                },
+                                &[order_book_seeds],
+                            ),
+                            amount,
+                        )?;
+                    }
+
+                    refunded_order_ids.push(order.id);
+                }
+
+                book.orders.clear();
+                book.seq_num += 1;
+                book.try_serialize(&mut &mut order_book.try_borrow_mut_data()?[..])?;
+            }
+        }
+
+        let seeds = generate_amm_seeds!(amm);
+
+        let leftover_base_amount = vault_ata_base.amount;
+        let leftover_quote_amount = vault_ata_quote.amount;
+
+        for (vault_ata, payer_account) in [
+            (vault_ata_base, payer_base_account),
+            (vault_ata_quote, payer_quote_account),
+        ] {
+            if vault_ata.amount > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: vault_ata.to_account_info(),
+                            to: payer_account.to_account_info(),
+                            authority: amm.to_account_info(),
+                        },
+                        &[seeds],
+                    ),
+                    vault_ata.amount,
+                )?;
+            }
+
+            token::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: vault_ata.to_account_info(),
+                    destination: payer.to_account_info(),
+                    authority: amm.to_account_info(),
+                },
+                &[seeds],
+            ))?;
+        }
+
+        let clock = Clock::get()?;
+        emit_cpi!(CloseAmmEvent {
+            common: CommonFields::new(&clock, payer.key(), amm),
+            leftover_base_amount,
+            leftover_quote_amount,
+            refunded_order_ids,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::events::{ClosePositionEvent, CommonFields};
+use crate::generate_position_seeds;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub amm: Account<'info, Amm>,
+    #[account(
+        mut,
+        has_one = amm,
+        has_one = owner,
+        close = owner,
+    )]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        mut,
+        token::mint = amm.lp_mint,
+        token::authority = owner,
+    )]
+    pub owner_lp_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.lp_mint,
+        associated_token::authority = position,
+    )]
+    pub position_lp_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl ClosePosition<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.position.locker.is_none(), AmmError::PositionLocked);
+
+        Ok(())
+    }
+
+    /// Gives the position's LP tokens back to its owner and closes it.
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let ClosePosition {
+            owner,
+            amm,
+            position,
+            owner_lp_account,
+            position_lp_account,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        // anyone can send LP tokens to the position, so we give back whatever
+        // it holds rather than `position.lp_tokens`
+        let lp_tokens = position_lp_account.amount;
+
+        let nonce_bytes = position.nonce.to_le_bytes();
+        let seeds = generate_position_seeds!(position, nonce_bytes);
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: position_lp_account.to_account_info(),
+                    to: owner_lp_account.to_account_info(),
+                    authority: position.to_account_info(),
+                },
+                &[seeds],
+            ),
+            lp_tokens,
+        )?;
+
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: position_lp_account.to_account_info(),
+                destination: owner.to_account_info(),
+                authority: position.to_account_info(),
+            },
+            &[seeds],
+        ))?;
+
+        let clock = Clock::get()?;
+        emit_cpi!(ClosePositionEvent {
+            common: CommonFields::new(&clock, owner.key(), amm),
+            position: position.key(),
+            lp_tokens,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+
+use crate::generate_amm_seeds;
+use crate::state::*;
+
+use crate::events::{CollectProtocolFeesEvent, CommonFields};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CollectProtocolFees<'info> {
+    pub fee_authority: Signer<'info>,
+    #[account(
+        has_one = fee_authority,
+        seeds = [GLOBAL_CONFIG_SEED_PREFIX],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    #[account(mut)]
+    pub amm: Account<'info, Amm>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = global_config.protocol_fee_recipient,
+    )]
+    pub recipient_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = global_config.protocol_fee_recipient,
+    )]
+    pub recipient_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_base: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_quote: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl CollectProtocolFees<'_> {
+    /// Sends the protocol fees that swaps have accrued to the protocol fee
+    /// recipient.
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let CollectProtocolFees {
+            fee_authority,
+            global_config,
+            amm,
+            recipient_base_account,
+            recipient_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let base_amount = amm.protocol_base_fees_owed;
+        let quote_amount = amm.protocol_quote_fees_owed;
+
+        let seeds = generate_amm_seeds!(amm);
+
+        for (vault_ata, recipient_account, amount) in [
+            (vault_ata_base, recipient_base_account, base_amount),
+            (vault_ata_quote, recipient_quote_account, quote_amount),
+        ] {
+            if amount > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: vault_ata.to_account_info(),
+                            to: recipient_account.to_account_info(),
+                            authority: amm.to_account_info(),
+                        },
+                        &[seeds],
+                    ),
+                    amount,
+                )?;
+            }
+        }
+
+        amm.protocol_base_fees_owed = 0;
+        amm.protocol_quote_fees_owed = 0;
+
+        amm.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(CollectProtocolFeesEvent {
+            common: CommonFields::new(&clock, fee_authority.key(), amm),
+            protocol_fee_recipient: global_config.protocol_fee_recipient,
+            base_amount,
+            quote_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -13,6 +13,15 @@ pub struct AddOrRemoveLiquidity<'info> {
         has_one = lp_mint,
     )]
     pub amm: Account<'info, Amm>,
+    /// Only used by the zaps, which swap and so count `user` as a trader.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<TraderRecord>(),
+        seeds = [TRADER_RECORD_SEED_PREFIX, amm.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub trader_record: Option<Box<Account<'info, TraderRecord>>>,
     #[account(mut)]
     pub lp_mint: Box<Account<'info, Mint>>,
     #[account(
@@ -21,6 +30,20 @@ pub struct AddOrRemoveLiquidity<'info> {
         token::authority = user,
     )]
     pub user_lp_account: Box<Account<'info, TokenAccount>>,
+    /// Only used by `add_liquidity` and `zap_in`, which mint the LP tokens to
+    /// this position instead of `user_lp_account` when it's passed in
+    #[account(
+        mut,
+        has_one = amm,
+        constraint = position.owner == user.key(),
+    )]
+    pub position: Option<Box<Account<'info, Position>>>,
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = position,
+    )]
+    pub position_lp_account: Option<Box<Account<'info, TokenAccount>>>,
     #[account(
         mut,
         token::mint = amm.base_mint,
@@ -46,4 +69,5 @@ pub struct AddOrRemoveLiquidity<'info> {
     )]
     pub vault_ata_quote: Account<'info, TokenAccount>,
     pub token_program: Program<'info, Token>,
+    pub system_program: Option<Program<'info, System>>,
 }
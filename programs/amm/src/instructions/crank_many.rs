@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{CommonFields, CrankThatTwapEvent};
+use crate::state::*;
+
+/// Cranks the TWAP of every AMM passed in as a remaining account, so that
+/// keepers can update many markets in one instruction.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CrankMany {}
+
+impl<'info, 'c: 'info> CrankMany<'info> {
+    pub fn handle(ctx: Context<'_, '_, 'c, 'info, Self>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        for amm_info in ctx.remaining_accounts.iter() {
+            require!(amm_info.is_writable, ErrorCode::ConstraintMut);
+
+            let mut amm = Account::<Amm>::try_from(amm_info)?;
+
+            amm.update_twap(clock.slot)?;
+
+            amm.seq_num += 1;
+
+            amm.exit(ctx.program_id)?;
+
+            emit_cpi!(CrankThatTwapEvent {
+                common: CommonFields::new(&clock, Pubkey::default(), &amm),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -11,6 +11,9 @@ use crate::events::{CommonFields, CreateAmmEvent};
 pub struct CreateAmmArgs {
     pub twap_initial_observation: u128,
     pub twap_max_observation_change_per_update: u128,
+    pub admin: Option<Pubkey>,
+    pub max_price_impact_bps: Option<u16>,
+    pub batch_auction_slots: Option<u64>,
 }
 
 #[event_cpi]
@@ -31,6 +34,10 @@ pub struct CreateAmm<'info> {
         bump
     )]
     pub amm: Account<'info, Amm>,
+    /// CHECK: AMMs created before the global config is initialized don't
+    /// charge a protocol fee
+    #[account(seeds = [GLOBAL_CONFIG_SEED_PREFIX], bump)]
+    pub global_config: UncheckedAccount<'info>,
     #[account(
         init,
         payer = user,
@@ -63,13 +70,22 @@ pub struct CreateAmm<'info> {
 }
 
 impl CreateAmm<'_> {
-    pub fn validate(&self) -> Result<()> {
+    pub fn validate(&self, args: &CreateAmmArgs) -> Result<()> {
         require_neq!(
             self.base_mint.key(),
             self.quote_mint.key(),
             AmmError::SameTokenMints
         );
 
+        // batches are cleared at whatever price balances them, so a price
+        // impact limit could leave a batch impossible to clear
+        if let Some(batch_auction_slots) = args.batch_auction_slots {
+            require!(
+                batch_auction_slots > 0 && args.max_price_impact_bps.is_none(),
+                AmmError::InvalidBatchAuctionConfig
+            );
+        }
+
         Ok(())
     }
 
@@ -77,6 +93,7 @@ impl CreateAmm<'_> {
         let CreateAmm {
             user,
             amm,
+            global_config,
             lp_mint,
             base_mint,
             quote_mint,
@@ -94,8 +111,14 @@ impl CreateAmm<'_> {
         let CreateAmmArgs {
             twap_initial_observation,
             twap_max_observation_change_per_update,
+            admin,
+            max_price_impact_bps,
+            batch_auction_slots,
         } = args;
 
+        let protocol_fee_bps = GlobalConfig::load_if_exists(global_config)?
+            .map_or(0, |global_config| global_config.protocol_fee_bps);
+
         amm.set_inner(Amm {
             bump: ctx.bumps.amm,
 
@@ -118,6 +141,32 @@ impl CreateAmm<'_> {
             ),
 
             seq_num: 0,
+
+            payer: user.key(),
+
+            cumulative_quote_volume: 0,
+            swap_count: 0,
+            trader_count: 0,
+            cumulative_base_volume: 0,
+            cumulative_base_fees: 0,
+            cumulative_quote_fees: 0,
+
+            protocol_fee_bps,
+            protocol_base_fees_owed: 0,
+            protocol_quote_fees_owed: 0,
+
+            locked_lp_tokens: 0,
+
+            admin,
+            paused_at_slot: None,
+            total_paused_slots: 0,
+
+            max_price_impact_bps,
+
+            batch_auction_slots,
+            unclaimed_batch_orders: 0,
+
+            flash_loan: None,
         });
 
         let clock = Clock::get()?;
@@ -130,6 +179,10 @@ impl CreateAmm<'_> {
             quote_mint: quote_mint.key(),
             vault_ata_base: vault_ata_base.key(),
             vault_ata_quote: vault_ata_quote.key(),
+            protocol_fee_bps,
+            admin,
+            max_price_impact_bps,
+            batch_auction_slots,
         });
 
         Ok(())
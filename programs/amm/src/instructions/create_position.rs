@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::*;
+
+use crate::events::{CommonFields, CreatePositionEvent};
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreatePositionArgs {
+    /// Lets an owner hold more than one position in an AMM
+    pub nonce: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: CreatePositionArgs)]
+pub struct CreatePosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub amm: Account<'info, Amm>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + std::mem::size_of::<Position>(),
+        seeds = [
+            POSITION_SEED_PREFIX,
+            amm.key().as_ref(),
+            owner.key().as_ref(),
+            &args.nonce.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub position: Box<Account<'info, Position>>,
+    #[account(address = amm.lp_mint)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = lp_mint,
+        associated_token::authority = position,
+    )]
+    pub position_lp_account: Box<Account<'info, TokenAccount>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CreatePosition<'_> {
+    /// Creates an empty position. Liquidity goes into it through
+    /// `add_liquidity` or `zap_in`, which record what was deposited, so
+    /// depositors usually create the position in the same transaction.
+    pub fn handle(ctx: Context<Self>, args: CreatePositionArgs) -> Result<()> {
+        let CreatePosition {
+            owner,
+            amm,
+            position,
+            lp_mint: _,
+            position_lp_account: _,
+            associated_token_program: _,
+            token_program: _,
+            system_program: _,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let CreatePositionArgs { nonce } = args;
+
+        let clock = Clock::get()?;
+
+        position.set_inner(Position {
+            amm: amm.key(),
+            owner: owner.key(),
+            nonce,
+            lp_tokens: 0,
+            base_deposited: 0,
+            quote_deposited: 0,
+            deposit_slot: clock.slot,
+            locker: None,
+            bump: ctx.bumps.position,
+            seq_num: 0,
+        });
+
+        emit_cpi!(CreatePositionEvent {
+            common: CommonFields::new(&clock, owner.key(), amm),
+            position: position.key(),
+            nonce,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, FillOrdersEvent};
+use crate::state::*;
+use crate::{generate_amm_seeds, generate_order_book_seeds};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FillOrders<'info> {
+    pub filler: Signer<'info>,
+    #[account(mut)]
+    pub amm: Account<'info, Amm>,
+    #[account(mut, has_one = amm)]
+    pub order_book: Box<Account<'info, OrderBook>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = order_book,
+    )]
+    pub order_book_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = order_book,
+    )]
+    pub order_book_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_base: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_quote: Box<Account<'info, TokenAccount>>,
+    /// Receives the bounty for filled buys
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = filler,
+    )]
+    pub filler_base_account: Box<Account<'info, TokenAccount>>,
+    /// Receives the bounty for filled sells
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = filler,
+    )]
+    pub filler_quote_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info, 'c: 'info> FillOrders<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(!self.amm.is_paused(), AmmError::AmmPaused);
+        require!(
+            self.amm.batch_auction_slots.is_none(),
+            AmmError::BatchAuctionMode
+        );
+
+        Ok(())
+    }
+
+    /// Swaps every order that the pool's price has crossed through the AMM,
+    /// paying the filler a bounty out of each filled order's output.
+    ///
+    /// The owners' output token accounts can be passed in as remaining
+    /// accounts, in which case their filled orders are paid out and removed
+    /// from the order book to free up space.
+    pub fn handle(ctx: Context<'_, '_, 'c, 'info, Self>) -> Result<()> {
+        let FillOrders {
+            filler,
+            amm,
+            order_book,
+            order_book_base_account,
+            order_book_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            filler_base_account,
+            filler_quote_account,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+
+        amm.update_twap(clock.slot)?;
+
+        let fills = order_book.fill_orders(amm, clock.slot);
+
+        require!(!fills.filled_order_ids.is_empty(), AmmError::NoOrdersFilled);
+
+        let amm_seeds = generate_amm_seeds!(amm);
+        let order_book_seeds = generate_order_book_seeds!(order_book);
+
+        // orders on both sides can fill in the same batch, so we only move
+        // the difference between what goes into and out of each vault
+        for (to_amm, from_amm, order_book_account, vault_ata) in [
+            (
+                fills.base_to_amm,
+                fills.base_from_amm,
+                &order_book_base_account,
+                &vault_ata_base,
+            ),
+            (
+                fills.quote_to_amm,
+                fills.quote_from_amm,
+                &order_book_quote_account,
+                &vault_ata_quote,
+            ),
+        ] {
+            if to_amm > from_amm {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: order_book_account.to_account_info(),
+                            to: vault_ata.to_account_info(),
+                            authority: order_book.to_account_info(),
+                        },
+                        &[order_book_seeds],
+                    ),
+                    to_amm - from_amm,
+                )?;
+            } else if from_amm > to_amm {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: vault_ata.to_account_info(),
+                            to: order_book_account.to_account_info(),
+                            authority: amm.to_account_info(),
+                        },
+                        &[amm_seeds],
+                    ),
+                    from_amm - to_amm,
+                )?;
+            }
+        }
+
+        for (bounty, order_book_account, filler_account) in [
+            (
+                fills.base_bounty,
+                &order_book_base_account,
+                filler_base_account,
+            ),
+            (
+                fills.quote_bounty,
+                &order_book_quote_account,
+                filler_quote_account,
+            ),
+        ] {
+            if bounty > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: order_book_account.to_account_info(),
+                            to: filler_account.to_account_info(),
+                            authority: order_book.to_account_info(),
+                        },
+                        &[order_book_seeds],
+                    ),
+                    bounty,
+                )?;
+            }
+        }
+
+        let mut settled_order_ids = vec![];
+
+        for order_id in &fills.filled_order_ids {
+            let order = order_book.orders[order_book.find_order(*order_id)?];
+
+            let Some(output_amount) = order.output_amount else {
+                continue;
+            };
+
+            let (output_mint, order_book_account) = match order.swap_type {
+                SwapType::Buy => (amm.base_mint, &order_book_base_account),
+                SwapType::Sell => (amm.quote_mint, &order_book_quote_account),
+            };
+
+            let owner_account_address = get_associated_token_address(&order.owner, &output_mint);
+
+            // the owner could have closed their account, in which case they
+            // have to remove the order themselves
+            let Some(owner_account) = ctx.remaining_accounts.iter().find(|account| {
+                account.key() == owner_account_address
+                    && account.owner == &token::ID
+                    && !account.data_is_empty()
+            }) else {
+                continue;
+            };
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: order_book_account.to_account_info(),
+                        to: owner_account.clone(),
+                        authority: order_book.to_account_info(),
+                    },
+                    &[order_book_seeds],
+                ),
+                output_amount,
+            )?;
+
+            settled_order_ids.push(order.id);
+        }
+
+        order_book
+            .orders
+            .retain(|order| !settled_order_ids.contains(&order.id));
+
+        amm.seq_num += 1;
+        order_book.seq_num += 1;
+
+        emit_cpi!(FillOrdersEvent {
+            common: CommonFields::new(&clock, filler.key(), amm),
+            order_book: order_book.key(),
+            filled_order_ids: fills.filled_order_ids,
+            settled_order_ids,
+            base_bounty: fills.base_bounty,
+            quote_bounty: fills.quote_bounty,
+        });
+
+        Ok(())
+    }
+}
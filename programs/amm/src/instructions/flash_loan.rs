@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::Discriminator;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, FlashLoanEvent};
+use crate::generate_amm_seeds;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FlashLoanArgs {
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+/// The index of the AMM in `repay_flash_loan`'s accounts.
+const REPAY_AMM_ACCOUNT_INDEX: usize = 1;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FlashLoanAccounts<'info> {
+    pub borrower: Signer<'info>,
+    #[account(mut)]
+    pub amm: Account<'info, Amm>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+    )]
+    pub borrower_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+    )]
+    pub borrower_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_base: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_quote: Box<Account<'info, TokenAccount>>,
+    /// CHECK: checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl FlashLoanAccounts<'_> {
+    pub fn validate(&self, args: &FlashLoanArgs) -> Result<()> {
+        require!(!self.amm.is_paused(), AmmError::AmmPaused);
+
+        require!(
+            self.amm.flash_loan.is_none(),
+            AmmError::FlashLoanOutstanding
+        );
+
+        require!(
+            args.base_amount > 0 || args.quote_amount > 0,
+            AmmError::InvalidFlashLoanAmount
+        );
+        require_gte!(
+            self.amm.base_amount,
+            args.base_amount,
+            AmmError::InvalidFlashLoanAmount
+        );
+        require_gte!(
+            self.amm.quote_amount,
+            args.quote_amount,
+            AmmError::InvalidFlashLoanAmount
+        );
+
+        self.validate_repayment()
+    }
+
+    /// Checks that a later instruction in this transaction repays the loan.
+    /// `repay_flash_loan` works out what's owed from the AMM, so it's enough
+    /// that it's there.
+    fn validate_repayment(&self) -> Result<()> {
+        let instructions = self.instructions.to_account_info();
+
+        let current_index = load_current_index_checked(&instructions)? as usize;
+
+        for index in current_index + 1.. {
+            let Ok(instruction) = load_instruction_at_checked(index, &instructions) else {
+                break;
+            };
+
+            let is_repayment = instruction.program_id == crate::ID
+                && instruction.data.get(..8)
+                    == Some(&crate::instruction::RepayFlashLoan::DISCRIMINATOR[..])
+                && instruction
+                    .accounts
+                    .get(REPAY_AMM_ACCOUNT_INDEX)
+                    .is_some_and(|account| account.pubkey == self.amm.key());
+
+            if is_repayment {
+                return Ok(());
+            }
+        }
+
+        err!(AmmError::FlashLoanNotRepaid)
+    }
+
+    /// Lends out some of the AMM's reserves. The reserves in the AMM's state
+    /// don't change, so swaps and the TWAP are unaffected by the loan.
+    pub fn handle(ctx: Context<Self>, args: FlashLoanArgs) -> Result<()> {
+        let FlashLoanAccounts {
+            borrower,
+            amm,
+            borrower_base_account,
+            borrower_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            instructions: _,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let flash_loan = FlashLoan::new(
+            args.base_amount,
+            args.quote_amount,
+            vault_ata_base.amount,
+            vault_ata_quote.amount,
+        );
+
+        amm.flash_loan = Some(flash_loan);
+
+        let seeds = generate_amm_seeds!(amm);
+
+        for (amount, vault_from, borrower_to) in [
+            (
+                flash_loan.base_amount,
+                vault_ata_base,
+                borrower_base_account,
+            ),
+            (
+                flash_loan.quote_amount,
+                vault_ata_quote,
+                borrower_quote_account,
+            ),
+        ] {
+            if amount > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: vault_from.to_account_info(),
+                            to: borrower_to.to_account_info(),
+                            authority: amm.to_account_info(),
+                        },
+                        &[seeds],
+                    ),
+                    amount,
+                )?;
+            }
+        }
+
+        amm.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(FlashLoanEvent {
+            common: CommonFields::new(&clock, borrower.key(), amm),
+            base_amount: flash_loan.base_amount,
+            quote_amount: flash_loan.quote_amount,
+            base_fee: flash_loan.base_fee,
+            quote_fee: flash_loan.quote_fee,
+        });
+
+        Ok(())
+    }
+}
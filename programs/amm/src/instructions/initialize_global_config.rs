@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::events::InitializeGlobalConfigEvent;
+use crate::program::Amm as AmmProgram;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeGlobalConfigArgs {
+    pub fee_authority: Pubkey,
+    pub protocol_fee_recipient: Pubkey,
+    pub protocol_fee_bps: u16,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeGlobalConfig<'info> {
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+    #[account(
+        init,
+        payer = upgrade_authority,
+        space = 8 + std::mem::size_of::<GlobalConfig>(),
+        seeds = [GLOBAL_CONFIG_SEED_PREFIX],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    #[account(constraint = amm_program.programdata_address()? == Some(program_data.key()))]
+    pub amm_program: Program<'info, AmmProgram>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()))]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeGlobalConfig<'_> {
+    pub fn validate(&self, args: &InitializeGlobalConfigArgs) -> Result<()> {
+        Amm::validate_protocol_fee_bps(args.protocol_fee_bps)?;
+
+        Ok(())
+    }
+
+    /// Only the program's upgrade authority can do this, since whoever
+    /// initializes the global config controls protocol fees.
+    pub fn handle(ctx: Context<Self>, args: InitializeGlobalConfigArgs) -> Result<()> {
+        let InitializeGlobalConfig {
+            upgrade_authority,
+            global_config,
+            amm_program: _,
+            program_data: _,
+            system_program: _,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let InitializeGlobalConfigArgs {
+            fee_authority,
+            protocol_fee_recipient,
+            protocol_fee_bps,
+        } = args;
+
+        global_config.set_inner(GlobalConfig {
+            fee_authority,
+            protocol_fee_recipient,
+            protocol_fee_bps,
+            bump: ctx.bumps.global_config,
+            seq_num: 0,
+        });
+
+        let clock = Clock::get()?;
+        emit_cpi!(InitializeGlobalConfigEvent {
+            slot: clock.slot,
+            unix_timestamp: clock.unix_timestamp,
+            upgrade_authority: upgrade_authority.key(),
+            global_config: global_config.key(),
+            fee_authority,
+            protocol_fee_recipient,
+            protocol_fee_bps,
+        });
+
+        Ok(())
+    }
+}
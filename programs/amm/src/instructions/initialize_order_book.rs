@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::*;
+
+use crate::events::{CommonFields, InitializeOrderBookEvent};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeOrderBook<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub amm: Account<'info, Amm>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<OrderBook>() + MAX_LIMIT_ORDERS * std::mem::size_of::<LimitOrder>(),
+        seeds = [ORDER_BOOK_SEED_PREFIX, amm.key().as_ref()],
+        bump
+    )]
+    pub order_book: Box<Account<'info, OrderBook>>,
+    #[account(address = amm.base_mint)]
+    pub base_mint: Box<Account<'info, Mint>>,
+    #[account(address = amm.quote_mint)]
+    pub quote_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::authority = order_book,
+        associated_token::mint = base_mint
+    )]
+    pub order_book_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::authority = order_book,
+        associated_token::mint = quote_mint
+    )]
+    pub order_book_quote_account: Box<Account<'info, TokenAccount>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeOrderBook<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let InitializeOrderBook {
+            payer,
+            amm,
+            order_book,
+            base_mint: _,
+            quote_mint: _,
+            order_book_base_account: _,
+            order_book_quote_account: _,
+            associated_token_program: _,
+            token_program: _,
+            system_program: _,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        order_book.set_inner(OrderBook {
+            amm: amm.key(),
+            next_order_id: 0,
+            orders: vec![],
+            bump: ctx.bumps.order_book,
+            seq_num: 0,
+        });
+
+        let clock = Clock::get()?;
+        emit_cpi!(InitializeOrderBookEvent {
+            common: CommonFields::new(&clock, payer.key(), amm),
+            order_book: order_book.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, LockPositionEvent, UnlockPositionEvent};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct LockPosition<'info> {
+    pub owner: Signer<'info>,
+    /// Usually a PDA of the program that's locking the position, such as a
+    /// proposal
+    pub locker: Signer<'info>,
+    pub amm: Account<'info, Amm>,
+    #[account(mut, has_one = amm, has_one = owner)]
+    pub position: Box<Account<'info, Position>>,
+}
+
+impl LockPosition<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.position.locker.is_none(), AmmError::PositionLocked);
+
+        Ok(())
+    }
+
+    /// Locks a position until `locker` unlocks it. Both have to sign, so that
+    /// nobody can lock a position they can't unlock.
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let LockPosition {
+            owner,
+            locker,
+            amm,
+            position,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        position.locker = Some(locker.key());
+        position.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(LockPositionEvent {
+            common: CommonFields::new(&clock, owner.key(), amm),
+            position: position.key(),
+            locker: locker.key(),
+            lp_tokens: position.lp_tokens,
+        });
+
+        Ok(())
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnlockPosition<'info> {
+    pub locker: Signer<'info>,
+    pub amm: Account<'info, Amm>,
+    #[account(mut, has_one = amm)]
+    pub position: Box<Account<'info, Position>>,
+}
+
+impl UnlockPosition<'_> {
+    pub fn validate(&self) -> Result<()> {
+        let locker = self
+            .position
+            .locker
+            .ok_or(error!(AmmError::PositionNotLocked))?;
+
+        require_keys_eq!(locker, self.locker.key(), AmmError::InvalidLocker);
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let UnlockPosition {
+            locker,
+            amm,
+            position,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        position.locker = None;
+        position.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(UnlockPositionEvent {
+            common: CommonFields::new(&clock, position.owner, amm),
+            position: position.key(),
+            locker: locker.key(),
+        });
+
+        Ok(())
+    }
+}
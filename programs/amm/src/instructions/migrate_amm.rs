@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, MigrateAmmEvent};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateAmm<'info> {
+    /// CHECK: an AMM created before its newer fields existed doesn't
+    /// deserialize as an `Amm`, so we check it by hand in `validate`
+    #[account(mut, owner = crate::ID)]
+    pub amm: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateAmm<'_> {
+    pub fn validate(&self) -> Result<()> {
+        let data = self.amm.try_borrow_data()?;
+
+        require!(
+            data.len() >= 8 && data[..8] == Amm::DISCRIMINATOR,
+            AmmError::InvalidAmm
+        );
+        require_gt!(
+            8 + std::mem::size_of::<Amm>(),
+            data.len(),
+            AmmError::AmmAlreadyMigrated
+        );
+
+        Ok(())
+    }
+
+    /// Makes room for the fields that were added to `Amm` after it was
+    /// created. They all read as zeroes, which are their defaults. AMMs that
+    /// are migrated have no `payer`, so they can't be closed.
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let MigrateAmm {
+            amm,
+            payer,
+            system_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let new_space = 8 + std::mem::size_of::<Amm>();
+
+        let rent_owed = Rent::get()?
+            .minimum_balance(new_space)
+            .saturating_sub(amm.lamports());
+
+        if rent_owed > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: amm.to_account_info(),
+                    },
+                ),
+                rent_owed,
+            )?;
+        }
+
+        amm.to_account_info().realloc(new_space, true)?;
+
+        let migrated_amm = Amm::try_deserialize(&mut &amm.try_borrow_data()?[..])?;
+
+        let clock = Clock::get()?;
+        emit_cpi!(MigrateAmmEvent {
+            common: CommonFields::from_amm(&clock, payer.key(), amm.key(), &migrated_amm),
+        });
+
+        Ok(())
+    }
+}
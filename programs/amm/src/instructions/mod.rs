@@ -1,13 +1,57 @@
 pub use add_liquidity::*;
+pub use cancel_expired_limit_order::*;
+pub use claim_batch_order::*;
+pub use clear_batch::*;
+pub use close_amm::*;
+pub use close_position::*;
+pub use collect_protocol_fees::*;
 pub use common::*;
+pub use crank_many::*;
 pub use crank_that_twap::*;
 pub use create_amm::*;
+pub use create_position::*;
+pub use fill_orders::*;
+pub use flash_loan::*;
+pub use initialize_global_config::*;
+pub use initialize_order_book::*;
+pub use lock_position::*;
+pub use migrate_amm::*;
+pub use pause_amm::*;
+pub use place_limit_order::*;
+pub use remove_limit_order::*;
 pub use remove_liquidity::*;
+pub use repay_flash_loan::*;
+pub use submit_batch_order::*;
 pub use swap::*;
+pub use update_protocol_fee::*;
+pub use zap_in::*;
+pub use zap_out::*;
 
 pub mod add_liquidity;
+pub mod cancel_expired_limit_order;
+pub mod claim_batch_order;
+pub mod clear_batch;
+pub mod close_amm;
+pub mod close_position;
+pub mod collect_protocol_fees;
 pub mod common;
+pub mod crank_many;
 pub mod crank_that_twap;
 pub mod create_amm;
+pub mod create_position;
+pub mod fill_orders;
+pub mod flash_loan;
+pub mod initialize_global_config;
+pub mod initialize_order_book;
+pub mod lock_position;
+pub mod migrate_amm;
+pub mod pause_amm;
+pub mod place_limit_order;
+pub mod remove_limit_order;
 pub mod remove_liquidity;
+pub mod repay_flash_loan;
+pub mod submit_batch_order;
 pub mod swap;
+pub mod update_protocol_fee;
+pub mod zap_in;
+pub mod zap_out;
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, PauseAmmEvent, UnpauseAmmEvent};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PauseOrUnpauseAmm<'info> {
+    pub admin: Signer<'info>,
+    #[account(mut)]
+    pub amm: Account<'info, Amm>,
+}
+
+impl PauseOrUnpauseAmm<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.amm.admin == Some(self.admin.key()),
+            AmmError::InvalidAdmin
+        );
+
+        Ok(())
+    }
+
+    /// Halts swaps and deposits, e.g. if a bug is discovered mid-proposal.
+    /// LPs can still remove their liquidity.
+    pub fn handle_pause(ctx: Context<Self>) -> Result<()> {
+        let PauseOrUnpauseAmm {
+            admin,
+            amm,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+
+        amm.pause(clock.slot)?;
+
+        amm.seq_num += 1;
+
+        emit_cpi!(PauseAmmEvent {
+            common: CommonFields::new(&clock, admin.key(), amm),
+        });
+
+        Ok(())
+    }
+
+    pub fn handle_unpause(ctx: Context<Self>) -> Result<()> {
+        let PauseOrUnpauseAmm {
+            admin,
+            amm,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+
+        let paused_slots = amm.unpause(clock.slot)?;
+
+        amm.seq_num += 1;
+
+        emit_cpi!(UnpauseAmmEvent {
+            common: CommonFields::new(&clock, admin.key(), amm),
+            paused_slots,
+        });
+
+        Ok(())
+    }
+}
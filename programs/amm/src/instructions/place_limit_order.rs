@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, PlaceLimitOrderEvent};
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlaceLimitOrderArgs {
+    pub swap_type: SwapType,
+    pub input_amount: u64,
+    pub limit_price: u128,
+    /// How many slots the order rests in the book before it expires, at most
+    /// `MAX_LIMIT_ORDER_LIFETIME_SLOTS`
+    pub expires_in_slots: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PlaceLimitOrder<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut)]
+    pub amm: Account<'info, Amm>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<TraderRecord>(),
+        seeds = [TRADER_RECORD_SEED_PREFIX, amm.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub trader_record: Box<Account<'info, TraderRecord>>,
+    #[account(mut, has_one = amm)]
+    pub order_book: Box<Account<'info, OrderBook>>,
+    /// The quote mint for buys and the base mint for sells
+    pub input_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = input_mint,
+        token::authority = user,
+    )]
+    pub user_input_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = order_book,
+    )]
+    pub order_book_input_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl PlaceLimitOrder<'_> {
+    pub fn validate(&self, args: &PlaceLimitOrderArgs) -> Result<()> {
+        require!(
+            args.input_amount > 0 && args.limit_price > 0,
+            AmmError::InvalidLimitOrder
+        );
+
+        require!(
+            args.expires_in_slots > 0 && args.expires_in_slots <= MAX_LIMIT_ORDER_LIFETIME_SLOTS,
+            AmmError::InvalidLimitOrderExpiry
+        );
+
+        require_gt!(
+            MAX_LIMIT_ORDERS,
+            self.order_book.orders.len(),
+            AmmError::OrderBookFull
+        );
+
+        require_gt!(
+            MAX_LIMIT_ORDERS_PER_OWNER,
+            self.order_book.orders_owned_by(self.user.key()),
+            AmmError::TooManyLimitOrders
+        );
+
+        let (input_mint, input_reserve) = match args.swap_type {
+            SwapType::Buy => (self.amm.quote_mint, self.amm.quote_amount),
+            SwapType::Sell => (self.amm.base_mint, self.amm.base_amount),
+        };
+
+        require_keys_eq!(
+            self.input_mint.key(),
+            input_mint,
+            AmmError::InvalidLimitOrder
+        );
+
+        require_gte!(
+            args.input_amount as u128 * 10_000,
+            input_reserve as u128 * MIN_LIMIT_ORDER_BPS as u128,
+            AmmError::LimitOrderTooSmall
+        );
+
+        require_gte!(
+            self.user_input_account.amount,
+            args.input_amount,
+            AmmError::InsufficientBalance
+        );
+
+        Ok(())
+    }
+
+    /// Escrows the order's input in the order book until the order is filled
+    /// or removed. The user counts as one of the AMM's traders from here on,
+    /// since fills happen later on someone else's crank.
+    pub fn handle(ctx: Context<Self>, args: PlaceLimitOrderArgs) -> Result<()> {
+        let PlaceLimitOrder {
+            user,
+            amm,
+            trader_record,
+            order_book,
+            input_mint: _,
+            user_input_account,
+            order_book_input_account,
+            token_program,
+            system_program: _,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let PlaceLimitOrderArgs {
+            swap_type,
+            input_amount,
+            limit_price,
+            expires_in_slots,
+        } = args;
+
+        let clock = Clock::get()?;
+        let expires_at_slot = clock.slot + expires_in_slots;
+
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Transfer {
+                    from: user_input_account.to_account_info(),
+                    to: order_book_input_account.to_account_info(),
+                    authority: user.to_account_info(),
+                },
+            ),
+            input_amount,
+        )?;
+
+        let order_id = order_book.next_order_id;
+
+        order_book.orders.push(LimitOrder {
+            id: order_id,
+            owner: user.key(),
+            swap_type,
+            input_amount,
+            limit_price,
+            output_amount: None,
+            expires_at_slot,
+        });
+
+        order_book.next_order_id += 1;
+        order_book.seq_num += 1;
+
+        trader_record.record_trade(
+            amm.key(),
+            amm,
+            user.key(),
+            clock.slot,
+            ctx.bumps.trader_record,
+        );
+
+        emit_cpi!(PlaceLimitOrderEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            order_book: order_book.key(),
+            order_id,
+            swap_type,
+            input_amount,
+            limit_price,
+            expires_at_slot,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+
+use crate::events::{CommonFields, RemoveLimitOrderEvent};
+use crate::generate_order_book_seeds;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveLimitOrderArgs {
+    pub order_id: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveLimitOrder<'info> {
+    pub user: Signer<'info>,
+    pub amm: Account<'info, Amm>,
+    #[account(mut, has_one = amm)]
+    pub order_book: Box<Account<'info, OrderBook>>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = user,
+    )]
+    pub user_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = user,
+    )]
+    pub user_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = order_book,
+    )]
+    pub order_book_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = order_book,
+    )]
+    pub order_book_quote_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl RemoveLimitOrder<'_> {
+    /// Removes an order from the book, sending its owner the order's output
+    /// if it was filled or its input if it wasn't.
+    pub fn handle(ctx: Context<Self>, args: RemoveLimitOrderArgs) -> Result<()> {
+        let RemoveLimitOrder {
+            user,
+            amm,
+            order_book,
+            user_base_account,
+            user_quote_account,
+            order_book_base_account,
+            order_book_quote_account,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let order_index = order_book.find_order(args.order_id)?;
+        let order = order_book.orders[order_index];
+
+        require_keys_eq!(order.owner, user.key(), ErrorCode::ConstraintOwner);
+
+        // a filled order is owed its output and an unfilled one its input
+        let (amount, from, to) = match (order.swap_type, order.output_amount) {
+            (SwapType::Buy, Some(output_amount)) => {
+                (output_amount, order_book_base_account, user_base_account)
+            }
+            (SwapType::Sell, Some(output_amount)) => {
+                (output_amount, order_book_quote_account, user_quote_account)
+            }
+            (SwapType::Buy, None) => (
+                order.input_amount,
+                order_book_quote_account,
+                user_quote_account,
+            ),
+            (SwapType::Sell, None) => (
+                order.input_amount,
+                order_book_base_account,
+                user_base_account,
+            ),
+        };
+
+        let seeds = generate_order_book_seeds!(order_book);
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: order_book.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )?;
+
+        order_book.orders.remove(order_index);
+        order_book.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(RemoveLimitOrderEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            order_book: order_book.key(),
+            order_id: order.id,
+            filled: order.output_amount.is_some(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -20,13 +20,17 @@ impl AddOrRemoveLiquidity<'_> {
         let AddOrRemoveLiquidity {
             user,
             amm,
+            trader_record: _,
             lp_mint,
             user_lp_account,
+            position: _,
+            position_lp_account: _,
             user_base_account,
             user_quote_account,
             vault_ata_base,
             vault_ata_quote,
             token_program,
+            system_program: _,
             program: _,
             event_authority: _,
         } = ctx.accounts;
@@ -50,7 +54,7 @@ impl AddOrRemoveLiquidity<'_> {
         // airlifted from uniswap v1:
         // https://github.com/Uniswap/v1-contracts/blob/c10c08d81d6114f694baa8bd32f555a40f6264da/contracts/uniswap_exchange.vy#L83
 
-        let total_liquidity = lp_mint.supply;
+        let total_liquidity = amm.total_lp_supply(lp_mint.supply);
         require_gt!(total_liquidity, 0, AmmError::AssertFailed);
 
         let (base_to_withdraw, quote_to_withdraw) =
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, RepayFlashLoanEvent};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RepayFlashLoan<'info> {
+    pub repayer: Signer<'info>,
+    /// `flash_loan` expects this to be the second account
+    #[account(mut)]
+    pub amm: Account<'info, Amm>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = repayer,
+    )]
+    pub repayer_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = repayer,
+    )]
+    pub repayer_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_base: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_quote: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl RepayFlashLoan<'_> {
+    /// Pays back the outstanding flash loan plus its fees.
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let RepayFlashLoan {
+            repayer,
+            amm,
+            repayer_base_account,
+            repayer_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let flash_loan = amm.repay_flash_loan()?;
+
+        for (amount, repayer_from, vault_to) in [
+            (
+                flash_loan.base_amount + flash_loan.base_fee,
+                &repayer_base_account,
+                &vault_ata_base,
+            ),
+            (
+                flash_loan.quote_amount + flash_loan.quote_fee,
+                &repayer_quote_account,
+                &vault_ata_quote,
+            ),
+        ] {
+            if amount > 0 {
+                token::transfer(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: repayer_from.to_account_info(),
+                            to: vault_to.to_account_info(),
+                            authority: repayer.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+            }
+        }
+
+        // `repay_flash_loan` only updates our accounting, so we check that the
+        // tokens are actually back
+        vault_ata_base.reload()?;
+        vault_ata_quote.reload()?;
+
+        require_gte!(
+            vault_ata_base.amount as u128 * vault_ata_quote.amount as u128,
+            flash_loan.vault_base_before as u128 * flash_loan.vault_quote_before as u128,
+            AmmError::ConstantProductInvariantFailed
+        );
+
+        require_gte!(
+            vault_ata_base.amount,
+            amm.base_amount + amm.protocol_base_fees_owed,
+            AmmError::VaultsUndercollateralized
+        );
+        require_gte!(
+            vault_ata_quote.amount,
+            amm.quote_amount + amm.protocol_quote_fees_owed,
+            AmmError::VaultsUndercollateralized
+        );
+
+        amm.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(RepayFlashLoanEvent {
+            common: CommonFields::new(&clock, repayer.key(), amm),
+            base_amount: flash_loan.base_amount,
+            quote_amount: flash_loan.quote_amount,
+            base_fee: flash_loan.base_fee,
+            quote_fee: flash_loan.quote_fee,
+        });
+
+        Ok(())
+    }
+}
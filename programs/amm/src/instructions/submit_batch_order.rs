@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, SubmitBatchOrderEvent};
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SubmitBatchOrderArgs {
+    /// Must be the batch for the current slot
+    pub batch_id: u64,
+    pub swap_type: SwapType,
+    pub input_amount: u64,
+    /// The least output to accept for `input_amount`. It's added to any
+    /// minimum from the user's earlier orders on the same side of the batch.
+    pub min_output_amount: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: SubmitBatchOrderArgs)]
+pub struct SubmitBatchOrder<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut)]
+    pub amm: Account<'info, Amm>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<Batch>(),
+        seeds = [BATCH_SEED_PREFIX, amm.key().as_ref(), &args.batch_id.to_le_bytes()],
+        bump
+    )]
+    pub batch: Box<Account<'info, Batch>>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<BatchOrder>(),
+        seeds = [BATCH_ORDER_SEED_PREFIX, batch.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub batch_order: Box<Account<'info, BatchOrder>>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<TraderRecord>(),
+        seeds = [TRADER_RECORD_SEED_PREFIX, amm.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub trader_record: Box<Account<'info, TraderRecord>>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = user,
+    )]
+    pub user_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = user,
+    )]
+    pub user_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_base: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_quote: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl SubmitBatchOrder<'_> {
+    pub fn validate(&self, args: &SubmitBatchOrderArgs) -> Result<()> {
+        require!(!self.amm.is_paused(), AmmError::AmmPaused);
+
+        let (batch_id, _) = self.amm.get_batch(Clock::get()?.slot)?;
+        require_eq!(args.batch_id, batch_id, AmmError::BatchClosed);
+
+        require!(args.input_amount > 0, AmmError::ZeroSwapAmount);
+
+        if self.batch_order.user == Pubkey::default() {
+            require_gt!(
+                MAX_BATCH_ORDERS,
+                self.batch.unclaimed_order_count,
+                AmmError::BatchFull
+            );
+        }
+
+        let input_balance = match args.swap_type {
+            SwapType::Buy => self.user_quote_account.amount,
+            SwapType::Sell => self.user_base_account.amount,
+        };
+        require_gte!(
+            input_balance,
+            args.input_amount,
+            AmmError::InsufficientBalance
+        );
+
+        Ok(())
+    }
+
+    /// Adds to the user's order in the current batch. The input goes into
+    /// the AMM's vaults but not its reserves until the batch is cleared.
+    pub fn handle(ctx: Context<Self>, args: SubmitBatchOrderArgs) -> Result<()> {
+        let SubmitBatchOrder {
+            user,
+            amm,
+            batch,
+            batch_order,
+            trader_record,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            token_program,
+            system_program: _,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let SubmitBatchOrderArgs {
+            batch_id,
+            swap_type,
+            input_amount,
+            min_output_amount,
+        } = args;
+
+        let clock = Clock::get()?;
+
+        // the first order in a batch creates it
+        if batch.amm == Pubkey::default() {
+            let (_, end_slot) = amm.get_batch(clock.slot)?;
+
+            batch.set_inner(Batch {
+                amm: amm.key(),
+                id: batch_id,
+                end_slot,
+                total_quote_in: 0,
+                total_base_in: 0,
+                unclaimed_order_count: 0,
+                clearing_price: None,
+                base_owed: 0,
+                quote_owed: 0,
+                payer: user.key(),
+                bump: ctx.bumps.batch,
+            });
+        }
+
+        if batch_order.user == Pubkey::default() {
+            batch_order.set_inner(BatchOrder {
+                batch: batch.key(),
+                user: user.key(),
+                quote_in: 0,
+                base_in: 0,
+                min_base_out: 0,
+                min_quote_out: 0,
+                refunded: false,
+                bump: ctx.bumps.batch_order,
+            });
+
+            batch.unclaimed_order_count += 1;
+            amm.unclaimed_batch_orders += 1;
+        }
+
+        trader_record.record_trade(
+            amm.key(),
+            amm,
+            user.key(),
+            clock.slot,
+            ctx.bumps.trader_record,
+        );
+
+        let (user_from, vault_to) = match swap_type {
+            SwapType::Buy => {
+                batch_order.quote_in += input_amount;
+                batch_order.min_base_out += min_output_amount;
+                batch.total_quote_in += input_amount;
+
+                (user_quote_account, vault_ata_quote)
+            }
+            SwapType::Sell => {
+                batch_order.base_in += input_amount;
+                batch_order.min_quote_out += min_output_amount;
+                batch.total_base_in += input_amount;
+
+                (user_base_account, vault_ata_base)
+            }
+        };
+
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Transfer {
+                    from: user_from.to_account_info(),
+                    to: vault_to.to_account_info(),
+                    authority: user.to_account_info(),
+                },
+            ),
+            input_amount,
+        )?;
+
+        amm.seq_num += 1;
+
+        emit_cpi!(SubmitBatchOrderEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            batch: batch.key(),
+            batch_id,
+            swap_type,
+            input_amount,
+            min_output_amount,
+        });
+
+        Ok(())
+    }
+}
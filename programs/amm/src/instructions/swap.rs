@@ -19,8 +19,20 @@ pub struct SwapArgs {
 pub struct Swap<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
+    /// Pays for `trader_record` the first time `user` trades. This is
+    /// separate from `user` so that program-owned accounts can swap.
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(mut)]
     pub amm: Account<'info, Amm>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + std::mem::size_of::<TraderRecord>(),
+        seeds = [TRADER_RECORD_SEED_PREFIX, amm.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub trader_record: Box<Account<'info, TraderRecord>>,
     #[account(
         mut,
         token::mint = amm.base_mint,
@@ -46,18 +58,22 @@ pub struct Swap<'info> {
     )]
     pub vault_ata_quote: Account<'info, TokenAccount>,
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 impl Swap<'_> {
     pub fn handle(ctx: Context<Swap>, args: SwapArgs) -> Result<()> {
         let Swap {
             user,
+            payer: _,
             amm,
+            trader_record,
             user_base_account,
             user_quote_account,
             vault_ata_base,
             vault_ata_quote,
             token_program,
+            system_program: _,
             event_authority: _,
             program: _,
         } = ctx.accounts;
@@ -85,10 +101,24 @@ impl Swap<'_> {
 
         require!(input_amount > 0, AmmError::ZeroSwapAmount);
 
+        require!(!amm.is_paused(), AmmError::AmmPaused);
+        require!(
+            amm.batch_auction_slots.is_none(),
+            AmmError::BatchAuctionMode
+        );
+
         amm.update_twap(clock.slot)?;
 
         let output_amount = amm.swap(input_amount, swap_type)?;
 
+        trader_record.record_trade(
+            amm.key(),
+            amm,
+            user.key(),
+            clock.slot,
+            ctx.bumps.trader_record,
+        );
+
         let seeds = generate_amm_seeds!(amm);
 
         let (user_from, vault_to, vault_from, user_to) = match swap_type {
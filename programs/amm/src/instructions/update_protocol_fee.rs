@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+use crate::events::UpdateProtocolFeeEvent;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateProtocolFeeArgs {
+    pub protocol_fee_bps: Option<u16>,
+    pub protocol_fee_recipient: Option<Pubkey>,
+    pub fee_authority: Option<Pubkey>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateProtocolFee<'info> {
+    pub fee_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = fee_authority,
+        seeds = [GLOBAL_CONFIG_SEED_PREFIX],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+impl UpdateProtocolFee<'_> {
+    pub fn validate(&self, args: &UpdateProtocolFeeArgs) -> Result<()> {
+        if let Some(protocol_fee_bps) = args.protocol_fee_bps {
+            Amm::validate_protocol_fee_bps(protocol_fee_bps)?;
+        }
+
+        Ok(())
+    }
+
+    /// Changes the protocol fee configuration. A new `protocol_fee_bps` only
+    /// applies to AMMs created afterwards. Fees that have already accrued go
+    /// to whoever is the recipient when they're collected.
+    pub fn handle(ctx: Context<Self>, args: UpdateProtocolFeeArgs) -> Result<()> {
+        let UpdateProtocolFee {
+            fee_authority,
+            global_config,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let UpdateProtocolFeeArgs {
+            protocol_fee_bps,
+            protocol_fee_recipient,
+            fee_authority: new_fee_authority,
+        } = args;
+
+        if let Some(protocol_fee_bps) = protocol_fee_bps {
+            global_config.protocol_fee_bps = protocol_fee_bps;
+        }
+        if let Some(protocol_fee_recipient) = protocol_fee_recipient {
+            global_config.protocol_fee_recipient = protocol_fee_recipient;
+        }
+        if let Some(new_fee_authority) = new_fee_authority {
+            global_config.fee_authority = new_fee_authority;
+        }
+
+        global_config.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(UpdateProtocolFeeEvent {
+            slot: clock.slot,
+            unix_timestamp: clock.unix_timestamp,
+            user: fee_authority.key(),
+            global_config: global_config.key(),
+            protocol_fee_bps: global_config.protocol_fee_bps,
+            protocol_fee_recipient: global_config.protocol_fee_recipient,
+            fee_authority: global_config.fee_authority,
+            seq_num: global_config.seq_num,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, ZapInEvent};
+use crate::AddOrRemoveLiquidity;
+use crate::{generate_amm_seeds, state::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ZapInArgs {
+    /// `Buy` deposits quote tokens and swaps some of them into base tokens,
+    /// `Sell` deposits base tokens and swaps some of them into quote tokens
+    pub swap_type: SwapType,
+    /// How much of the input token you will deposit to the pool
+    pub input_amount: u64,
+    /// The minimum LP token you will get back
+    pub min_lp_tokens: u64,
+}
+
+impl AddOrRemoveLiquidity<'_> {
+    /// Adds liquidity from only one of the pool's tokens by swapping part of
+    /// the input into the other token and depositing both. Any input that
+    /// isn't needed after rounding stays with the user.
+    pub fn handle_zap_in(ctx: Context<Self>, args: ZapInArgs) -> Result<()> {
+        let AddOrRemoveLiquidity {
+            user,
+            amm,
+            trader_record,
+            lp_mint,
+            user_lp_account,
+            position,
+            position_lp_account,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            token_program,
+            system_program: _,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let ZapInArgs {
+            swap_type,
+            input_amount,
+            min_lp_tokens,
+        } = args;
+
+        let (user_input_account, user_output_account, vault_input, vault_output) = match swap_type {
+            SwapType::Buy => (
+                user_quote_account,
+                user_base_account,
+                vault_ata_quote,
+                vault_ata_base,
+            ),
+            SwapType::Sell => (
+                user_base_account,
+                user_quote_account,
+                vault_ata_base,
+                vault_ata_quote,
+            ),
+        };
+
+        require_gte!(
+            user_input_account.amount,
+            input_amount,
+            AmmError::InsufficientBalance
+        );
+
+        require!(input_amount > 0, AmmError::ZeroLiquidityToAdd);
+        require!(min_lp_tokens > 0, AmmError::ZeroMinLpTokens);
+
+        let total_lp_supply = amm.total_lp_supply(lp_mint.supply);
+        require_gt!(total_lp_supply, 0, AmmError::NoReserves);
+
+        let Some(trader_record) = trader_record else {
+            return err!(ErrorCode::ConstraintAccountIsNone);
+        };
+
+        require!(!amm.is_paused(), AmmError::AmmPaused);
+        require!(
+            amm.batch_auction_slots.is_none(),
+            AmmError::BatchAuctionMode
+        );
+
+        let lp_account = match (&position, position_lp_account) {
+            (Some(_), Some(position_lp_account)) => position_lp_account.to_account_info(),
+            (None, None) => user_lp_account.to_account_info(),
+            _ => return err!(ErrorCode::ConstraintAccountIsNone),
+        };
+
+        let clock = Clock::get()?;
+        amm.update_twap(clock.slot)?;
+
+        let swap_input_amount = amm.get_zap_in_swap_amount(input_amount, swap_type)?;
+        require!(swap_input_amount > 0, AmmError::ZeroSwapAmount);
+
+        // the swap's output never leaves the vault, so we only need to do the
+        // accounting here
+        let swap_output_amount = amm.swap(swap_input_amount, swap_type)?;
+
+        trader_record.record_trade(
+            amm.key(),
+            amm,
+            user.key(),
+            clock.slot,
+            ctx.bumps.trader_record,
+        );
+
+        let (input_reserve, output_reserve) = match swap_type {
+            SwapType::Buy => (amm.quote_amount as u128, amm.base_amount as u128),
+            SwapType::Sell => (amm.base_amount as u128, amm.quote_amount as u128),
+        };
+
+        let remaining_input_amount = input_amount - swap_input_amount;
+
+        let lp_tokens_to_mint: u64 = std::cmp::min(
+            (remaining_input_amount as u128 * total_lp_supply as u128) / input_reserve,
+            (swap_output_amount as u128 * total_lp_supply as u128) / output_reserve,
+        )
+        .try_into()
+        .map_err(|_| AmmError::CastingOverflow)?;
+
+        require_gte!(
+            lp_tokens_to_mint,
+            min_lp_tokens,
+            AmmError::AddLiquiditySlippageExceeded
+        );
+
+        // like `handle_add`, we round deposits up in the pool's favor
+        let input_deposited = std::cmp::min(
+            remaining_input_amount,
+            ((lp_tokens_to_mint as u128 * input_reserve) / total_lp_supply as u128) as u64 + 1,
+        );
+        let output_deposited = std::cmp::min(
+            swap_output_amount,
+            ((lp_tokens_to_mint as u128 * output_reserve) / total_lp_supply as u128) as u64 + 1,
+        );
+        let output_refunded = swap_output_amount - output_deposited;
+
+        match swap_type {
+            SwapType::Buy => {
+                amm.quote_amount += input_deposited;
+                amm.base_amount += output_deposited;
+            }
+            SwapType::Sell => {
+                amm.base_amount += input_deposited;
+                amm.quote_amount += output_deposited;
+            }
+        }
+
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Transfer {
+                    from: user_input_account.to_account_info(),
+                    to: vault_input.to_account_info(),
+                    authority: user.to_account_info(),
+                },
+            ),
+            swap_input_amount + input_deposited,
+        )?;
+
+        let seeds = generate_amm_seeds!(amm);
+        let signer = &[&seeds[..]];
+
+        if output_refunded > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: vault_output.to_account_info(),
+                        to: user_output_account.to_account_info(),
+                        authority: amm.to_account_info(),
+                    },
+                    signer,
+                ),
+                output_refunded,
+            )?;
+        }
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: lp_mint.to_account_info(),
+                    to: lp_account,
+                    authority: amm.to_account_info(),
+                },
+                signer,
+            ),
+            lp_tokens_to_mint,
+        )?;
+
+        amm.seq_num += 1;
+
+        if let Some(position) = position {
+            let (base_deposited, quote_deposited) = match swap_type {
+                SwapType::Buy => (output_deposited, input_deposited),
+                SwapType::Sell => (input_deposited, output_deposited),
+            };
+
+            position.record_deposit(
+                lp_tokens_to_mint,
+                base_deposited,
+                quote_deposited,
+                clock.slot,
+            );
+        }
+
+        emit_cpi!(ZapInEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            swap_type,
+            input_amount,
+            min_lp_tokens,
+            swap_input_amount,
+            swap_output_amount,
+            input_deposited,
+            output_deposited,
+            output_refunded,
+            lp_tokens_minted: lp_tokens_to_mint,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Transfer};
+
+use crate::{error::AmmError, *};
+
+use crate::events::ZapOutEvent;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ZapOutArgs {
+    /// `Sell` swaps the withdrawn base tokens so that you receive only quote
+    /// tokens, `Buy` swaps the withdrawn quote tokens so that you receive
+    /// only base tokens
+    pub swap_type: SwapType,
+    pub lp_tokens_to_burn: u64,
+    /// The minimum you will get back of the token you receive
+    pub min_output_amount: u64,
+}
+
+impl AddOrRemoveLiquidity<'_> {
+    /// Removes liquidity and swaps one side of it into the other inside the
+    /// pool, so that the user receives a single token. The swap trades
+    /// against the liquidity that's left, so the last LP can't zap out.
+    pub fn handle_zap_out(ctx: Context<Self>, args: ZapOutArgs) -> Result<()> {
+        let AddOrRemoveLiquidity {
+            user,
+            amm,
+            trader_record,
+            lp_mint,
+            user_lp_account,
+            position: _,
+            position_lp_account: _,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            token_program,
+            system_program: _,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let ZapOutArgs {
+            swap_type,
+            lp_tokens_to_burn,
+            min_output_amount,
+        } = args;
+
+        require_gte!(
+            user_lp_account.amount,
+            lp_tokens_to_burn,
+            AmmError::InsufficientBalance
+        );
+
+        require!(lp_tokens_to_burn > 0, AmmError::ZeroLiquidityRemove);
+
+        let Some(trader_record) = trader_record else {
+            return err!(ErrorCode::ConstraintAccountIsNone);
+        };
+
+        // zapping out swaps, so unlike removing liquidity it isn't allowed
+        // while paused
+        require!(!amm.is_paused(), AmmError::AmmPaused);
+        require!(
+            amm.batch_auction_slots.is_none(),
+            AmmError::BatchAuctionMode
+        );
+
+        let clock = Clock::get()?;
+        amm.update_twap(clock.slot)?;
+
+        let total_liquidity = amm.total_lp_supply(lp_mint.supply);
+        require_gt!(total_liquidity, 0, AmmError::AssertFailed);
+
+        let (base_withdrawn, quote_withdrawn) =
+            amm.get_base_and_quote_withdrawable(lp_tokens_to_burn, total_liquidity);
+
+        token::burn(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Burn {
+                    mint: lp_mint.to_account_info(),
+                    from: user_lp_account.to_account_info(),
+                    authority: user.to_account_info(),
+                },
+            ),
+            lp_tokens_to_burn,
+        )?;
+
+        amm.base_amount -= base_withdrawn;
+        amm.quote_amount -= quote_withdrawn;
+
+        let (swap_input_amount, kept_amount) = match swap_type {
+            SwapType::Buy => (quote_withdrawn, base_withdrawn),
+            SwapType::Sell => (base_withdrawn, quote_withdrawn),
+        };
+
+        // the swap's input never leaves the vault, so we only need to do the
+        // accounting here
+        let swap_output_amount = if swap_input_amount > 0 {
+            amm.swap(swap_input_amount, swap_type)?
+        } else {
+            0
+        };
+
+        trader_record.record_trade(
+            amm.key(),
+            amm,
+            user.key(),
+            clock.slot,
+            ctx.bumps.trader_record,
+        );
+
+        let output_amount = kept_amount + swap_output_amount;
+
+        require_gte!(
+            output_amount,
+            min_output_amount,
+            AmmError::SwapSlippageExceeded
+        );
+
+        let (vault_from, user_to) = match swap_type {
+            SwapType::Buy => (vault_ata_base, user_base_account),
+            SwapType::Sell => (vault_ata_quote, user_quote_account),
+        };
+
+        let seeds = generate_amm_seeds!(amm);
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: vault_from.to_account_info(),
+                    to: user_to.to_account_info(),
+                    authority: amm.to_account_info(),
+                },
+                &[seeds],
+            ),
+            output_amount,
+        )?;
+
+        amm.seq_num += 1;
+
+        emit_cpi!(ZapOutEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            swap_type,
+            lp_tokens_burned: lp_tokens_to_burn,
+            min_output_amount,
+            base_withdrawn,
+            quote_withdrawn,
+            swap_input_amount,
+            swap_output_amount,
+            output_amount,
+        });
+
+        Ok(())
+    }
+}
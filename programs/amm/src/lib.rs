@@ -32,7 +32,7 @@ pub mod amm {
 
     use super::*;
 
-    #[access_control(ctx.accounts.validate())]
+    #[access_control(ctx.accounts.validate(&args))]
     pub fn create_amm(ctx: Context<CreateAmm>, args: CreateAmmArgs) -> Result<()> {
         CreateAmm::handle(ctx, args)
     }
@@ -48,6 +48,14 @@ pub mod amm {
         AddOrRemoveLiquidity::handle_remove(ctx, args)
     }
 
+    pub fn zap_in(ctx: Context<AddOrRemoveLiquidity>, args: ZapInArgs) -> Result<()> {
+        AddOrRemoveLiquidity::handle_zap_in(ctx, args)
+    }
+
+    pub fn zap_out(ctx: Context<AddOrRemoveLiquidity>, args: ZapOutArgs) -> Result<()> {
+        AddOrRemoveLiquidity::handle_zap_out(ctx, args)
+    }
+
     pub fn swap(ctx: Context<Swap>, args: SwapArgs) -> Result<()> {
         Swap::handle(ctx, args)
     }
@@ -55,4 +63,132 @@ pub mod amm {
     pub fn crank_that_twap(ctx: Context<CrankThatTwap>) -> Result<()> {
         CrankThatTwap::handle(ctx)
     }
+
+    pub fn crank_many<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CrankMany<'info>>,
+    ) -> Result<()> {
+        CrankMany::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_amm(ctx: Context<MigrateAmm>) -> Result<()> {
+        MigrateAmm::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_amm<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CloseAmm<'info>>,
+    ) -> Result<()> {
+        CloseAmm::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn initialize_global_config(
+        ctx: Context<InitializeGlobalConfig>,
+        args: InitializeGlobalConfigArgs,
+    ) -> Result<()> {
+        InitializeGlobalConfig::handle(ctx, args)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn pause_amm(ctx: Context<PauseOrUnpauseAmm>) -> Result<()> {
+        PauseOrUnpauseAmm::handle_pause(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn unpause_amm(ctx: Context<PauseOrUnpauseAmm>) -> Result<()> {
+        PauseOrUnpauseAmm::handle_unpause(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn submit_batch_order(
+        ctx: Context<SubmitBatchOrder>,
+        args: SubmitBatchOrderArgs,
+    ) -> Result<()> {
+        SubmitBatchOrder::handle(ctx, args)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn clear_batch(ctx: Context<ClearBatch>) -> Result<()> {
+        ClearBatch::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_batch_order(ctx: Context<ClaimBatchOrder>) -> Result<()> {
+        ClaimBatchOrder::handle(ctx)
+    }
+
+    pub fn initialize_order_book(ctx: Context<InitializeOrderBook>) -> Result<()> {
+        InitializeOrderBook::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn place_limit_order(
+        ctx: Context<PlaceLimitOrder>,
+        args: PlaceLimitOrderArgs,
+    ) -> Result<()> {
+        PlaceLimitOrder::handle(ctx, args)
+    }
+
+    pub fn remove_limit_order(
+        ctx: Context<RemoveLimitOrder>,
+        args: RemoveLimitOrderArgs,
+    ) -> Result<()> {
+        RemoveLimitOrder::handle(ctx, args)
+    }
+
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn cancel_expired_limit_order(
+        ctx: Context<CancelExpiredLimitOrder>,
+        args: CancelExpiredLimitOrderArgs,
+    ) -> Result<()> {
+        CancelExpiredLimitOrder::handle(ctx, args)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn fill_orders<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, FillOrders<'info>>,
+    ) -> Result<()> {
+        FillOrders::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn flash_loan(ctx: Context<FlashLoanAccounts>, args: FlashLoanArgs) -> Result<()> {
+        FlashLoanAccounts::handle(ctx, args)
+    }
+
+    pub fn repay_flash_loan(ctx: Context<RepayFlashLoan>) -> Result<()> {
+        RepayFlashLoan::handle(ctx)
+    }
+
+    pub fn create_position(ctx: Context<CreatePosition>, args: CreatePositionArgs) -> Result<()> {
+        CreatePosition::handle(ctx, args)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+        ClosePosition::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn lock_position(ctx: Context<LockPosition>) -> Result<()> {
+        LockPosition::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn unlock_position(ctx: Context<UnlockPosition>) -> Result<()> {
+        UnlockPosition::handle(ctx)
+    }
+
+    pub fn collect_protocol_fees(ctx: Context<CollectProtocolFees>) -> Result<()> {
+        CollectProtocolFees::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn update_protocol_fee(
+        ctx: Context<UpdateProtocolFee>,
+        args: UpdateProtocolFeeArgs,
+    ) -> Result<()> {
+        UpdateProtocolFee::handle(ctx, args)
+    }
 }
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::clock::Slot;
+use price_math::Price;
 
 use crate::error::AmmError;
-use crate::{MAX_PRICE, ONE_MINUTE_IN_SLOTS, PRICE_SCALE};
+use crate::{
+    FLASH_LOAN_FEE_BPS, MAX_PRICE, MAX_PROTOCOL_FEE_BPS, ONE_MINUTE_IN_SLOTS, PRICE_SCALE,
+};
 use std::cmp::{max, min, Ordering};
 
 #[derive(Clone, Copy, Debug, AnchorSerialize, AnchorDeserialize)]
@@ -18,8 +21,8 @@ pub struct TwapOracle {
     pub last_updated_slot: u64,
     /// A price is the number of quote units per base unit multiplied by 1e12.
     /// You cannot simply divide by 1e12 to get a price you can display in the UI
-    /// because the base and quote decimals may be different. Instead, do:
-    /// ui_price = (price * (10**(base_decimals - quote_decimals))) / 1e12
+    /// because the base and quote decimals may be different. Instead, use
+    /// `price_math::Price::to_ui`.
     pub last_price: u128,
     /// If we did a raw TWAP over prices, someone could push the TWAP heavily with
     /// a few extremely large outliers. So we use observations, which can only move
@@ -63,6 +66,40 @@ impl TwapOracle {
     }
 }
 
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct FlashLoan {
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub base_fee: u64,
+    pub quote_fee: u64,
+    /// What the vaults held before the loan, so that repaying it can check
+    /// that they end up with at least as much
+    pub vault_base_before: u64,
+    pub vault_quote_before: u64,
+}
+
+impl FlashLoan {
+    pub fn new(
+        base_amount: u64,
+        quote_amount: u64,
+        vault_base_before: u64,
+        vault_quote_before: u64,
+    ) -> Self {
+        // rounded up, so that even tiny loans pay something
+        let fee =
+            |amount: u64| (amount as u128 * FLASH_LOAN_FEE_BPS as u128).div_ceil(10_000) as u64;
+
+        Self {
+            base_amount,
+            quote_amount,
+            base_fee: fee(base_amount),
+            quote_fee: fee(quote_amount),
+            vault_base_before,
+            vault_quote_before,
+        }
+    }
+}
+
 #[account]
 #[derive(Default)]
 pub struct Amm {
@@ -84,6 +121,82 @@ pub struct Amm {
     pub oracle: TwapOracle,
 
     pub seq_num: u64,
+
+    /// Receives the AMM's rent when it's closed.
+    pub payer: Pubkey,
+
+    /// The total quote tokens traded through this AMM, counting both buys
+    /// and sells. Lets consumers like autocrat require real participation.
+    pub cumulative_quote_volume: u128,
+    /// The number of swaps through this AMM.
+    pub swap_count: u64,
+    /// The number of distinct users who have traded on this AMM, counted
+    /// through their `TraderRecord`s. Unlike `swap_count`, one user can't
+    /// inflate this by splitting their trades.
+    pub trader_count: u64,
+    /// The total base tokens traded through this AMM, counting both buys
+    /// and sells.
+    pub cumulative_base_volume: u128,
+    /// The swap fees paid in base tokens, which are paid on sells. Includes
+    /// the protocol's share.
+    pub cumulative_base_fees: u128,
+    /// The swap fees paid in quote tokens, which are paid on buys. Includes
+    /// the protocol's share.
+    pub cumulative_quote_fees: u128,
+
+    /// The share of each swap fee that goes to the protocol instead of LPs,
+    /// in basis points of the fee. Taken from the `GlobalConfig` when the AMM
+    /// is created and fixed from then on, so it can't change while the AMM
+    /// backs a proposal.
+    pub protocol_fee_bps: u16,
+    /// Protocol fees sit in the vaults until they're collected, but they
+    /// aren't part of `base_amount` or `quote_amount`, so LPs can't withdraw
+    /// them and swaps don't trade against them.
+    pub protocol_base_fees_owed: u64,
+    pub protocol_quote_fees_owed: u64,
+
+    /// LP tokens that count towards the LP supply but were never minted, so
+    /// nobody can burn them. Set to `MINIMUM_LIQUIDITY` on the first deposit.
+    pub locked_lp_tokens: u64,
+
+    /// Can pause and unpause the AMM, e.g. the DAO treasury if a proposal's
+    /// conditional tokens turn out to be misconfigured. `None` means the AMM
+    /// can never be paused.
+    pub admin: Option<Pubkey>,
+    /// The slot the AMM was paused at, if it's paused. While an AMM is paused,
+    /// swaps and deposits are disabled but withdrawals aren't, and its TWAP
+    /// oracle is frozen.
+    pub paused_at_slot: Option<u64>,
+    /// The total slots the AMM has spent paused, not counting a pause that's
+    /// still ongoing. These slots don't count towards the TWAP.
+    pub total_paused_slots: u64,
+
+    /// The furthest a swap can move the price away from the oracle's last
+    /// observation, in basis points. Swaps that move the price back towards
+    /// the observation are always allowed. `None` means no limit.
+    pub max_price_impact_bps: Option<u16>,
+
+    /// If set, swaps aren't executed immediately. Instead, orders submitted in
+    /// each window of this many slots are batched and cleared together at a
+    /// single price, so there's nothing to sandwich.
+    pub batch_auction_slots: Option<u64>,
+    /// Batch orders whose tokens are still in the vaults.
+    pub unclaimed_batch_orders: u64,
+
+    /// A flash loan that's been lent out and must be repaid later in the
+    /// same transaction.
+    pub flash_loan: Option<FlashLoan>,
+}
+
+/// The result of clearing a batch.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BatchClearing {
+    /// The price that every order in the batch traded at
+    pub price: u128,
+    /// What the batch's buyers are owed in total
+    pub base_owed: u64,
+    /// What the batch's sellers are owed in total
+    pub quote_owed: u64,
 }
 
 impl Amm {
@@ -94,6 +207,28 @@ impl Amm {
     /// Does the internal accounting to swap `input_amount` into the returned
     /// output amount so that output amount can be transferred to the user.
     pub fn swap(&mut self, input_amount: u64, swap_type: SwapType) -> Result<u64> {
+        let price_deviation_start = self.get_price_deviation_bps();
+
+        let output_amount = self.swap_through_reserves(input_amount, swap_type)?;
+
+        self.swap_count += 1;
+
+        if let Some(max_price_impact_bps) = self.max_price_impact_bps {
+            let price_deviation = self.get_price_deviation_bps();
+
+            require!(
+                price_deviation <= max_price_impact_bps as u128
+                    || price_deviation <= price_deviation_start,
+                AmmError::PriceImpactTooHigh
+            );
+        }
+
+        Ok(output_amount)
+    }
+
+    /// Trades `input_amount` against the reserves, charging the swap fee and
+    /// recording volume, without any of the per-swap checks that `swap` does.
+    fn swap_through_reserves(&mut self, input_amount: u64, swap_type: SwapType) -> Result<u64> {
         let base_amount_start = self.base_amount as u128;
         let quote_amount_start = self.quote_amount as u128;
 
@@ -122,24 +257,215 @@ impl Amm {
             .try_into()
             .map_err(|_| AmmError::CastingOverflow)?;
 
-        match swap_type {
+        // the fee is whatever part of the input didn't count towards the
+        // output, rounded up
+        let fee_amount = input_amount as u128 - input_amount_with_fee / 100;
+
+        // can't overflow a u64 because it's at most half of `fee_amount`
+        let protocol_fee_amount = (fee_amount * self.protocol_fee_bps as u128 / 10_000) as u64;
+
+        let reserve_input_amount = input_amount - protocol_fee_amount;
+
+        let (base_volume, quote_volume) = match swap_type {
             SwapType::Buy => {
-                self.quote_amount += input_amount;
+                self.quote_amount += reserve_input_amount;
                 self.base_amount -= output_amount;
+
+                self.cumulative_quote_fees += fee_amount;
+                self.protocol_quote_fees_owed += protocol_fee_amount;
+
+                (output_amount, input_amount)
             }
             SwapType::Sell => {
-                self.base_amount += input_amount;
+                self.base_amount += reserve_input_amount;
                 self.quote_amount -= output_amount;
+
+                self.cumulative_base_fees += fee_amount;
+                self.protocol_base_fees_owed += protocol_fee_amount;
+
+                (input_amount, output_amount)
             }
-        }
+        };
 
         let new_k = self.k();
 
         require_gte!(new_k, k, AmmError::ConstantProductInvariantFailed);
 
+        self.cumulative_base_volume += base_volume as u128;
+        self.cumulative_quote_volume += quote_volume as u128;
+
         Ok(output_amount)
     }
 
+    /// The id of the batch that orders submitted at `slot` go into and the
+    /// slot that batch can be cleared at.
+    pub fn get_batch(&self, slot: Slot) -> Result<(u64, Slot)> {
+        let batch_auction_slots = self
+            .batch_auction_slots
+            .ok_or(error!(AmmError::NotInBatchAuctionMode))?;
+
+        let batch_id = (slot - self.created_at_slot) / batch_auction_slots;
+        let end_slot = self.created_at_slot + (batch_id + 1) * batch_auction_slots;
+
+        Ok((batch_id, end_slot))
+    }
+
+    /// Clears a batch of buys spending `total_quote_in` and sells spending
+    /// `total_base_in` at a single price. Buys and sells are matched against
+    /// each other and only the imbalance is traded against the reserves.
+    pub fn clear_batch(
+        &mut self,
+        total_quote_in: u64,
+        total_base_in: u64,
+    ) -> Result<BatchClearing> {
+        let quote_reserve = self.quote_amount as u128;
+        let base_reserve = self.base_amount as u128;
+
+        require!(quote_reserve != 0, AmmError::NoReserves);
+        require!(base_reserve != 0, AmmError::NoReserves);
+
+        let quote_in = total_quote_in as u128;
+        let base_in = total_base_in as u128;
+
+        // if buys outweigh sells, the pool sells the imbalance at the clearing
+        // price p, taking its 99/100 fee. solving for the p at which the
+        // pool's output is exactly what buyers are owed beyond the sells,
+        // i.e. quote_in / p - base_in, gives:
+        // p = (quote_in + quote_reserve * 100 / 99) / (base_reserve + base_in)
+        // we round up, in the pool's favor
+        let buy_price = (PRICE_SCALE * (99 * quote_in + 100 * quote_reserve))
+            .div_ceil(99 * (base_reserve + base_in));
+
+        // and symmetrically if sells outweigh buys, rounding down:
+        // p = (quote_reserve + quote_in) / (base_in + base_reserve * 100 / 99)
+        let sell_price =
+            PRICE_SCALE * 99 * (quote_reserve + quote_in) / (99 * base_in + 100 * base_reserve);
+
+        let quote_value_in = quote_in * PRICE_SCALE;
+        let base_value_in = |price: u128| {
+            base_in
+                .checked_mul(price)
+                .ok_or(error!(AmmError::InputAmountOverflow))
+        };
+
+        let (price, imbalance) = if quote_in == 0 && base_in == 0 {
+            (quote_reserve * PRICE_SCALE / base_reserve, None)
+        } else if quote_value_in >= base_value_in(buy_price)? {
+            (buy_price, Some(SwapType::Buy))
+        } else if base_value_in(sell_price)? >= quote_value_in {
+            (sell_price, Some(SwapType::Sell))
+        } else {
+            // within the pool's fee, buys and sells can be matched against
+            // each other without trading against the pool at all
+            (quote_value_in / base_in, None)
+        };
+
+        require_neq!(price, 0, AmmError::AssertFailed);
+
+        let base_owed = quote_value_in / price;
+        let quote_owed = base_value_in(price)? / PRICE_SCALE;
+
+        // the imbalance goes through the reserves like any other swap, and we
+        // cap what's owed at what's available so that rounding in the clearing
+        // price can never take tokens from LPs
+        let (base_owed, quote_owed) = match imbalance {
+            Some(SwapType::Buy) => {
+                let base_from_pool = self
+                    .swap_through_reserves((quote_in - quote_owed) as u64, SwapType::Buy)?
+                    as u128;
+                (min(base_owed, base_in + base_from_pool), quote_owed)
+            }
+            Some(SwapType::Sell) => {
+                let quote_from_pool = self
+                    .swap_through_reserves((base_in - base_owed) as u64, SwapType::Sell)?
+                    as u128;
+                (base_owed, min(quote_owed, quote_in + quote_from_pool))
+            }
+            None => (min(base_owed, base_in), min(quote_owed, quote_in)),
+        };
+
+        // the imbalance's volume was counted by the swap, so this is what was
+        // matched between buys and sells
+        self.cumulative_base_volume += min(base_owed, base_in);
+        self.cumulative_quote_volume += min(quote_owed, quote_in);
+
+        Ok(BatchClearing {
+            price,
+            // both are at most one side's input plus the pool's output
+            base_owed: base_owed as u64,
+            quote_owed: quote_owed as u64,
+        })
+    }
+
+    /// Settles the outstanding flash loan once it's been repaid, adding its
+    /// fees to the reserves like swap fees.
+    pub fn repay_flash_loan(&mut self) -> Result<FlashLoan> {
+        let flash_loan = self
+            .flash_loan
+            .take()
+            .ok_or(error!(AmmError::NoFlashLoan))?;
+
+        let protocol_fee = |fee: u64| (fee as u128 * self.protocol_fee_bps as u128 / 10_000) as u64;
+        let protocol_base_fee = protocol_fee(flash_loan.base_fee);
+        let protocol_quote_fee = protocol_fee(flash_loan.quote_fee);
+
+        self.base_amount += flash_loan.base_fee - protocol_base_fee;
+        self.quote_amount += flash_loan.quote_fee - protocol_quote_fee;
+
+        self.protocol_base_fees_owed += protocol_base_fee;
+        self.protocol_quote_fees_owed += protocol_quote_fee;
+
+        self.cumulative_base_fees += flash_loan.base_fee as u128;
+        self.cumulative_quote_fees += flash_loan.quote_fee as u128;
+
+        Ok(flash_loan)
+    }
+
+    /// How far the pool's price is from the oracle's last observation, in
+    /// basis points of the observation.
+    pub fn get_price_deviation_bps(&self) -> u128 {
+        let last_observation = self.oracle.last_observation;
+
+        if self.base_amount == 0 || last_observation == 0 {
+            return 0;
+        }
+
+        let price = (self.quote_amount as u128 * PRICE_SCALE) / self.base_amount as u128;
+
+        // can't overflow because prices are at most MAX_PRICE (~1e31)
+        price.abs_diff(last_observation) * 10_000 / last_observation
+    }
+
+    /// How much of `input_amount` a zap should swap so that the rest of the
+    /// input and the swap's output are in the pool's ratio after the swap.
+    pub fn get_zap_in_swap_amount(&self, input_amount: u64, swap_type: SwapType) -> Result<u64> {
+        let input_reserve = match swap_type {
+            SwapType::Buy => self.quote_amount,
+            SwapType::Sell => self.base_amount,
+        } as u128;
+
+        require!(input_reserve != 0, AmmError::NoReserves);
+
+        // for a swap of s out of an input of a into an input reserve of r,
+        // solving (a - s) / (r + s) = output / (output_reserve - output) with
+        // the 99/100 fee from `swap` gives:
+        // s = (sqrt(r * (39601 * r + 39600 * a)) - 199 * r) / 198
+        let radicand = input_reserve
+            .checked_mul(39601 * input_reserve + 39600 * input_amount as u128)
+            .ok_or(error!(AmmError::InputAmountOverflow))?;
+
+        let swap_amount = (isqrt(radicand) - 199 * input_reserve) / 198;
+
+        // can't overflow a u64 because it's less than `input_amount`
+        Ok(swap_amount as u64)
+    }
+
+    /// The LP supply including the locked LP tokens, which is what each LP
+    /// token's share of the reserves is based on.
+    pub fn total_lp_supply(&self, lp_mint_supply: u64) -> u64 {
+        lp_mint_supply + self.locked_lp_tokens
+    }
+
     /// Get the number of base and quote tokens withdrawable from a position
     pub fn get_base_and_quote_withdrawable(
         &self,
@@ -165,7 +491,10 @@ impl Amm {
 
     /// Returns the time-weighted average price since market creation in UQ64x32 form.
     pub fn get_twap(&self) -> Result<u128> {
-        let slots_passed = (self.oracle.last_updated_slot - self.created_at_slot) as u128;
+        // paused slots were skipped by the aggregator, so we skip them here too
+        let slots_passed = (self.oracle.last_updated_slot
+            - self.created_at_slot
+            - self.total_paused_slots) as u128;
 
         require_neq!(slots_passed, 0, AmmError::NoSlotsPassed);
         require!(self.oracle.aggregator != 0, AmmError::AssertFailed);
@@ -178,6 +507,33 @@ impl Amm {
     ///
     /// Returns an observation if one was recorded.
     pub fn update_twap(&mut self, current_slot: Slot) -> Result<Option<u128>> {
+        if self.base_amount == 0 || self.quote_amount == 0 {
+            return Ok(None);
+        }
+
+        // we store prices as quote units / base units scaled by 1e12.
+        // for example, suppose META is $100 and there's 400 USDC & 4 META in
+        // this pool. USDC has 6 decimals and META has 9, so we have:
+        // - 400 * 1,000,000   = 400,000,000 USDC units
+        // - 4 * 1,000,000,000 = 4,000,000,000 META units (hansons)
+        // so there's (400,000,000 / 4,000,000,000) or 0.1 USDC units per hanson,
+        // which is 100,000,000,000 when scaled by 1e12.
+        let price = Price::from_amounts(self.quote_amount, self.base_amount)
+            .ok_or(error!(AmmError::NoReserves))?;
+
+        self.record_observation(current_slot, price.to_scaled())
+    }
+
+    /// Records an observation that moves from the last observation towards
+    /// `price`, if the oracle is due for one. Batch auctions call this
+    /// directly with their clearing prices.
+    ///
+    /// Returns an observation if one was recorded.
+    pub fn record_observation(&mut self, current_slot: Slot, price: u128) -> Result<Option<u128>> {
+        if self.is_paused() {
+            return Ok(None);
+        }
+
         let oracle = &mut self.oracle;
         // a manipulator is likely to be "bursty" with their usage, such as a
         // validator who abuses their slots to manipulate the TWAP.
@@ -201,19 +557,6 @@ impl Amm {
             return Ok(None);
         }
 
-        if self.base_amount == 0 || self.quote_amount == 0 {
-            return Ok(None);
-        }
-
-        // we store prices as quote units / base units scaled by 1e12.
-        // for example, suppose META is $100 and there's 400 USDC & 4 META in
-        // this pool. USDC has 6 decimals and META has 9, so we have:
-        // - 400 * 1,000,000   = 400,000,000 USDC units
-        // - 4 * 1,000,000,000 = 4,000,000,000 META units (hansons)
-        // so there's (400,000,000 / 4,000,000,000) or 0.1 USDC units per hanson,
-        // which is 100,000,000,000 when scaled by 1e12.
-        let price = (self.quote_amount as u128 * PRICE_SCALE) / self.base_amount as u128;
-
         let last_observation = oracle.last_observation;
 
         let new_observation = if price > last_observation {
@@ -276,6 +619,46 @@ impl Amm {
         Ok(Some(new_observation))
     }
 
+    pub fn is_paused(&self) -> bool {
+        self.paused_at_slot.is_some()
+    }
+
+    /// Records a final observation and freezes the TWAP oracle.
+    pub fn pause(&mut self, current_slot: Slot) -> Result<()> {
+        require!(!self.is_paused(), AmmError::AmmPaused);
+
+        self.update_twap(current_slot)?;
+
+        self.paused_at_slot = Some(current_slot);
+
+        Ok(())
+    }
+
+    /// Unfreezes the TWAP oracle, moving its last update forward by the
+    /// length of the pause so that the next observation isn't weighted by the
+    /// paused slots.
+    pub fn unpause(&mut self, current_slot: Slot) -> Result<u64> {
+        let paused_at_slot = self.paused_at_slot.ok_or(error!(AmmError::AmmNotPaused))?;
+
+        let paused_slots = current_slot - paused_at_slot;
+
+        self.oracle.last_updated_slot += paused_slots;
+        self.total_paused_slots += paused_slots;
+        self.paused_at_slot = None;
+
+        Ok(paused_slots)
+    }
+
+    pub fn validate_protocol_fee_bps(protocol_fee_bps: u16) -> Result<()> {
+        require_gte!(
+            MAX_PROTOCOL_FEE_BPS,
+            protocol_fee_bps,
+            AmmError::InvalidProtocolFee
+        );
+
+        Ok(())
+    }
+
     pub fn invariant(&self) -> Result<()> {
         let oracle = &self.oracle;
 
@@ -286,6 +669,23 @@ impl Amm {
     }
 }
 
+/// The largest integer whose square is at most `n`.
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // newton's method, starting from ceil(n / 2) so that we can't overflow
+    let mut x = n;
+    let mut y = (n >> 1) + (n & 1);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+
+    x
+}
+
 #[macro_export]
 macro_rules! generate_amm_seeds {
     ($amm:expr) => {{
@@ -300,6 +700,7 @@ macro_rules! generate_amm_seeds {
 
 #[cfg(test)]
 mod simple_amm_tests {
+    use super::isqrt;
     use crate::{error::AmmError, state::*};
     use SwapType::{Buy, Sell};
 
@@ -340,6 +741,253 @@ mod simple_amm_tests {
         assert_eq!(amm_clone.k(), 30); // 2 x 15
     }
 
+    #[test]
+    pub fn swap_accounting() {
+        let mut amm = Amm {
+            base_amount: 1_000,
+            quote_amount: 1_000,
+            ..Amm::default()
+        };
+
+        let base_out = amm.swap(250, Buy).unwrap();
+        assert_eq!(amm.cumulative_quote_volume, 250);
+        assert_eq!(amm.cumulative_base_volume, base_out as u128);
+        assert_eq!(amm.cumulative_quote_fees, 3); // 2.5 rounded up
+        assert_eq!(amm.cumulative_base_fees, 0);
+
+        let quote_out = amm.swap(100, Sell).unwrap();
+        assert_eq!(amm.cumulative_quote_volume, 250 + quote_out as u128);
+        assert_eq!(amm.cumulative_base_volume, base_out as u128 + 100);
+        assert_eq!(amm.cumulative_quote_fees, 3);
+        assert_eq!(amm.cumulative_base_fees, 1);
+
+        assert_eq!(amm.swap_count, 2);
+    }
+
+    #[test]
+    pub fn protocol_fees() {
+        let mut amm = Amm {
+            base_amount: 100_000,
+            quote_amount: 100_000,
+            protocol_fee_bps: 5_000,
+            ..Amm::default()
+        };
+        let mut amm_without_fee = Amm {
+            protocol_fee_bps: 0,
+            ..amm.clone()
+        };
+
+        // the protocol fee doesn't change what traders get back
+        assert_eq!(
+            amm.swap(10_000, Buy).unwrap(),
+            amm_without_fee.swap(10_000, Buy).unwrap()
+        );
+
+        // half of the 100 quote fee stays out of the reserves
+        assert_eq!(amm.protocol_quote_fees_owed, 50);
+        assert_eq!(amm.quote_amount, amm_without_fee.quote_amount - 50);
+        assert_eq!(amm.base_amount, amm_without_fee.base_amount);
+
+        amm.swap(1_000, Sell).unwrap();
+        assert_eq!(amm.protocol_base_fees_owed, 5);
+        assert_eq!(amm.protocol_quote_fees_owed, 50);
+
+        assert!(Amm::validate_protocol_fee_bps(5_000).is_ok());
+        assert!(Amm::validate_protocol_fee_bps(5_001).is_err());
+    }
+
+    #[test]
+    pub fn integer_sqrt() {
+        for n in 0..10_000u128 {
+            let root = isqrt(n);
+            assert!(root * root <= n);
+            assert!((root + 1) * (root + 1) > n);
+        }
+
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    pub fn zap_in_swap_amount() {
+        for (base_amount, quote_amount, input_amount, swap_type) in [
+            (1_000_000, 1_000_000, 10_000, Buy),
+            (1_000_000, 1_000_000, 10_000_000, Buy),
+            (5_000_000_000, 2_000_000, 750_000, Buy),
+            (5_000_000_000, 2_000_000, 1_000_000_000, Sell),
+        ] {
+            let mut amm = Amm {
+                base_amount,
+                quote_amount,
+                ..Amm::default()
+            };
+
+            let swap_amount = amm.get_zap_in_swap_amount(input_amount, swap_type).unwrap();
+            let output_amount = amm.swap(swap_amount, swap_type).unwrap() as u128;
+
+            let (input_reserve, output_reserve) = match swap_type {
+                Buy => (amm.quote_amount as u128, amm.base_amount as u128),
+                Sell => (amm.base_amount as u128, amm.quote_amount as u128),
+            };
+            let remaining_input = (input_amount - swap_amount) as u128;
+
+            // the rest of the input and the output should be in the pool's
+            // ratio, give or take rounding
+            let lhs = remaining_input * output_reserve;
+            let rhs = output_amount * input_reserve;
+            assert!(lhs.abs_diff(rhs) * 1_000 <= lhs.max(rhs));
+        }
+    }
+
+    #[test]
+    pub fn paused_slots_dont_count_towards_twap() {
+        let mut amm = Amm {
+            base_amount: 5,
+            quote_amount: 50,
+            oracle: TwapOracle::new(0, 10 * PRICE_SCALE, MAX_PRICE),
+            ..Amm::default()
+        };
+
+        amm.pause(ONE_MINUTE_IN_SLOTS).unwrap();
+        assert!(amm.pause(ONE_MINUTE_IN_SLOTS + 1).is_err());
+
+        // the oracle is frozen while paused, even if the price moves
+        amm.quote_amount = 500;
+        assert_eq!(amm.update_twap(10 * ONE_MINUTE_IN_SLOTS).unwrap(), None);
+
+        assert_eq!(
+            amm.unpause(11 * ONE_MINUTE_IN_SLOTS).unwrap(),
+            10 * ONE_MINUTE_IN_SLOTS
+        );
+        assert!(amm.unpause(11 * ONE_MINUTE_IN_SLOTS).is_err());
+        assert_eq!(amm.get_twap().unwrap(), 10 * PRICE_SCALE);
+
+        // only the minute after the pause counts towards the new price
+        amm.update_twap(12 * ONE_MINUTE_IN_SLOTS).unwrap();
+        assert_eq!(amm.get_twap().unwrap(), 55 * PRICE_SCALE);
+    }
+
+    #[test]
+    pub fn price_impact_limit() {
+        let mut amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            oracle: TwapOracle::new(0, PRICE_SCALE, MAX_PRICE),
+            max_price_impact_bps: Some(500),
+            ..Amm::default()
+        };
+
+        // moves the price by ~2%
+        amm.swap(10_000, Buy).unwrap();
+
+        // would move it by ~10% in total
+        assert_eq!(
+            amm.clone().swap(40_000, Buy).unwrap_err(),
+            AmmError::PriceImpactTooHigh.into()
+        );
+
+        amm.swap(10_000, Sell).unwrap();
+
+        // a pool that's already past the limit can only move back towards
+        // the observation
+        let mut far_from_observation = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 2_000_000,
+            ..amm.clone()
+        };
+        far_from_observation.swap(10_000, Sell).unwrap();
+        assert!(far_from_observation.swap(10_000, Buy).is_err());
+    }
+
+    #[test]
+    pub fn batch_clearing() {
+        for (total_quote_in, total_base_in) in [
+            (0, 0),
+            (50_000, 0),
+            (0, 50_000),
+            (50_000, 10_000),
+            (10_000, 50_000),
+            (50_000, 50_000),
+            (50_100, 50_000),
+        ] {
+            let mut amm = Amm {
+                base_amount: 1_000_000,
+                quote_amount: 1_000_000,
+                ..Amm::default()
+            };
+            let k = amm.k();
+
+            let vault_base = amm.base_amount + total_base_in;
+            let vault_quote = amm.quote_amount + total_quote_in;
+
+            let BatchClearing {
+                price,
+                base_owed,
+                quote_owed,
+            } = amm.clear_batch(total_quote_in, total_base_in).unwrap();
+
+            // LPs don't lose out and the vaults cover what's owed
+            assert!(amm.k() >= k);
+            assert!(amm.base_amount + base_owed <= vault_base);
+            assert!(amm.quote_amount + quote_owed <= vault_quote);
+
+            // everyone gets the clearing price, give or take rounding
+            assert!(base_owed.abs_diff((total_quote_in as u128 * PRICE_SCALE / price) as u64) <= 1);
+            assert!(quote_owed.abs_diff((total_base_in as u128 * price / PRICE_SCALE) as u64) <= 1);
+
+            // net buys clear above the starting price and net sells below it
+            if total_quote_in > total_base_in + total_base_in / 50 {
+                assert!(price > PRICE_SCALE);
+            } else if total_base_in > total_quote_in + total_quote_in / 50 {
+                assert!(price < PRICE_SCALE);
+            }
+        }
+    }
+
+    #[test]
+    pub fn batch_clearing_matches_orders_within_fee() {
+        let mut amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            ..Amm::default()
+        };
+
+        let clearing = amm.clear_batch(50_100, 50_000).unwrap();
+
+        // matched entirely between buys and sells, so the pool doesn't trade
+        assert_eq!(clearing.price, PRICE_SCALE * 501 / 500);
+        assert_eq!(amm.base_amount, 1_000_000);
+        assert_eq!(amm.quote_amount, 1_000_000);
+        assert_eq!(amm.cumulative_quote_volume, 50_100);
+        assert_eq!(amm.cumulative_base_volume, 50_000);
+    }
+
+    #[test]
+    pub fn flash_loan_fees() {
+        let flash_loan = FlashLoan::new(1_000_000, 1, 10_000_000, 10_000_000);
+        assert_eq!(flash_loan.base_fee, 1_000);
+        assert_eq!(flash_loan.quote_fee, 1);
+
+        let mut amm = Amm {
+            base_amount: 10_000_000,
+            quote_amount: 10_000_000,
+            protocol_fee_bps: 5_000,
+            flash_loan: Some(flash_loan),
+            ..Amm::default()
+        };
+
+        assert_eq!(amm.repay_flash_loan().unwrap(), flash_loan);
+        assert_eq!(amm.flash_loan, None);
+        assert_eq!(amm.base_amount, 10_000_500);
+        assert_eq!(amm.quote_amount, 10_000_001);
+        assert_eq!(amm.protocol_base_fees_owed, 500);
+        assert_eq!(amm.cumulative_base_fees, 1_000);
+
+        assert_eq!(
+            amm.repay_flash_loan().unwrap_err(),
+            AmmError::NoFlashLoan.into()
+        );
+    }
+
     #[test]
     pub fn simple_twap_math_amm() {
         let mut amm = Amm {
@@ -350,10 +998,13 @@ mod simple_amm_tests {
         };
 
         // minute hasn't passed since last slot
-        assert_eq!(amm.update_twap(1), None);
+        assert_eq!(amm.update_twap(1).unwrap(), None);
         assert_eq!(amm.oracle.last_updated_slot, 0);
 
-        assert_eq!(amm.update_twap(ONE_MINUTE_IN_SLOTS), Some(10 * PRICE_SCALE));
+        assert_eq!(
+            amm.update_twap(ONE_MINUTE_IN_SLOTS).unwrap(),
+            Some(10 * PRICE_SCALE)
+        );
     }
 
     #[test]
@@ -369,18 +1020,82 @@ mod simple_amm_tests {
 
         let slots_until_overflow = u128::MAX / (u64::MAX as u128 * PRICE_SCALE);
 
-        amm.update_twap(slots_until_overflow as u64);
-        require!(amm.oracle.aggregator > MAX_PRICE * 18_400_000);
+        amm.update_twap(slots_until_overflow as u64).unwrap();
+        assert!(amm.oracle.aggregator > MAX_PRICE * 18_400_000);
         assert_ne!(amm.oracle.aggregator, u128::MAX);
 
-        amm_clone.update_twap(slots_until_overflow as u64 + 1);
+        amm_clone
+            .update_twap(slots_until_overflow as u64 + 1)
+            .unwrap();
         assert_eq!(amm_clone.oracle.aggregator, u128::MAX);
 
         // check that it wraps over
-        amm_clone.update_twap(slots_until_overflow as u64 + 1 + ONE_MINUTE_IN_SLOTS);
+        amm_clone
+            .update_twap(slots_until_overflow as u64 + 1 + ONE_MINUTE_IN_SLOTS)
+            .unwrap();
         assert_eq!(
             amm_clone.oracle.aggregator,
             ONE_MINUTE_IN_SLOTS as u128 * MAX_PRICE - 1
         ); // sub 1 cuz wrap
     }
 }
+
+#[cfg(test)]
+mod migration_tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    // the fields an AMM had before `migrate_amm` existed
+    #[derive(AnchorSerialize)]
+    struct AmmV0 {
+        bump: u8,
+        created_at_slot: u64,
+        lp_mint: Pubkey,
+        base_mint: Pubkey,
+        quote_mint: Pubkey,
+        base_mint_decimals: u8,
+        quote_mint_decimals: u8,
+        base_amount: u64,
+        quote_amount: u64,
+        oracle: TwapOracle,
+        seq_num: u64,
+    }
+
+    #[test]
+    fn migrated_amm_keeps_old_fields_and_zeroes_new_ones() {
+        let old_amm = AmmV0 {
+            bump: 255,
+            created_at_slot: 1_000,
+            lp_mint: Pubkey::new_unique(),
+            base_mint: Pubkey::new_unique(),
+            quote_mint: Pubkey::new_unique(),
+            base_mint_decimals: 9,
+            quote_mint_decimals: 6,
+            base_amount: 5_000,
+            quote_amount: 20_000,
+            oracle: TwapOracle::new(1_000, 4_000, 100),
+            seq_num: 7,
+        };
+
+        let mut data = Amm::DISCRIMINATOR.to_vec();
+        old_amm.serialize(&mut data).unwrap();
+        // `realloc` zero-initializes the new bytes
+        data.resize(8 + std::mem::size_of::<Amm>(), 0);
+
+        let amm = Amm::try_deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(amm.lp_mint, old_amm.lp_mint);
+        assert_eq!(amm.base_amount, 5_000);
+        assert_eq!(amm.quote_amount, 20_000);
+        assert_eq!(amm.oracle.last_observation, 4_000);
+        assert_eq!(amm.seq_num, 7);
+        assert_eq!(amm.payer, Pubkey::default());
+        assert_eq!(amm.cumulative_quote_volume, 0);
+        assert_eq!(amm.locked_lp_tokens, 0);
+        assert_eq!(amm.admin, None);
+        assert!(!amm.is_paused());
+        assert_eq!(amm.max_price_impact_bps, None);
+        assert_eq!(amm.batch_auction_slots, None);
+        assert_eq!(amm.flash_loan, None);
+    }
+}
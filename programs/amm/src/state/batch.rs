@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Amm, BatchClearing, PRICE_SCALE};
+
+/// The orders submitted to a batch-auction AMM during one window. Their
+/// input sits in the AMM's vaults, outside of its reserves, until the batch
+/// is cleared and each order claims its output.
+#[account]
+pub struct Batch {
+    pub amm: Pubkey,
+    pub id: u64,
+    /// The first slot the batch can be cleared at
+    pub end_slot: u64,
+    pub total_quote_in: u64,
+    pub total_base_in: u64,
+    pub unclaimed_order_count: u32,
+    /// The price every order in the batch traded at, once it's cleared
+    pub clearing_price: Option<u128>,
+    /// What's left to pay out to the batch's orders. Payouts round down, so
+    /// some dust can be left over after every order has claimed.
+    pub base_owed: u64,
+    pub quote_owed: u64,
+    /// Paid for the batch account and gets its rent back once every order
+    /// has claimed
+    pub payer: Pubkey,
+    pub bump: u8,
+}
+
+/// A user's orders in a batch. Buys and sells from the same user are tracked
+/// separately, since both clear at the same price.
+#[account]
+pub struct BatchOrder {
+    pub batch: Pubkey,
+    pub user: Pubkey,
+    pub quote_in: u64,
+    pub base_in: u64,
+    /// The least the order will accept for its buys and its sells. If the
+    /// batch would clear at a price that pays less than either, the order is
+    /// left out of the batch and gets its input back instead.
+    pub min_base_out: u64,
+    pub min_quote_out: u64,
+    /// Set when the batch is cleared without this order
+    pub refunded: bool,
+    pub bump: u8,
+}
+
+impl BatchOrder {
+    /// What the order receives in base and quote tokens when its batch
+    /// clears at `clearing_price`.
+    pub fn get_output(&self, clearing_price: u128) -> (u64, u64) {
+        // the batch's totals didn't overflow when it was cleared, so neither
+        // can an order's share of them
+        let base_output = (self.quote_in as u128 * PRICE_SCALE / clearing_price) as u64;
+        let quote_output = (self.base_in as u128 * clearing_price / PRICE_SCALE) as u64;
+
+        (base_output, quote_output)
+    }
+
+    /// Whether the order is happy to trade at `clearing_price`.
+    pub fn accepts(&self, clearing_price: u128) -> bool {
+        let (base_output, quote_output) = self.get_output(clearing_price);
+
+        base_output >= self.min_base_out && quote_output >= self.min_quote_out
+    }
+}
+
+/// What clearing a batch's orders came to.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BatchOrdersClearing {
+    pub clearing: BatchClearing,
+    /// The input of the orders that traded
+    pub total_quote_in: u64,
+    pub total_base_in: u64,
+}
+
+/// Clears `orders` against `amm`, refunding every order that wouldn't get
+/// its minimum output at the clearing price. Leaving orders out moves the
+/// clearing price, which can push other orders past their minimums, so we
+/// repeat until every order that's left accepts the price. Each pass refunds
+/// at least one order, so this ends.
+pub fn clear_batch_orders(amm: &mut Amm, orders: &mut [BatchOrder]) -> Result<BatchOrdersClearing> {
+    loop {
+        let (total_quote_in, total_base_in) = orders
+            .iter()
+            .filter(|order| !order.refunded)
+            .fold((0u64, 0u64), |(quote_in, base_in), order| {
+                (quote_in + order.quote_in, base_in + order.base_in)
+            });
+
+        // clearing changes the reserves, so we only keep it once every order
+        // has accepted the price
+        let mut next_amm = amm.clone();
+        let clearing = next_amm.clear_batch(total_quote_in, total_base_in)?;
+
+        let mut refunded_any = false;
+        for order in orders.iter_mut().filter(|order| !order.refunded) {
+            if !order.accepts(clearing.price) {
+                order.refunded = true;
+                refunded_any = true;
+            }
+        }
+
+        if !refunded_any {
+            *amm = next_amm;
+
+            return Ok(BatchOrdersClearing {
+                clearing,
+                total_quote_in,
+                total_base_in,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use super::*;
+
+    fn order(quote_in: u64, base_in: u64, min_base_out: u64, min_quote_out: u64) -> BatchOrder {
+        BatchOrder {
+            batch: Pubkey::default(),
+            user: Pubkey::new_unique(),
+            quote_in,
+            base_in,
+            min_base_out,
+            min_quote_out,
+            refunded: false,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn refunds_orders_past_their_minimum() {
+        let mut amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            ..Amm::default()
+        };
+
+        let mut orders = [
+            // no minimums, so these always trade
+            order(50_000, 0, 0, 0),
+            order(100_000, 0, 0, 0),
+            // the buys push the price to ~1.17, which this one won't pay
+            order(10_000, 0, 9_800, 0),
+        ];
+
+        let BatchOrdersClearing {
+            clearing,
+            total_quote_in,
+            total_base_in,
+        } = clear_batch_orders(&mut amm, &mut orders).unwrap();
+
+        assert!(!orders[0].refunded);
+        assert!(!orders[1].refunded);
+        assert!(orders[2].refunded);
+        assert_eq!(total_quote_in, 150_000);
+        assert_eq!(total_base_in, 0);
+
+        // the refunded order's input never reached the reserves
+        assert!(amm.quote_amount <= 1_000_000 + 150_000);
+        assert!(orders[..2]
+            .iter()
+            .all(|order| order.accepts(clearing.price)));
+    }
+
+    #[test]
+    fn refunding_everyone_leaves_the_reserves_alone() {
+        let mut amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            ..Amm::default()
+        };
+
+        // these would match at exactly 1, but both want better than that
+        let mut orders = [order(10_000, 0, 10_001, 0), order(0, 10_000, 0, 10_001)];
+
+        let cleared = clear_batch_orders(&mut amm, &mut orders).unwrap();
+
+        assert!(orders.iter().all(|order| order.refunded));
+        assert_eq!(cleared.total_quote_in, 0);
+        assert_eq!(cleared.total_base_in, 0);
+        assert_eq!(cleared.clearing.base_owed, 0);
+        assert_eq!(amm.base_amount, 1_000_000);
+        assert_eq!(amm.quote_amount, 1_000_000);
+    }
+}
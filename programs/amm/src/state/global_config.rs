@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// The protocol fee configuration that every AMM is created with. There's
+/// one per program, so the protocol rather than each AMM's creator decides
+/// who collects protocol fees.
+#[account]
+pub struct GlobalConfig {
+    /// Can collect protocol fees and change this configuration.
+    pub fee_authority: Pubkey,
+    /// The owner of the token accounts that protocol fees are collected to.
+    pub protocol_fee_recipient: Pubkey,
+    /// The protocol fee that new AMMs are created with, in basis points of
+    /// each swap fee. An AMM's fee can't change after it's created, so
+    /// changing this doesn't affect the markets of proposals that are
+    /// already trading.
+    pub protocol_fee_bps: u16,
+    pub bump: u8,
+    pub seq_num: u64,
+}
+
+impl GlobalConfig {
+    /// Deserializes the global config, or returns `None` if it hasn't been
+    /// initialized yet.
+    pub fn load_if_exists(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.owner != &crate::ID {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &info.try_borrow_data()?[..],
+        )?))
+    }
+}
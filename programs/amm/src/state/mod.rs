@@ -1,11 +1,57 @@
 pub use amm::*;
+pub use batch::*;
+pub use global_config::*;
+pub use order_book::*;
+pub use position::*;
+pub use trader_record::*;
 
 pub mod amm;
+pub mod batch;
+pub mod global_config;
+pub mod order_book;
+pub mod position;
+pub mod trader_record;
 
 pub const TEN_SECONDS_IN_SLOTS: u64 = 25;
 pub const ONE_MINUTE_IN_SLOTS: u64 = TEN_SECONDS_IN_SLOTS * 6;
-pub const PRICE_SCALE: u128 = 1_000_000_000_000;
-pub const MAX_PRICE: u128 = u64::MAX as u128 * PRICE_SCALE;
+pub use price_math::PRICE_SCALE;
+pub const MAX_PRICE: u128 = price_math::Price::MAX.to_scaled();
+
+/// Protocol fees can take at most half of each swap fee, so that LPs always
+/// earn something.
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 5_000;
+
+/// LP tokens that are locked forever on a pool's first deposit, so that its
+/// reserves can never be fully withdrawn. Like Uniswap v2's
+/// `MINIMUM_LIQUIDITY`.
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;
 
 pub const AMM_SEED_PREFIX: &[u8] = b"amm__";
 pub const AMM_LP_MINT_SEED_PREFIX: &[u8] = b"amm_lp_mint";
+pub const GLOBAL_CONFIG_SEED_PREFIX: &[u8] = b"global_config";
+pub const TRADER_RECORD_SEED_PREFIX: &[u8] = b"trader_record";
+pub const ORDER_BOOK_SEED_PREFIX: &[u8] = b"order_book";
+pub const BATCH_SEED_PREFIX: &[u8] = b"batch";
+pub const BATCH_ORDER_SEED_PREFIX: &[u8] = b"batch_order";
+pub const POSITION_SEED_PREFIX: &[u8] = b"position";
+
+/// Clearing a batch needs all of its orders passed in, so this has to fit in
+/// a transaction.
+pub const MAX_BATCH_ORDERS: u32 = 16;
+
+pub const MAX_LIMIT_ORDERS: usize = 32;
+/// So that one user can't fill up an order book on their own.
+pub const MAX_LIMIT_ORDERS_PER_OWNER: usize = 4;
+/// Limit orders expire at most a day after they're placed, after which anyone
+/// can cancel them. Otherwise orders that never fill could hold an order book
+/// full forever.
+pub const MAX_LIMIT_ORDER_LIFETIME_SLOTS: u64 = ONE_MINUTE_IN_SLOTS * 60 * 24;
+/// The smallest a limit order can be, in basis points of the AMM's reserve of
+/// its input token, so that filling up an order book ties up real capital.
+pub const MIN_LIMIT_ORDER_BPS: u64 = 10;
+/// The share of each filled order's output that goes to whoever filled it.
+pub const FILL_BOUNTY_BPS: u64 = 10;
+
+/// The fee on each token lent by a flash loan, which goes to LPs and the
+/// protocol like swap fees.
+pub const FLASH_LOAN_FEE_BPS: u64 = 10;
@@ -0,0 +1,300 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::state::{Amm, SwapType, FILL_BOUNTY_BPS, PRICE_SCALE};
+
+#[derive(Clone, Copy, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct LimitOrder {
+    pub id: u64,
+    pub owner: Pubkey,
+    /// `Buy` spends quote tokens on base tokens, `Sell` spends base tokens on
+    /// quote tokens
+    pub swap_type: SwapType,
+    pub input_amount: u64,
+    /// The worst average price the order will fill at, in the same units as
+    /// AMM prices. For buys this is a maximum and for sells it's a minimum.
+    pub limit_price: u128,
+    /// What the owner receives, net of the filler's bounty. `None` until the
+    /// order is filled.
+    pub output_amount: Option<u64>,
+    /// Once this slot has passed, the order can't be filled and anyone can
+    /// cancel it.
+    pub expires_at_slot: u64,
+}
+
+impl LimitOrder {
+    /// The least output the order can receive without filling past its
+    /// limit price.
+    pub fn min_output_amount(&self) -> Option<u64> {
+        let min_output_amount = match self.swap_type {
+            SwapType::Buy => {
+                (self.input_amount as u128 * PRICE_SCALE).checked_div(self.limit_price)?
+            }
+            SwapType::Sell => {
+                (self.input_amount as u128).checked_mul(self.limit_price)? / PRICE_SCALE
+            }
+        };
+
+        min_output_amount.try_into().ok()
+    }
+
+    pub fn is_expired(&self, current_slot: u64) -> bool {
+        current_slot > self.expires_at_slot
+    }
+
+    /// What the order's owner is owed, as a base amount and a quote amount:
+    /// its output if it was filled and its input otherwise.
+    pub fn amounts_owed(&self) -> (u64, u64) {
+        match (self.swap_type, self.output_amount) {
+            (SwapType::Buy, Some(output_amount)) => (output_amount, 0),
+            (SwapType::Sell, Some(output_amount)) => (0, output_amount),
+            (SwapType::Buy, None) => (0, self.input_amount),
+            (SwapType::Sell, None) => (self.input_amount, 0),
+        }
+    }
+}
+
+/// Limit orders that rest against an AMM. Each order's input is escrowed in
+/// the order book's token accounts until it's filled or removed, and anyone
+/// can fill orders whose limit price the pool has crossed in exchange for a
+/// bounty.
+#[account]
+pub struct OrderBook {
+    pub amm: Pubkey,
+    pub next_order_id: u64,
+    /// Filled orders are paid out and removed when whoever fills them passes
+    /// in their owners' token accounts. Otherwise they stay here until their
+    /// owners remove them or they expire and someone cancels them.
+    pub orders: Vec<LimitOrder>,
+    pub bump: u8,
+    pub seq_num: u64,
+}
+
+/// The token movements that a batch of fills needs.
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct OrderFills {
+    pub filled_order_ids: Vec<u64>,
+    /// Input that moves from the order book into the AMM
+    pub base_to_amm: u64,
+    pub quote_to_amm: u64,
+    /// Output that moves from the AMM into the order book, including bounties
+    pub base_from_amm: u64,
+    pub quote_from_amm: u64,
+    /// What the order book pays the filler
+    pub base_bounty: u64,
+    pub quote_bounty: u64,
+}
+
+impl OrderBook {
+    /// Deserializes an AMM's order book, or returns `None` if it hasn't been
+    /// initialized.
+    pub fn load_if_exists(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.owner != &crate::ID {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &info.try_borrow_data()?[..],
+        )?))
+    }
+
+    pub fn orders_owned_by(&self, owner: Pubkey) -> usize {
+        self.orders
+            .iter()
+            .filter(|order| order.owner == owner)
+            .count()
+    }
+
+    pub fn find_order(&self, order_id: u64) -> Result<usize> {
+        self.orders
+            .iter()
+            .position(|order| order.id == order_id)
+            .ok_or(error!(AmmError::LimitOrderNotFound))
+    }
+
+    /// Fills every unfilled, unexpired order that can be swapped through
+    /// `amm` at or better than its limit price, in the order they were
+    /// placed. Orders that can't be filled yet are left alone.
+    pub fn fill_orders(&mut self, amm: &mut Amm, current_slot: u64) -> OrderFills {
+        let mut fills = OrderFills::default();
+
+        for order in self.orders.iter_mut() {
+            if order.output_amount.is_some() || order.is_expired(current_slot) {
+                continue;
+            }
+
+            let min_output_amount = match order.min_output_amount() {
+                Some(min_output_amount) => min_output_amount,
+                None => continue,
+            };
+
+            // a failed swap can leave the AMM half-updated, so we swap on a
+            // copy and only keep it if the order fills
+            let mut next_amm = amm.clone();
+            let output_amount = match next_amm.swap(order.input_amount, order.swap_type) {
+                Ok(output_amount) if output_amount >= min_output_amount => output_amount,
+                _ => continue,
+            };
+            *amm = next_amm;
+
+            // can't overflow a u64 because it's less than `output_amount`
+            let bounty = (output_amount as u128 * FILL_BOUNTY_BPS as u128 / 10_000) as u64;
+
+            match order.swap_type {
+                SwapType::Buy => {
+                    fills.quote_to_amm += order.input_amount;
+                    fills.base_from_amm += output_amount;
+                    fills.base_bounty += bounty;
+                }
+                SwapType::Sell => {
+                    fills.base_to_amm += order.input_amount;
+                    fills.quote_from_amm += output_amount;
+                    fills.quote_bounty += bounty;
+                }
+            }
+
+            order.output_amount = Some(output_amount - bounty);
+            fills.filled_order_ids.push(order.id);
+        }
+
+        fills
+    }
+}
+
+#[macro_export]
+macro_rules! generate_order_book_seeds {
+    ($order_book:expr) => {{
+        &[
+            ORDER_BOOK_SEED_PREFIX,
+            $order_book.amm.as_ref(),
+            &[$order_book.bump],
+        ]
+    }};
+}
+
+#[cfg(test)]
+mod order_book_tests {
+    use super::*;
+    use crate::state::{TwapOracle, MAX_PRICE};
+    use SwapType::{Buy, Sell};
+
+    fn order(id: u64, swap_type: SwapType, input_amount: u64, limit_price: u128) -> LimitOrder {
+        LimitOrder {
+            id,
+            owner: Pubkey::default(),
+            swap_type,
+            input_amount,
+            limit_price,
+            output_amount: None,
+            expires_at_slot: 100,
+        }
+    }
+
+    #[test]
+    fn fills_crossed_orders() {
+        let mut amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            oracle: TwapOracle::new(0, PRICE_SCALE, MAX_PRICE),
+            ..Amm::default()
+        };
+
+        let mut order_book = OrderBook {
+            amm: Pubkey::default(),
+            next_order_id: 3,
+            orders: vec![
+                // willing to pay up to 1.05, so fills after the 1% fee
+                order(0, Buy, 1_000, PRICE_SCALE * 105 / 100),
+                // only buys at 0.5
+                order(1, Buy, 1_000, PRICE_SCALE / 2),
+                // sells at 0.9 or better
+                order(2, Sell, 2_000, PRICE_SCALE * 9 / 10),
+            ],
+            bump: 255,
+            seq_num: 0,
+        };
+
+        let fills = order_book.fill_orders(&mut amm, 0);
+
+        assert_eq!(fills.filled_order_ids, vec![0, 2]);
+        assert_eq!(fills.quote_to_amm, 1_000);
+        assert_eq!(fills.base_to_amm, 2_000);
+
+        let [buy, unfilled, sell] = &order_book.orders[..] else {
+            panic!("orders shouldn't be removed by fills");
+        };
+        assert_eq!(
+            buy.output_amount.unwrap() + fills.base_bounty,
+            fills.base_from_amm
+        );
+        assert_eq!(
+            sell.output_amount.unwrap() + fills.quote_bounty,
+            fills.quote_from_amm
+        );
+        assert!(unfilled.output_amount.is_none());
+        assert_eq!(amm.swap_count, 2);
+
+        // filled orders aren't filled again
+        let fills = order_book.fill_orders(&mut amm, 0);
+        assert!(fills.filled_order_ids.is_empty());
+        assert_eq!(amm.swap_count, 2);
+    }
+
+    #[test]
+    fn leaves_amm_untouched_when_nothing_fills() {
+        let mut amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            max_price_impact_bps: Some(100),
+            oracle: TwapOracle::new(0, PRICE_SCALE, MAX_PRICE),
+            ..Amm::default()
+        };
+
+        let mut order_book = OrderBook {
+            amm: Pubkey::default(),
+            next_order_id: 1,
+            // would fill at its price but exceeds the AMM's price impact limit
+            orders: vec![order(0, Buy, 100_000, 2 * PRICE_SCALE)],
+            bump: 255,
+            seq_num: 0,
+        };
+
+        let fills = order_book.fill_orders(&mut amm, 0);
+
+        assert_eq!(fills, OrderFills::default());
+        assert_eq!(amm.base_amount, 1_000_000);
+        assert_eq!(amm.quote_amount, 1_000_000);
+    }
+
+    #[test]
+    fn doesnt_fill_expired_orders() {
+        let mut amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            oracle: TwapOracle::new(0, PRICE_SCALE, MAX_PRICE),
+            ..Amm::default()
+        };
+
+        let mut order_book = OrderBook {
+            amm: Pubkey::default(),
+            next_order_id: 1,
+            orders: vec![order(0, Buy, 1_000, 2 * PRICE_SCALE)],
+            bump: 255,
+            seq_num: 0,
+        };
+
+        let fills = order_book.fill_orders(&mut amm, 101);
+
+        assert_eq!(fills, OrderFills::default());
+        assert!(order_book.orders[0].output_amount.is_none());
+        // the expired order is refunded its input
+        assert_eq!(order_book.orders[0].amounts_owed(), (0, 1_000));
+
+        let fills = order_book.fill_orders(&mut amm, 100);
+        assert_eq!(fills.filled_order_ids, vec![0]);
+        assert_eq!(
+            order_book.orders[0].amounts_owed(),
+            (order_book.orders[0].output_amount.unwrap(), 0)
+        );
+    }
+}
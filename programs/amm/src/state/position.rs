@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+/// An LP position that records where its liquidity came from. The position
+/// holds its LP tokens in an account it owns, so unlike fungible LP tokens it
+/// can be locked as a whole, e.g. by a proposal.
+#[account]
+pub struct Position {
+    pub amm: Pubkey,
+    pub owner: Pubkey,
+    pub nonce: u64,
+    /// The LP tokens minted to the position by `add_liquidity` and `zap_in`
+    pub lp_tokens: u64,
+    /// What was actually deposited for those LP tokens
+    pub base_deposited: u64,
+    pub quote_deposited: u64,
+    /// The slot of the latest deposit
+    pub deposit_slot: u64,
+    /// Whoever has locked the position. Only they can unlock it, and the
+    /// owner can't close it while it's locked.
+    pub locker: Option<Pubkey>,
+    pub bump: u8,
+    pub seq_num: u64,
+}
+
+impl Position {
+    pub fn record_deposit(
+        &mut self,
+        lp_tokens: u64,
+        base_amount: u64,
+        quote_amount: u64,
+        slot: u64,
+    ) {
+        self.lp_tokens += lp_tokens;
+        self.base_deposited += base_amount;
+        self.quote_deposited += quote_amount;
+        self.deposit_slot = slot;
+        self.seq_num += 1;
+    }
+}
+
+#[macro_export]
+macro_rules! generate_position_seeds {
+    ($position:expr, $nonce_bytes:expr) => {{
+        &[
+            POSITION_SEED_PREFIX,
+            $position.amm.as_ref(),
+            $position.owner.as_ref(),
+            $nonce_bytes.as_ref(),
+            &[$position.bump],
+        ]
+    }};
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Amm;
+
+/// Marks that a user has traded on an AMM, so that the AMM counts each trader
+/// once in `trader_count` no matter how many trades they split their volume
+/// into. Trading instructions create it the first time a user trades.
+#[account]
+pub struct TraderRecord {
+    pub amm: Pubkey,
+    pub trader: Pubkey,
+    pub first_trade_slot: u64,
+    pub bump: u8,
+}
+
+impl TraderRecord {
+    /// Fills in a record that `init_if_needed` just created and counts its
+    /// trader on the AMM. Records that already existed are left alone.
+    pub fn record_trade(
+        &mut self,
+        amm_key: Pubkey,
+        amm: &mut Amm,
+        trader: Pubkey,
+        slot: u64,
+        bump: u8,
+    ) {
+        if self.amm != Pubkey::default() {
+            return;
+        }
+
+        *self = TraderRecord {
+            amm: amm_key,
+            trader,
+            first_trade_slot: slot,
+            bump,
+        };
+
+        amm.trader_count += 1;
+    }
+}
+
+#[cfg(test)]
+mod trader_record_tests {
+    use super::*;
+
+    #[test]
+    fn counts_each_trader_once() {
+        let amm_key = Pubkey::new_unique();
+        let mut amm = Amm::default();
+        let trader = Pubkey::new_unique();
+
+        // what `init_if_needed` creates
+        let mut record = TraderRecord {
+            amm: Pubkey::default(),
+            trader: Pubkey::default(),
+            first_trade_slot: 0,
+            bump: 0,
+        };
+
+        record.record_trade(amm_key, &mut amm, trader, 100, 254);
+        record.record_trade(amm_key, &mut amm, trader, 200, 254);
+
+        assert_eq!(amm.trader_count, 1);
+        assert_eq!(record.amm, amm_key);
+        assert_eq!(record.trader, trader);
+        assert_eq!(record.first_trade_slot, 100);
+
+        let mut other_record = TraderRecord {
+            amm: Pubkey::default(),
+            trader: Pubkey::default(),
+            first_trade_slot: 0,
+            bump: 0,
+        };
+        other_record.record_trade(amm_key, &mut amm, Pubkey::new_unique(), 300, 253);
+
+        assert_eq!(amm.trader_count, 2);
+    }
+}
@@ -26,4 +26,78 @@ pub enum AutocratError {
     InsufficientLpTokenBalance,
     #[msg("The LP tokens passed in have less liquidity than the DAO's `min_quote_futarchic_liquidity` or `min_base_futachic_liquidity`")]
     InsufficientLpTokenLock,
+    #[msg("A stream must pay out a non-zero amount")]
+    StreamAmountZero,
+    #[msg("A stream's `end_timestamp` must be after its `start_timestamp`")]
+    InvalidStreamDuration,
+    #[msg("The treasury doesn't have enough tokens to do this")]
+    InsufficientTreasuryBalance,
+    #[msg("Nothing has vested in this stream since the last withdrawal")]
+    NothingToWithdraw,
+    #[msg("This stream has already been cancelled")]
+    StreamAlreadyCancelled,
+    #[msg("The program's or buffer's upgrade authority isn't the DAO's treasury")]
+    InvalidUpgradeAuthority,
+    #[msg("The buffer account isn't an initialized BPF loader buffer")]
+    InvalidBuffer,
+    #[msg("The buffer's contents don't match the hash in the proposal")]
+    BufferHashMismatch,
+    #[msg("A treasury order must swap a non-zero amount in non-zero slices")]
+    OrderAmountZero,
+    #[msg("A treasury order's `end_slot` must be after its `start_slot`")]
+    InvalidOrderDuration,
+    #[msg("Treasury orders can only trade the DAO's token against its USDC")]
+    InvalidOrderAmm,
+    #[msg("The treasury token account doesn't hold the order's input token")]
+    InvalidOrderInputAccount,
+    #[msg("The treasury token account doesn't hold the order's output token")]
+    InvalidOrderOutputAccount,
+    #[msg("The limit price would make the order's minimum output overflow or divide by zero")]
+    InvalidLimitPrice,
+    #[msg("This treasury order has been cancelled")]
+    OrderCancelled,
+    #[msg("This treasury order has already been cranked this slot")]
+    OrderAlreadyCrankedThisSlot,
+    #[msg("No input has been released since this order was last cranked")]
+    NothingToCrank,
+    #[msg("A DAO can't have more than `MAX_DELEGATED_PROPOSERS` delegated proposers")]
+    TooManyDelegatedProposers,
+    #[msg("This delegated proposer has to wait for its cooldown to pass before proposing again")]
+    DelegatedProposerCooldown,
+    #[msg("This account isn't a DAO")]
+    InvalidDao,
+    #[msg("This DAO has already been migrated")]
+    DaoAlreadyMigrated,
+    #[msg("This DAO already has `max_concurrent_proposals` proposals trading")]
+    TooManyActiveProposals,
+    #[msg("This proposer already has `max_concurrent_proposals_per_proposer` proposals trading")]
+    TooManyActiveProposalsForProposer,
+    #[msg("The proposer's record must be passed in to count their trading proposals")]
+    MissingProposerRecord,
+    #[msg("To check for conflicts, every trading proposal of the DAO must be passed in as a remaining account")]
+    MissingPendingProposals,
+    #[msg("A remaining account isn't a distinct trading proposal of this DAO")]
+    InvalidPendingProposal,
+    #[msg("This proposal writes to an account that a trading proposal also writes to")]
+    ConflictingProposal,
+    #[msg("A proposal's title can't be longer than `MAX_TITLE_LENGTH` bytes")]
+    TitleTooLong,
+    #[msg(
+        "A proposal's `description_url` can't be longer than `MAX_DESCRIPTION_URL_LENGTH` bytes"
+    )]
+    DescriptionUrlTooLong,
+    #[msg("Only executed or failed proposals can be closed")]
+    ProposalNotResolved,
+    #[msg("The spot market passed in isn't the one in the DAO's decision rule")]
+    InvalidSpotMarket,
+    #[msg("The spot market has to be an AMM or a Raydium CPMM pool for the DAO's token and USDC")]
+    UnsupportedSpotMarket,
+    #[msg("The spot market's TWAP hasn't been updated since the proposal was created")]
+    SpotTwapUnavailable,
+    #[msg("The pass and fail markets can only be pausable by the DAO's treasury")]
+    InvalidMarketAdmin,
+    #[msg("The pass and fail markets can't be paused when a proposal is created")]
+    MarketPaused,
+    #[msg("A position passed in isn't the proposer's or isn't in the right market")]
+    InvalidPosition,
 }
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{ProposalInstruction, ProposalState};
+use crate::{
+    Dao, DecisionRule, ProposalCategory, ProposalConflictMode, ProposalInstruction, ProposalState,
+};
+use amm::state::SwapType;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CommonFields {
@@ -42,6 +45,55 @@ pub struct UpdateDaoEvent {
     pub twap_max_observation_change_per_update: u128,
     pub min_quote_futarchic_liquidity: u64,
     pub min_base_futarchic_liquidity: u64,
+    pub delegated_proposers: Vec<Pubkey>,
+    pub delegated_proposer_cooldown_slots: u64,
+    pub delegated_proposer_min_quote_futarchic_liquidity: u64,
+    pub delegated_proposer_min_base_futarchic_liquidity: u64,
+    pub max_concurrent_proposals: u32,
+    pub max_concurrent_proposals_per_proposer: u32,
+    pub proposal_conflict_mode: ProposalConflictMode,
+    pub keeper_reward_lamports: u64,
+    pub decision_rule: DecisionRule,
+    pub min_quote_volume: u64,
+}
+
+impl UpdateDaoEvent {
+    pub fn new(clock: &Clock, dao: &Account<Dao>) -> Self {
+        Self {
+            common: CommonFields::new(clock),
+            dao: dao.key(),
+            pass_threshold_bps: dao.pass_threshold_bps,
+            slots_per_proposal: dao.slots_per_proposal,
+            twap_initial_observation: dao.twap_initial_observation,
+            twap_max_observation_change_per_update: dao.twap_max_observation_change_per_update,
+            min_quote_futarchic_liquidity: dao.min_quote_futarchic_liquidity,
+            min_base_futarchic_liquidity: dao.min_base_futarchic_liquidity,
+            delegated_proposers: dao
+                .delegated_proposers
+                .iter()
+                .map(|delegated_proposer| delegated_proposer.pubkey)
+                .collect(),
+            delegated_proposer_cooldown_slots: dao.delegated_proposer_cooldown_slots,
+            delegated_proposer_min_quote_futarchic_liquidity: dao
+                .delegated_proposer_min_quote_futarchic_liquidity,
+            delegated_proposer_min_base_futarchic_liquidity: dao
+                .delegated_proposer_min_base_futarchic_liquidity,
+            max_concurrent_proposals: dao.max_concurrent_proposals,
+            max_concurrent_proposals_per_proposer: dao.max_concurrent_proposals_per_proposer,
+            proposal_conflict_mode: dao.proposal_conflict_mode,
+            keeper_reward_lamports: dao.keeper_reward_lamports,
+            decision_rule: dao.decision_rule,
+            min_quote_volume: dao.min_quote_volume,
+        }
+    }
+}
+
+#[event]
+pub struct MigrateDaoEvent {
+    pub common: CommonFields,
+    pub dao: Pubkey,
+    pub payer: Pubkey,
+    pub seq_num: u64,
 }
 
 #[event]
@@ -63,6 +115,14 @@ pub struct InitializeProposalEvent {
     pub fail_lp_tokens_locked: u64,
     pub pda_bump: u8,
     pub instruction: ProposalInstruction,
+    pub description_url: String,
+    pub title: String,
+    pub content_hash: [u8; 32],
+    pub category: ProposalCategory,
+    pub is_delegated_proposer: bool,
+    pub conflicting_proposals: Vec<Pubkey>,
+    pub pass_position: Option<Pubkey>,
+    pub fail_position: Option<Pubkey>,
 }
 
 #[event]
@@ -73,7 +133,15 @@ pub struct FinalizeProposalEvent {
     pub pass_market_twap: u128,
     pub fail_market_twap: u128,
     pub threshold: u128,
+    pub spot_market_twap: Option<u128>,
+    pub spot_threshold: Option<u128>,
+    pub pass_market_quote_volume: u128,
+    pub fail_market_quote_volume: u128,
+    pub pass_market_paused_slots: u64,
+    pub fail_market_paused_slots: u64,
     pub state: ProposalState,
+    pub keeper: Pubkey,
+    pub keeper_reward: u64,
 }
 
 #[event]
@@ -81,4 +149,101 @@ pub struct ExecuteProposalEvent {
     pub common: CommonFields,
     pub proposal: Pubkey,
     pub dao: Pubkey,
+    pub keeper: Pubkey,
+    pub keeper_reward: u64,
+}
+
+#[event]
+pub struct CrankProposalTwapsEvent {
+    pub common: CommonFields,
+    pub proposal: Pubkey,
+    pub dao: Pubkey,
+    pub keeper: Pubkey,
+    pub keeper_reward: u64,
+}
+
+#[event]
+pub struct CloseProposalEvent {
+    pub common: CommonFields,
+    pub proposal: Pubkey,
+    pub dao: Pubkey,
+    pub proposer: Pubkey,
+    pub state: ProposalState,
+}
+
+#[event]
+pub struct InitializeStreamEvent {
+    pub common: CommonFields,
+    pub stream: Pubkey,
+    pub dao: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    pub nonce: u64,
+}
+
+#[event]
+pub struct WithdrawFromStreamEvent {
+    pub common: CommonFields,
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+    pub seq_num: u64,
+}
+
+#[event]
+pub struct CancelStreamEvent {
+    pub common: CommonFields,
+    pub stream: Pubkey,
+    pub dao: Pubkey,
+    pub unvested_amount: u64,
+    pub seq_num: u64,
+}
+
+#[event]
+pub struct UpgradeProgramEvent {
+    pub common: CommonFields,
+    pub dao: Pubkey,
+    pub program: Pubkey,
+    pub buffer: Pubkey,
+    pub buffer_hash: [u8; 32],
+}
+
+#[event]
+pub struct InitializeTreasuryOrderEvent {
+    pub common: CommonFields,
+    pub order: Pubkey,
+    pub dao: Pubkey,
+    pub amm: Pubkey,
+    pub swap_type: SwapType,
+    pub amount: u64,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub max_input_per_slice: u64,
+    pub limit_price: Option<u128>,
+    pub nonce: u64,
+}
+
+#[event]
+pub struct CrankTreasuryOrderEvent {
+    pub common: CommonFields,
+    pub order: Pubkey,
+    pub dao: Pubkey,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub total_input_spent: u64,
+    pub total_output_received: u64,
+    pub seq_num: u64,
+}
+
+#[event]
+pub struct CancelTreasuryOrderEvent {
+    pub common: CommonFields,
+    pub order: Pubkey,
+    pub dao: Pubkey,
+    pub unspent_input_amount: u64,
+    pub seq_num: u64,
 }
@@ -0,0 +1,85 @@
+use super::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CancelStream<'info> {
+    #[account(mut, has_one = dao)]
+    pub stream: Box<Account<'info, Stream>>,
+    #[account(has_one = treasury)]
+    pub dao: Box<Account<'info, Dao>>,
+    pub treasury: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = stream.mint,
+        associated_token::authority = stream,
+    )]
+    pub stream_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = stream.mint,
+        token::authority = treasury,
+    )]
+    pub treasury_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl CancelStream<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.stream.cancelled_timestamp.is_none(),
+            AutocratError::StreamAlreadyCancelled
+        );
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let Self {
+            stream,
+            dao,
+            treasury: _,
+            stream_token_account,
+            treasury_token_account,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+
+        stream.cancelled_timestamp = Some(clock.unix_timestamp);
+
+        // everything that vested before cancellation stays in the stream for
+        // the recipient to withdraw
+        let unvested_amount = stream.total_amount - stream.vested_amount(clock.unix_timestamp);
+
+        let nonce_bytes = stream.nonce.to_le_bytes();
+        let seeds = generate_stream_seeds!(stream, nonce_bytes);
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: stream_token_account.to_account_info(),
+                    to: treasury_token_account.to_account_info(),
+                    authority: stream.to_account_info(),
+                },
+                signer,
+            ),
+            unvested_amount,
+        )?;
+
+        stream.seq_num += 1;
+
+        emit_cpi!(CancelStreamEvent {
+            common: CommonFields::new(&clock),
+            stream: stream.key(),
+            dao: dao.key(),
+            unvested_amount,
+            seq_num: stream.seq_num,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CancelTreasuryOrder<'info> {
+    #[account(mut, has_one = dao)]
+    pub order: Box<Account<'info, TreasuryOrder>>,
+    #[account(has_one = treasury)]
+    pub dao: Box<Account<'info, Dao>>,
+    pub treasury: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = dao.token_mint,
+        associated_token::authority = order,
+    )]
+    pub order_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = dao.usdc_mint,
+        associated_token::authority = order,
+    )]
+    pub order_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = dao.token_mint,
+        token::authority = treasury,
+    )]
+    pub treasury_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = dao.usdc_mint,
+        token::authority = treasury,
+    )]
+    pub treasury_quote_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl CancelTreasuryOrder<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require!(!self.order.cancelled, AutocratError::OrderCancelled);
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let Self {
+            order,
+            dao,
+            treasury: _,
+            order_base_account,
+            order_quote_account,
+            treasury_base_account,
+            treasury_quote_account,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        order.cancelled = true;
+
+        let nonce_bytes = order.nonce.to_le_bytes();
+        let seeds = generate_treasury_order_seeds!(order, nonce_bytes);
+        let signer = &[&seeds[..]];
+
+        // output is sent to the treasury on every crank, so anything left in
+        // the order is unspent input (plus any tokens someone sent to it)
+        for (from, to) in [
+            (order_base_account, treasury_base_account),
+            (order_quote_account, treasury_quote_account),
+        ] {
+            if from.amount > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: from.to_account_info(),
+                            to: to.to_account_info(),
+                            authority: order.to_account_info(),
+                        },
+                        signer,
+                    ),
+                    from.amount,
+                )?;
+            }
+        }
+
+        order.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(CancelTreasuryOrderEvent {
+            common: CommonFields::new(&clock),
+            order: order.key(),
+            dao: dao.key(),
+            unspent_input_amount: order.total_input_amount - order.input_amount_spent,
+            seq_num: order.seq_num,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use super::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CloseProposal<'info> {
+    #[account(mut, has_one = proposer, close = proposer)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+impl CloseProposal<'_> {
+    pub fn validate(&self) -> Result<()> {
+        // passed proposals still need to be executed, and pending ones still
+        // need to be finalized
+        require!(
+            matches!(
+                self.proposal.state,
+                ProposalState::Executed | ProposalState::Failed
+            ),
+            AutocratError::ProposalNotResolved
+        );
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let Self {
+            proposal,
+            proposer,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+
+        emit_cpi!(CloseProposalEvent {
+            common: CommonFields::new(&clock),
+            proposal: proposal.key(),
+            dao: proposal.dao,
+            proposer: proposer.key(),
+            state: proposal.state,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+use anchor_lang::system_program::{self, Transfer as SystemTransfer};
+
+/// Pays `dao.keeper_reward_lamports` from the DAO's keeper pool to `keeper`.
+///
+/// Returns the reward paid, which is 0 if rewards are disabled, if a reward
+/// has already been paid this slot, if the pool has run dry, or if the keeper
+/// would still be below the rent-exempt minimum afterwards. We don't fail in
+/// those cases because the keeper's work is still useful.
+pub fn pay_keeper_reward<'info>(
+    dao: &mut Account<'info, Dao>,
+    keeper_pool: &SystemAccount<'info>,
+    keeper_pool_bump: u8,
+    keeper: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    slot: u64,
+) -> Result<u64> {
+    let reward = dao.keeper_reward_lamports;
+
+    // one reward per DAO per slot, so keepers can't farm the pool by
+    // submitting many cranks in the same slot
+    if reward == 0 || dao.last_keeper_reward_slot >= slot {
+        return Ok(0);
+    }
+
+    // the runtime rejects transfers that leave an account with a balance
+    // that's above zero but below the rent-exempt minimum, so both sides have
+    // to end up rent-exempt
+    let min_balance = Rent::get()?.minimum_balance(0);
+    if keeper_pool.lamports() < reward.saturating_add(min_balance) {
+        return Ok(0);
+    }
+    if keeper.lamports().saturating_add(reward) < min_balance {
+        return Ok(0);
+    }
+
+    let dao_key = dao.key();
+    let seeds = &[b"keeper_pool", dao_key.as_ref(), &[keeper_pool_bump]];
+    let signer = &[&seeds[..]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            SystemTransfer {
+                from: keeper_pool.to_account_info(),
+                to: keeper.clone(),
+            },
+            signer,
+        ),
+        reward,
+    )?;
+
+    dao.last_keeper_reward_slot = slot;
+
+    Ok(reward)
+}
@@ -0,0 +1,98 @@
+use super::*;
+
+use amm::cpi::accounts::CrankThatTwap;
+use amm::program::Amm as AmmProgram;
+
+/// Cranks the TWAPs of both of a proposal's markets, paying the keeper a
+/// reward from the DAO's keeper pool if either crank recorded an observation.
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CrankProposalTwaps<'info> {
+    #[account(has_one = dao, has_one = pass_amm, has_one = fail_amm)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(mut)]
+    pub dao: Box<Account<'info, Dao>>,
+    #[account(mut)]
+    pub pass_amm: Box<Account<'info, Amm>>,
+    #[account(mut)]
+    pub fail_amm: Box<Account<'info, Amm>>,
+    #[account(mut, seeds = [b"keeper_pool", dao.key().as_ref()], bump)]
+    pub keeper_pool: SystemAccount<'info>,
+    /// CHECK: only receives the keeper reward
+    #[account(mut)]
+    pub keeper: UncheckedAccount<'info>,
+    pub amm_program: Program<'info, AmmProgram>,
+    /// CHECK: checked by the AMM program
+    pub amm_event_authority: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CrankProposalTwaps<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.proposal.state == ProposalState::Pending,
+            AutocratError::ProposalAlreadyFinalized
+        );
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let Self {
+            proposal,
+            dao,
+            pass_amm,
+            fail_amm,
+            keeper_pool,
+            keeper,
+            amm_program,
+            amm_event_authority,
+            system_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+
+        let mut recorded_observation = false;
+
+        for amm in [&mut *pass_amm, &mut *fail_amm] {
+            let last_updated_slot = amm.oracle.last_updated_slot;
+
+            let amm_program = amm_program.to_account_info();
+            let cpi_accounts = CrankThatTwap {
+                amm: amm.to_account_info(),
+                event_authority: amm_event_authority.to_account_info(),
+                program: amm_program.clone(),
+            };
+            amm::cpi::crank_that_twap(CpiContext::new(amm_program, cpi_accounts))?;
+
+            amm.reload()?;
+
+            recorded_observation |= amm.oracle.last_updated_slot != last_updated_slot;
+        }
+
+        let keeper_reward = if recorded_observation {
+            pay_keeper_reward(
+                dao,
+                keeper_pool,
+                ctx.bumps.keeper_pool,
+                &keeper.to_account_info(),
+                system_program,
+                clock.slot,
+            )?
+        } else {
+            0
+        };
+
+        emit_cpi!(CrankProposalTwapsEvent {
+            common: CommonFields::new(&clock),
+            proposal: proposal.key(),
+            dao: dao.key(),
+            keeper: keeper.key(),
+            keeper_reward,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+use super::*;
+
+use amm::cpi::accounts::Swap;
+use amm::instructions::SwapArgs;
+use amm::program::Amm as AmmProgram;
+use amm::state::SwapType;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CrankTreasuryOrder<'info> {
+    #[account(mut, has_one = dao, has_one = amm)]
+    pub order: Box<Account<'info, TreasuryOrder>>,
+    #[account(has_one = treasury)]
+    pub dao: Box<Account<'info, Dao>>,
+    /// CHECK: only used as the authority of `treasury_output_account`
+    pub treasury: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub amm: Box<Account<'info, Amm>>,
+    /// Pays for the order's trader record on the AMM the first time the order
+    /// is cranked, since the order itself can't pay
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: checked by the AMM program
+    #[account(mut)]
+    pub trader_record: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = order,
+    )]
+    pub order_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = order,
+    )]
+    pub order_quote_account: Box<Account<'info, TokenAccount>>,
+    /// Receives the order's output, so it's a base account for buys and a
+    /// quote account for sells
+    #[account(mut, token::authority = treasury)]
+    pub treasury_output_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: checked by the AMM program
+    #[account(mut)]
+    pub amm_base_vault: UncheckedAccount<'info>,
+    /// CHECK: checked by the AMM program
+    #[account(mut)]
+    pub amm_quote_vault: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub amm_program: Program<'info, AmmProgram>,
+    /// CHECK: checked by the AMM program
+    pub amm_event_authority: UncheckedAccount<'info>,
+}
+
+impl CrankTreasuryOrder<'_> {
+    pub fn validate(&self) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require!(!self.order.cancelled, AutocratError::OrderCancelled);
+
+        // at most one slice per slot, so that the per-slice cap also caps how
+        // much can be traded against a single block's prices
+        require_gt!(
+            clock.slot,
+            self.order.last_slice_slot,
+            AutocratError::OrderAlreadyCrankedThisSlot
+        );
+
+        let output_mint = match self.order.swap_type {
+            SwapType::Buy => self.amm.base_mint,
+            SwapType::Sell => self.amm.quote_mint,
+        };
+
+        require_keys_eq!(
+            self.treasury_output_account.mint,
+            output_mint,
+            AutocratError::InvalidOrderOutputAccount
+        );
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let Self {
+            order,
+            dao,
+            treasury: _,
+            amm,
+            payer,
+            trader_record,
+            order_base_account,
+            order_quote_account,
+            treasury_output_account,
+            amm_base_vault,
+            amm_quote_vault,
+            token_program,
+            system_program,
+            amm_program,
+            amm_event_authority,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+
+        let input_amount = order.next_slice_amount(clock.slot);
+
+        require_gt!(input_amount, 0, AutocratError::NothingToCrank);
+
+        let output_amount_min = order.min_output_amount(input_amount)?;
+
+        let nonce_bytes = order.nonce.to_le_bytes();
+        let seeds = generate_treasury_order_seeds!(order, nonce_bytes);
+        let signer = &[&seeds[..]];
+
+        let amm_program = amm_program.to_account_info();
+        let cpi_accounts = Swap {
+            user: order.to_account_info(),
+            payer: payer.to_account_info(),
+            amm: amm.to_account_info(),
+            trader_record: trader_record.to_account_info(),
+            user_base_account: order_base_account.to_account_info(),
+            user_quote_account: order_quote_account.to_account_info(),
+            vault_ata_base: amm_base_vault.to_account_info(),
+            vault_ata_quote: amm_quote_vault.to_account_info(),
+            token_program: token_program.to_account_info(),
+            system_program: system_program.to_account_info(),
+            event_authority: amm_event_authority.to_account_info(),
+            program: amm_program.clone(),
+        };
+        amm::cpi::swap(
+            CpiContext::new_with_signer(amm_program, cpi_accounts, signer),
+            SwapArgs {
+                swap_type: order.swap_type,
+                input_amount,
+                output_amount_min,
+            },
+        )?;
+
+        let order_output_account = match order.swap_type {
+            SwapType::Buy => order_base_account,
+            SwapType::Sell => order_quote_account,
+        };
+        let output_balance_before = order_output_account.amount;
+        order_output_account.reload()?;
+        let output_amount = order_output_account.amount - output_balance_before;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: order_output_account.to_account_info(),
+                    to: treasury_output_account.to_account_info(),
+                    authority: order.to_account_info(),
+                },
+                signer,
+            ),
+            output_amount,
+        )?;
+
+        order.input_amount_spent += input_amount;
+        order.output_amount_received += output_amount;
+        order.last_slice_slot = clock.slot;
+        order.seq_num += 1;
+
+        emit_cpi!(CrankTreasuryOrderEvent {
+            common: CommonFields::new(&clock),
+            order: order.key(),
+            dao: dao.key(),
+            input_amount,
+            output_amount,
+            total_input_spent: order.input_amount_spent,
+            total_output_received: order.output_amount_received,
+            seq_num: order.seq_num,
+        });
+
+        Ok(())
+    }
+}
@@ -5,7 +5,14 @@ use super::*;
 pub struct ExecuteProposal<'info> {
     #[account(mut, has_one = dao)]
     pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
     pub dao: Box<Account<'info, Dao>>,
+    #[account(mut, seeds = [b"keeper_pool", dao.key().as_ref()], bump)]
+    pub keeper_pool: SystemAccount<'info>,
+    /// CHECK: only receives the keeper reward
+    #[account(mut)]
+    pub keeper: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 impl ExecuteProposal<'_> {
@@ -22,6 +29,9 @@ impl ExecuteProposal<'_> {
         let ExecuteProposal {
             proposal,
             dao,
+            keeper_pool,
+            keeper,
+            system_program,
             event_authority: _,
             program: _,
         } = ctx.accounts;
@@ -41,12 +51,27 @@ impl ExecuteProposal<'_> {
 
         solana_program::program::invoke_signed(&svm_instruction, ctx.remaining_accounts, signer)?;
 
+        // the instruction may have been an `update_dao`, so reload before we
+        // write our own changes back
+        dao.reload()?;
+
         let clock = Clock::get()?;
 
+        let keeper_reward = pay_keeper_reward(
+            dao,
+            keeper_pool,
+            ctx.bumps.keeper_pool,
+            &keeper.to_account_info(),
+            system_program,
+            clock.slot,
+        )?;
+
         emit_cpi!(ExecuteProposalEvent {
             common: CommonFields::new(&clock),
             proposal: proposal.key(),
             dao: dao.key(),
+            keeper: keeper.key(),
+            keeper_reward,
         });
 
         Ok(())
@@ -1,4 +1,8 @@
+use amm::cpi::accounts::{CrankThatTwap, UnlockPosition};
+use amm::program::Amm as AmmProgram;
+use amm::state::Position;
 use conditional_vault::{cpi::accounts::ResolveQuestion, ResolveQuestionArgs};
+use price_math::Price;
 
 use super::*;
 
@@ -12,10 +16,27 @@ pub struct FinalizeProposal<'info> {
         has_one = dao,
     )]
     pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
     pub pass_amm: Account<'info, Amm>,
+    #[account(mut)]
     pub fail_amm: Account<'info, Amm>,
-    #[account(has_one = treasury)]
+    /// Only needed if the proposal was created under a decision rule that
+    /// compares against a spot AMM
+    #[account(mut)]
+    pub spot_amm: Option<Box<Account<'info, Amm>>>,
+    /// CHECK: only needed if the proposal was created under a decision rule
+    /// that compares against a Raydium pool, and checked against the
+    /// proposal's snapshot
+    pub spot_raydium_observation: Option<UncheckedAccount<'info>>,
+    #[account(mut, has_one = treasury)]
     pub dao: Box<Account<'info, Dao>>,
+    /// Only needed if the proposal was counted in the proposer's record
+    #[account(
+        mut,
+        seeds = [b"proposer_record", dao.key().as_ref(), proposal.proposer.as_ref()],
+        bump = proposer_record.pda_bump
+    )]
+    pub proposer_record: Option<Box<Account<'info, ProposerRecord>>>,
     #[account(mut)]
     pub question: Account<'info, Question>,
     /// CHECK: it's okay
@@ -44,10 +65,24 @@ pub struct FinalizeProposal<'info> {
         associated_token::authority = dao.treasury,
     )]
     pub fail_lp_vault_account: Box<Account<'info, TokenAccount>>,
+    /// Only needed if the proposer locked positions
+    #[account(mut)]
+    pub pass_position: Option<Box<Account<'info, Position>>>,
+    #[account(mut)]
+    pub fail_position: Option<Box<Account<'info, Position>>>,
     pub token_program: Program<'info, Token>,
     pub vault_program: Program<'info, ConditionalVaultProgram>,
     /// CHECK: checked by vault program
     pub vault_event_authority: UncheckedAccount<'info>,
+    pub amm_program: Program<'info, AmmProgram>,
+    /// CHECK: checked by the AMM program
+    pub amm_event_authority: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"keeper_pool", dao.key().as_ref()], bump)]
+    pub keeper_pool: SystemAccount<'info>,
+    /// CHECK: only receives the keeper reward
+    #[account(mut)]
+    pub keeper: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 impl FinalizeProposal<'_> {
@@ -64,6 +99,27 @@ impl FinalizeProposal<'_> {
             AutocratError::ProposalAlreadyFinalized
         );
 
+        if self.proposal.counted_in_proposer_record {
+            require!(
+                self.proposer_record.is_some(),
+                AutocratError::MissingProposerRecord
+            );
+        }
+
+        for (locked_position, position) in [
+            (self.proposal.pass_position, &self.pass_position),
+            (self.proposal.fail_position, &self.fail_position),
+        ] {
+            if let Some(locked_position) = locked_position {
+                require!(
+                    position
+                        .as_ref()
+                        .is_some_and(|position| position.key() == locked_position),
+                    AutocratError::InvalidPosition
+                );
+            }
+        }
+
         Ok(())
     }
 
@@ -72,16 +128,26 @@ impl FinalizeProposal<'_> {
             proposal,
             pass_amm,
             fail_amm,
+            spot_amm,
+            spot_raydium_observation,
             dao,
+            proposer_record,
             question,
             treasury,
             pass_lp_user_account,
             fail_lp_user_account,
             pass_lp_vault_account,
             fail_lp_vault_account,
+            pass_position,
+            fail_position,
             vault_program,
             token_program,
             vault_event_authority,
+            amm_program,
+            amm_event_authority,
+            keeper_pool,
+            keeper,
+            system_program,
             event_authority: _,
             program: _,
         } = ctx.accounts;
@@ -130,8 +196,59 @@ impl FinalizeProposal<'_> {
             )?;
         }
 
+        for (locked_position, position, amm) in [
+            (proposal.pass_position, &pass_position, &pass_amm),
+            (proposal.fail_position, &fail_position, &fail_amm),
+        ] {
+            // we checked in `validate` that the right position was passed in
+            if let (Some(_), Some(position)) = (locked_position, position) {
+                let amm_program = amm_program.to_account_info();
+                let cpi_accounts = UnlockPosition {
+                    locker: proposal.to_account_info(),
+                    amm: amm.to_account_info(),
+                    position: position.to_account_info(),
+                    event_authority: amm_event_authority.to_account_info(),
+                    program: amm_program.clone(),
+                };
+                amm::cpi::unlock_position(CpiContext::new_with_signer(
+                    amm_program,
+                    cpi_accounts,
+                    proposal_signer,
+                ))?;
+            }
+        }
+
+        // record a final observation in both markets so that proposals don't
+        // get stuck waiting for someone to crank. if a market was cranked in
+        // the last minute this is a no-op, and that crank has to be recent
+        // enough on its own
+        for amm in [&mut *pass_amm, &mut *fail_amm] {
+            let amm_program = amm_program.to_account_info();
+            let cpi_accounts = CrankThatTwap {
+                amm: amm.to_account_info(),
+                event_authority: amm_event_authority.to_account_info(),
+                program: amm_program.clone(),
+            };
+            amm::cpi::crank_that_twap(CpiContext::new(amm_program, cpi_accounts))?;
+
+            amm.reload()?;
+        }
+
+        // the treasury pauses a market when something's wrong with it. we don't
+        // want a proposal to pass on a frozen TWAP, and waiting for an unpause
+        // could leave it pending forever, so it fails
+        let markets_paused = pass_amm.is_paused() || fail_amm.is_paused();
+
         let calculate_twap = |amm: &Amm| -> Result<u128> {
-            let slots_passed = amm.oracle.last_updated_slot - proposal.slot_enqueued;
+            if markets_paused {
+                // only reported in the event, so it's fine if there's no TWAP
+                return Ok(amm.get_twap().unwrap_or_default());
+            }
+
+            // slots that a market spent paused don't count towards the TWAP,
+            // so the markets have to trade for that much longer
+            let slots_passed = (amm.oracle.last_updated_slot - proposal.slot_enqueued)
+                .saturating_sub(amm.total_paused_slots);
 
             require!(
                 slots_passed >= dao.slots_per_proposal,
@@ -144,14 +261,91 @@ impl FinalizeProposal<'_> {
         let pass_market_twap = calculate_twap(pass_amm)?;
         let fail_market_twap = calculate_twap(fail_amm)?;
 
-        // this can't overflow because each twap can only be MAX_PRICE (~1e31),
-        // MAX_BPS + pass_threshold_bps is at most 1e5, and a u128 can hold
-        // 1e38. still, saturate
-        let threshold = fail_market_twap
-            .saturating_mul(MAX_BPS.saturating_add(dao.pass_threshold_bps).into())
-            / MAX_BPS as u128;
+        let calculate_threshold = |twap: u128| -> u128 {
+            Price::from_scaled(twap)
+                .saturating_add_bps(dao.pass_threshold_bps)
+                .to_scaled()
+        };
+
+        let threshold = calculate_threshold(fail_market_twap);
+
+        // we go by the snapshot rather than the DAO's current decision rule so
+        // that changing the rule doesn't affect proposals that are trading
+        let spot_market_twap = match proposal.spot_twap_snapshot {
+            Some(spot_twap_snapshot) => Some(match spot_twap_snapshot.spot_market {
+                SpotMarket::Amm { amm } => {
+                    let spot_amm = match spot_amm {
+                        Some(spot_amm) if spot_amm.key() == amm => spot_amm,
+                        _ => return err!(AutocratError::InvalidSpotMarket),
+                    };
+
+                    let amm_program = amm_program.to_account_info();
+                    let cpi_accounts = CrankThatTwap {
+                        amm: spot_amm.to_account_info(),
+                        event_authority: amm_event_authority.to_account_info(),
+                        program: amm_program.clone(),
+                    };
+                    amm::cpi::crank_that_twap(CpiContext::new(amm_program, cpi_accounts))?;
+
+                    spot_amm.reload()?;
+
+                    spot_twap_snapshot.twap_until(
+                        spot_amm.oracle.aggregator,
+                        spot_amm.oracle.last_updated_slot,
+                    )?
+                }
+                SpotMarket::Raydium {
+                    observation_state,
+                    token_mint_is_token_0,
+                } => {
+                    let observation = match spot_raydium_observation {
+                        Some(spot_raydium_observation)
+                            if spot_raydium_observation.key() == observation_state =>
+                        {
+                            RaydiumObservation::latest(spot_raydium_observation)?
+                        }
+                        _ => return err!(AutocratError::InvalidSpotMarket),
+                    };
+
+                    // if nobody has swapped on the pool since the proposal
+                    // was created, this fails until someone does
+                    let twap_x32 = spot_twap_snapshot.twap_until(
+                        observation.cumulative_token_price_x32(token_mint_is_token_0),
+                        observation.block_timestamp,
+                    )?;
+
+                    Price::from_x32(twap_x32).to_scaled()
+                }
+            }),
+            None => None,
+        };
+        let spot_threshold = spot_market_twap.map(calculate_threshold);
+
+        let pass_price = Price::from_scaled(pass_market_twap);
+        let beats_spot = match spot_market_twap {
+            Some(spot_market_twap) => pass_price
+                .exceeds_by_bps(Price::from_scaled(spot_market_twap), dao.pass_threshold_bps),
+            None => true,
+        };
+
+        // only volume traded since the proposal was initialized counts
+        let pass_market_quote_volume = pass_amm
+            .cumulative_quote_volume
+            .saturating_sub(proposal.pass_quote_volume_at_start);
+        let fail_market_quote_volume = fail_amm
+            .cumulative_quote_volume
+            .saturating_sub(proposal.fail_quote_volume_at_start);
+
+        let min_quote_volume = dao.min_quote_volume as u128;
+        let enough_volume = pass_market_quote_volume >= min_quote_volume
+            && fail_market_quote_volume >= min_quote_volume;
+
+        let beats_fail =
+            pass_price.exceeds_by_bps(Price::from_scaled(fail_market_twap), dao.pass_threshold_bps);
+
+        let passed = !markets_paused && beats_fail && beats_spot && enough_volume;
 
-        let (new_proposal_state, payout_numerators) = if pass_market_twap > threshold {
+        let (new_proposal_state, payout_numerators) = if passed {
             (ProposalState::Passed, vec![0, 1])
         } else {
             (ProposalState::Failed, vec![1, 0])
@@ -159,6 +353,17 @@ impl FinalizeProposal<'_> {
 
         proposal.state = new_proposal_state;
 
+        // both were incremented when the proposal was initialized, so these
+        // can't underflow. still, saturate
+        dao.active_proposal_count = dao.active_proposal_count.saturating_sub(1);
+        if proposal.counted_in_proposer_record {
+            // we checked in `validate` that it was passed in
+            if let Some(proposer_record) = proposer_record {
+                proposer_record.active_proposal_count =
+                    proposer_record.active_proposal_count.saturating_sub(1);
+            }
+        }
+
         let vault_program = vault_program.to_account_info();
         let cpi_accounts = ResolveQuestion {
             question: question.to_account_info(),
@@ -174,6 +379,15 @@ impl FinalizeProposal<'_> {
 
         let clock = Clock::get()?;
 
+        let keeper_reward = pay_keeper_reward(
+            dao,
+            keeper_pool,
+            ctx.bumps.keeper_pool,
+            &keeper.to_account_info(),
+            system_program,
+            clock.slot,
+        )?;
+
         emit_cpi!(FinalizeProposalEvent {
             common: CommonFields::new(&clock),
             proposal: proposal.key(),
@@ -181,7 +395,15 @@ impl FinalizeProposal<'_> {
             pass_market_twap,
             fail_market_twap,
             threshold,
+            spot_market_twap,
+            spot_threshold,
+            pass_market_quote_volume,
+            fail_market_quote_volume,
+            pass_market_paused_slots: pass_amm.total_paused_slots,
+            fail_market_paused_slots: fail_amm.total_paused_slots,
             state: new_proposal_state,
+            keeper: keeper.key(),
+            keeper_reward,
         });
 
         Ok(())
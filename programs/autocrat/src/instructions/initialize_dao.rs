@@ -16,7 +16,7 @@ pub struct InitializeDao<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + std::mem::size_of::<Dao>()
+        space = Dao::space()
     )]
     pub dao: Account<'info, Dao>,
     #[account(mut)]
@@ -41,6 +41,8 @@ impl InitializeDao<'_> {
 
         let dao = &mut ctx.accounts.dao;
 
+        let slots_per_proposal = slots_per_proposal.unwrap_or(THREE_DAYS_IN_SLOTS);
+
         let (treasury, treasury_pda_bump) =
             Pubkey::find_program_address(&[dao.key().as_ref()], ctx.program_id);
 
@@ -51,12 +53,25 @@ impl InitializeDao<'_> {
             treasury,
             proposal_count: 0,
             pass_threshold_bps: pass_threshold_bps.unwrap_or(DEFAULT_PASS_THRESHOLD_BPS),
-            slots_per_proposal: slots_per_proposal.unwrap_or(THREE_DAYS_IN_SLOTS),
+            slots_per_proposal,
             twap_initial_observation,
             twap_max_observation_change_per_update,
             min_base_futarchic_liquidity,
             min_quote_futarchic_liquidity,
             seq_num: 0,
+            delegated_proposers: vec![],
+            // by default, a delegated proposer can have one proposal trading at a time
+            delegated_proposer_cooldown_slots: slots_per_proposal,
+            delegated_proposer_min_quote_futarchic_liquidity: 0,
+            delegated_proposer_min_base_futarchic_liquidity: 0,
+            active_proposal_count: 0,
+            max_concurrent_proposals: 0,
+            max_concurrent_proposals_per_proposer: 0,
+            proposal_conflict_mode: ProposalConflictMode::Ignore,
+            keeper_reward_lamports: 0,
+            last_keeper_reward_slot: 0,
+            decision_rule: DecisionRule::PassOverFail,
+            min_quote_volume: 0,
         });
 
         let clock = Clock::get()?;
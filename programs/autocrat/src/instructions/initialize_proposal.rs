@@ -1,11 +1,16 @@
 use super::*;
 
-use amm::state::ONE_MINUTE_IN_SLOTS;
+use amm::cpi::accounts::{CrankThatTwap, LockPosition};
+use amm::program::Amm as AmmProgram;
+use amm::state::{Position, ONE_MINUTE_IN_SLOTS};
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct InitializeProposalParams {
     pub description_url: String,
+    pub title: String,
+    pub content_hash: [u8; 32],
+    pub category: ProposalCategory,
     pub instruction: ProposalInstruction,
     pub pass_lp_tokens_to_lock: u64,
     pub fail_lp_tokens_to_lock: u64,
@@ -19,13 +24,23 @@ pub struct InitializeProposal<'info> {
     #[account(
         init,
         payer = proposer,
-        space = 2000,
+        space = Proposal::space(&args.description_url, &args.title, &args.instruction),
         seeds = [b"proposal", proposer.key().as_ref(), &args.nonce.to_le_bytes()],
         bump
     )]
     pub proposal: Box<Account<'info, Proposal>>,
     #[account(mut)]
     pub dao: Box<Account<'info, Dao>>,
+    /// Only needed if the DAO limits how many proposals each proposer can
+    /// have trading, so that other proposers don't pay rent for one
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = 8 + std::mem::size_of::<ProposerRecord>(),
+        seeds = [b"proposer_record", dao.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_record: Option<Box<Account<'info, ProposerRecord>>>,
     #[account(
         constraint = question.oracle == proposal.key()
     )]
@@ -54,6 +69,12 @@ pub struct InitializeProposal<'info> {
         constraint = fail_amm.quote_mint == quote_vault.conditional_token_mints[FAIL_INDEX],
     )]
     pub fail_amm: Box<Account<'info, Amm>>,
+    /// Only needed if the DAO's decision rule compares against a spot AMM
+    #[account(mut)]
+    pub spot_amm: Option<Box<Account<'info, Amm>>>,
+    /// CHECK: only needed if the DAO's decision rule compares against a
+    /// Raydium pool, and checked against the rule in `validate`
+    pub spot_raydium_observation: Option<UncheckedAccount<'info>>,
     #[account(
         mut,
         associated_token::mint = pass_amm.lp_mint,
@@ -78,16 +99,37 @@ pub struct InitializeProposal<'info> {
         associated_token::authority = dao.treasury,
     )]
     pub fail_lp_vault_account: Account<'info, TokenAccount>,
+    /// A position to lock on top of `pass_lp_tokens_to_lock`. Its liquidity
+    /// counts towards the DAO's minimum.
+    #[account(mut)]
+    pub pass_position: Option<Box<Account<'info, Position>>>,
+    #[account(mut)]
+    pub fail_position: Option<Box<Account<'info, Position>>>,
     #[account(mut)]
     pub proposer: Signer<'info>,
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    pub amm_program: Program<'info, AmmProgram>,
+    /// CHECK: checked by the AMM program
+    pub amm_event_authority: UncheckedAccount<'info>,
 }
 
 impl InitializeProposal<'_> {
-    pub fn validate(&self) -> Result<()> {
+    pub fn validate(&self, params: &InitializeProposalParams) -> Result<()> {
         let clock = Clock::get()?;
 
+        require_gte!(
+            MAX_TITLE_LENGTH,
+            params.title.len(),
+            AutocratError::TitleTooLong
+        );
+
+        require_gte!(
+            MAX_DESCRIPTION_URL_LENGTH,
+            params.description_url.len(),
+            AutocratError::DescriptionUrlTooLong
+        );
+
         for amm in [&self.pass_amm, &self.fail_amm] {
             // an attacker is able to crank 5 observations before a proposal starts
             require!(
@@ -106,6 +148,62 @@ impl InitializeProposal<'_> {
                 self.dao.twap_max_observation_change_per_update,
                 AutocratError::InvalidMaxObservationChange
             );
+
+            // otherwise whoever created the markets could freeze their TWAPs
+            // whenever the proposal was going the wrong way for them
+            require!(
+                amm.admin.is_none() || amm.admin == Some(self.dao.treasury),
+                AutocratError::InvalidMarketAdmin
+            );
+
+            require!(!amm.is_paused(), AutocratError::MarketPaused);
+        }
+
+        if self.dao.max_concurrent_proposals != 0 {
+            require_gt!(
+                self.dao.max_concurrent_proposals,
+                self.dao.active_proposal_count,
+                AutocratError::TooManyActiveProposals
+            );
+        }
+
+        if self.dao.max_concurrent_proposals_per_proposer != 0 {
+            let Some(proposer_record) = &self.proposer_record else {
+                return err!(AutocratError::MissingProposerRecord);
+            };
+
+            require_gt!(
+                self.dao.max_concurrent_proposals_per_proposer,
+                proposer_record.active_proposal_count,
+                AutocratError::TooManyActiveProposalsForProposer
+            );
+        }
+
+        for (position, amm) in [
+            (&self.pass_position, &self.pass_amm),
+            (&self.fail_position, &self.fail_amm),
+        ] {
+            if let Some(position) = position {
+                require!(
+                    position.owner == self.proposer.key() && position.amm == amm.key(),
+                    AutocratError::InvalidPosition
+                );
+            }
+        }
+
+        if let DecisionRule::PassOverFailAndSpot { spot_market } = self.dao.decision_rule {
+            let spot_oracle = match spot_market {
+                SpotMarket::Amm { .. } => self.spot_amm.as_ref().map(|amm| amm.key()),
+                SpotMarket::Raydium { .. } => self
+                    .spot_raydium_observation
+                    .as_ref()
+                    .map(|observation_state| observation_state.key()),
+            };
+
+            require!(
+                spot_oracle == Some(spot_market.oracle()),
+                AutocratError::InvalidSpotMarket
+            );
         }
 
         // Should never be the case because the oracle is the proposal account, and you can't re-initialize a proposal
@@ -121,23 +219,33 @@ impl InitializeProposal<'_> {
             question,
             proposal,
             dao,
+            proposer_record,
             pass_amm,
             fail_amm,
+            spot_amm,
+            spot_raydium_observation,
             pass_lp_mint,
             fail_lp_mint,
             pass_lp_user_account,
             fail_lp_user_account,
             pass_lp_vault_account,
             fail_lp_vault_account,
+            pass_position,
+            fail_position,
             proposer,
             token_program,
             system_program: _,
+            amm_program,
+            amm_event_authority,
             event_authority: _,
             program: _,
         } = ctx.accounts;
 
         let InitializeProposalParams {
             description_url,
+            title,
+            content_hash,
+            category,
             instruction,
             pass_lp_tokens_to_lock,
             fail_lp_tokens_to_lock,
@@ -155,15 +263,56 @@ impl InitializeProposal<'_> {
             AutocratError::InsufficientLpTokenBalance
         );
 
-        let (pass_base_liquidity, pass_quote_liquidity) =
-            pass_amm.get_base_and_quote_withdrawable(pass_lp_tokens_to_lock, pass_lp_mint.supply);
-        let (fail_base_liquidity, fail_quote_liquidity) =
-            fail_amm.get_base_and_quote_withdrawable(fail_lp_tokens_to_lock, fail_lp_mint.supply);
+        let position_lp_tokens = |position: &Option<Box<Account<Position>>>| {
+            position.as_ref().map_or(0, |position| position.lp_tokens)
+        };
+
+        let (pass_base_liquidity, pass_quote_liquidity) = pass_amm.get_base_and_quote_withdrawable(
+            pass_lp_tokens_to_lock + position_lp_tokens(pass_position),
+            pass_amm.total_lp_supply(pass_lp_mint.supply),
+        );
+        let (fail_base_liquidity, fail_quote_liquidity) = fail_amm.get_base_and_quote_withdrawable(
+            fail_lp_tokens_to_lock + position_lp_tokens(fail_position),
+            fail_amm.total_lp_supply(fail_lp_mint.supply),
+        );
+
+        let clock = Clock::get()?;
+
+        let cooldown_slots = dao.delegated_proposer_cooldown_slots;
+        let (min_base_futarchic_liquidity, min_quote_futarchic_liquidity, is_delegated_proposer) =
+            match dao
+                .delegated_proposers
+                .iter_mut()
+                .find(|delegated_proposer| delegated_proposer.pubkey == proposer.key())
+            {
+                Some(delegated_proposer) => {
+                    require_gte!(
+                        clock.slot,
+                        delegated_proposer
+                            .last_slot_proposed
+                            .saturating_add(cooldown_slots),
+                        AutocratError::DelegatedProposerCooldown
+                    );
+
+                    delegated_proposer.last_slot_proposed = clock.slot;
+
+                    (
+                        dao.delegated_proposer_min_base_futarchic_liquidity,
+                        dao.delegated_proposer_min_quote_futarchic_liquidity,
+                        true,
+                    )
+                }
+                None => (
+                    dao.min_base_futarchic_liquidity,
+                    dao.min_quote_futarchic_liquidity,
+                    false,
+                ),
+            };
 
         for base_liquidity in [pass_base_liquidity, fail_base_liquidity] {
             require_gte!(
                 base_liquidity,
-                dao.min_base_futarchic_liquidity,
+                min_base_futarchic_liquidity,
                 AutocratError::InsufficientLpTokenLock
             );
         }
@@ -171,7 +320,7 @@ impl InitializeProposal<'_> {
         for quote_liquidity in [pass_quote_liquidity, fail_quote_liquidity] {
             require_gte!(
                 quote_liquidity,
-                dao.min_quote_futarchic_liquidity,
+                min_quote_futarchic_liquidity,
                 AutocratError::InsufficientLpTokenLock
             );
         }
@@ -201,14 +350,132 @@ impl InitializeProposal<'_> {
             )?;
         }
 
-        let clock = Clock::get()?;
+        // the proposal locks the positions, so that only finalizing it can
+        // unlock them
+        let proposer_key = proposer.key();
+        let nonce_bytes = nonce.to_le_bytes();
+        let proposal_seeds = &[
+            b"proposal",
+            proposer_key.as_ref(),
+            &nonce_bytes,
+            &[ctx.bumps.proposal],
+        ];
+        let proposal_signer = &[&proposal_seeds[..]];
+
+        for (position, amm) in [(&pass_position, &pass_amm), (&fail_position, &fail_amm)] {
+            if let Some(position) = position {
+                let amm_program = amm_program.to_account_info();
+                let cpi_accounts = LockPosition {
+                    owner: proposer.to_account_info(),
+                    locker: proposal.to_account_info(),
+                    amm: amm.to_account_info(),
+                    position: position.to_account_info(),
+                    event_authority: amm_event_authority.to_account_info(),
+                    program: amm_program.clone(),
+                };
+                amm::cpi::lock_position(CpiContext::new_with_signer(
+                    amm_program,
+                    cpi_accounts,
+                    proposal_signer,
+                ))?;
+            }
+        }
+
+        let writable_accounts = instruction.writable_accounts();
+
+        let conflicting_proposals = match dao.proposal_conflict_mode {
+            ProposalConflictMode::Ignore => vec![],
+            ProposalConflictMode::Flag | ProposalConflictMode::Reject => {
+                let conflicting_proposals = find_conflicting_proposals(
+                    dao.key(),
+                    dao.active_proposal_count,
+                    &writable_accounts,
+                    ctx.remaining_accounts,
+                )?;
+
+                if dao.proposal_conflict_mode == ProposalConflictMode::Reject {
+                    require!(
+                        conflicting_proposals.is_empty(),
+                        AutocratError::ConflictingProposal
+                    );
+                }
+
+                conflicting_proposals
+            }
+        };
+
+        let spot_twap_snapshot = match dao.decision_rule {
+            DecisionRule::PassOverFail => None,
+            // we check that the right spot accounts were passed in `validate`
+            DecisionRule::PassOverFailAndSpot { spot_market } => {
+                let (aggregator, time) = match (spot_market, spot_amm, spot_raydium_observation) {
+                    (SpotMarket::Amm { .. }, Some(spot_amm), _) => {
+                        // the aggregator only covers up to the oracle's last
+                        // update, so without a crank the snapshot could be
+                        // stale and the spot TWAP would include prices from
+                        // before the proposal. like in `finalize_proposal`,
+                        // this is a no-op if the market was cranked in the
+                        // last minute
+                        let amm_program = amm_program.to_account_info();
+                        let cpi_accounts = CrankThatTwap {
+                            amm: spot_amm.to_account_info(),
+                            event_authority: amm_event_authority.to_account_info(),
+                            program: amm_program.clone(),
+                        };
+                        amm::cpi::crank_that_twap(CpiContext::new(amm_program, cpi_accounts))?;
+
+                        spot_amm.reload()?;
+
+                        (
+                            spot_amm.oracle.aggregator,
+                            spot_amm.oracle.last_updated_slot,
+                        )
+                    }
+                    (
+                        SpotMarket::Raydium {
+                            token_mint_is_token_0,
+                            ..
+                        },
+                        _,
+                        Some(observation_state),
+                    ) => {
+                        // we can't crank a Raydium pool, so this is as of its
+                        // last swap
+                        let observation = RaydiumObservation::latest(observation_state)?;
+
+                        (
+                            observation.cumulative_token_price_x32(token_mint_is_token_0),
+                            observation.block_timestamp,
+                        )
+                    }
+                    _ => return err!(AutocratError::InvalidSpotMarket),
+                };
+
+                Some(TwapSnapshot {
+                    spot_market,
+                    aggregator,
+                    time,
+                })
+            }
+        };
 
         dao.proposal_count += 1;
+        dao.active_proposal_count += 1;
+
+        if let Some(proposer_record) = proposer_record {
+            proposer_record.dao = dao.key();
+            proposer_record.proposer = proposer.key();
+            proposer_record.pda_bump = ctx.bumps.proposer_record;
+            proposer_record.active_proposal_count += 1;
+        }
 
         proposal.set_inner(Proposal {
             number: dao.proposal_count,
             proposer: proposer.key(),
-            description_url,
+            description_url: description_url.clone(),
+            title: title.clone(),
+            content_hash,
+            category,
             slot_enqueued: clock.slot,
             state: ProposalState::Pending,
             instruction: instruction.clone(),
@@ -222,6 +489,13 @@ impl InitializeProposal<'_> {
             nonce,
             pda_bump: ctx.bumps.proposal,
             question: question.key(),
+            counted_in_proposer_record: proposer_record.is_some(),
+            writable_accounts,
+            spot_twap_snapshot,
+            pass_quote_volume_at_start: pass_amm.cumulative_quote_volume,
+            fail_quote_volume_at_start: fail_amm.cumulative_quote_volume,
+            pass_position: pass_position.as_ref().map(|position| position.key()),
+            fail_position: fail_position.as_ref().map(|position| position.key()),
         });
 
         emit_cpi!(InitializeProposalEvent {
@@ -242,8 +516,64 @@ impl InitializeProposal<'_> {
             fail_lp_tokens_locked: fail_lp_tokens_to_lock,
             pda_bump: ctx.bumps.proposal,
             instruction,
+            description_url,
+            title,
+            content_hash,
+            category,
+            is_delegated_proposer,
+            conflicting_proposals,
+            pass_position: proposal.pass_position,
+            fail_position: proposal.fail_position,
         });
 
         Ok(())
     }
 }
+
+/// Returns the pending proposals in `pending_proposals` that write to any of
+/// `writable_accounts`. Every one of the DAO's pending proposals needs to be
+/// passed in, otherwise a proposer could hide a conflict by leaving one out.
+fn find_conflicting_proposals(
+    dao: Pubkey,
+    active_proposal_count: u32,
+    writable_accounts: &[Pubkey],
+    pending_proposals: &[AccountInfo],
+) -> Result<Vec<Pubkey>> {
+    require_eq!(
+        pending_proposals.len(),
+        active_proposal_count as usize,
+        AutocratError::MissingPendingProposals
+    );
+
+    let mut seen_proposals: Vec<Pubkey> = vec![];
+    let mut conflicting_proposals: Vec<Pubkey> = vec![];
+
+    for pending_proposal in pending_proposals {
+        require_keys_eq!(
+            *pending_proposal.owner,
+            crate::ID,
+            AutocratError::InvalidPendingProposal
+        );
+
+        let proposal = Proposal::try_deserialize(&mut &pending_proposal.try_borrow_data()?[..])?;
+
+        require!(
+            proposal.dao == dao
+                && proposal.state == ProposalState::Pending
+                && !seen_proposals.contains(pending_proposal.key),
+            AutocratError::InvalidPendingProposal
+        );
+
+        seen_proposals.push(pending_proposal.key());
+
+        if proposal
+            .writable_accounts
+            .iter()
+            .any(|acc| writable_accounts.contains(acc))
+        {
+            conflicting_proposals.push(pending_proposal.key());
+        }
+    }
+
+    Ok(conflicting_proposals)
+}
@@ -0,0 +1,134 @@
+use super::*;
+
+use anchor_spl::associated_token::AssociatedToken;
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct InitializeStreamParams {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    pub nonce: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: InitializeStreamParams)]
+#[event_cpi]
+pub struct InitializeStream<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<Stream>(),
+        seeds = [b"stream", dao.key().as_ref(), &params.nonce.to_le_bytes()],
+        bump
+    )]
+    pub stream: Box<Account<'info, Stream>>,
+    #[account(has_one = treasury)]
+    pub dao: Box<Account<'info, Dao>>,
+    pub treasury: Signer<'info>,
+    pub mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = treasury,
+    )]
+    pub treasury_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = stream,
+    )]
+    pub stream_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeStream<'_> {
+    pub fn validate(&self, params: &InitializeStreamParams) -> Result<()> {
+        require_gt!(params.amount, 0, AutocratError::StreamAmountZero);
+
+        require_gt!(
+            params.end_timestamp,
+            params.start_timestamp,
+            AutocratError::InvalidStreamDuration
+        );
+
+        require_gte!(
+            self.treasury_token_account.amount,
+            params.amount,
+            AutocratError::InsufficientTreasuryBalance
+        );
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>, params: InitializeStreamParams) -> Result<()> {
+        let Self {
+            stream,
+            dao,
+            treasury,
+            mint,
+            treasury_token_account,
+            stream_token_account,
+            payer: _,
+            token_program,
+            associated_token_program: _,
+            system_program: _,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let InitializeStreamParams {
+            recipient,
+            amount,
+            start_timestamp,
+            end_timestamp,
+            nonce,
+        } = params;
+
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Transfer {
+                    from: treasury_token_account.to_account_info(),
+                    to: stream_token_account.to_account_info(),
+                    authority: treasury.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        stream.set_inner(Stream {
+            dao: dao.key(),
+            recipient,
+            mint: mint.key(),
+            total_amount: amount,
+            withdrawn_amount: 0,
+            start_timestamp,
+            end_timestamp,
+            cancelled_timestamp: None,
+            nonce,
+            pda_bump: ctx.bumps.stream,
+            seq_num: 0,
+        });
+
+        let clock = Clock::get()?;
+        emit_cpi!(InitializeStreamEvent {
+            common: CommonFields::new(&clock),
+            stream: stream.key(),
+            dao: dao.key(),
+            recipient,
+            mint: mint.key(),
+            amount,
+            start_timestamp,
+            end_timestamp,
+            nonce,
+        });
+
+        Ok(())
+    }
+}
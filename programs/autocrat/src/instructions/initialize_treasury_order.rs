@@ -0,0 +1,182 @@
+use super::*;
+
+use amm::state::SwapType;
+use anchor_spl::associated_token::AssociatedToken;
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeTreasuryOrderParams {
+    pub swap_type: SwapType,
+    pub amount: u64,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub max_input_per_slice: u64,
+    pub limit_price: Option<u128>,
+    pub nonce: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: InitializeTreasuryOrderParams)]
+#[event_cpi]
+pub struct InitializeTreasuryOrder<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<TreasuryOrder>(),
+        seeds = [b"treasury_order", dao.key().as_ref(), &params.nonce.to_le_bytes()],
+        bump
+    )]
+    pub order: Box<Account<'info, TreasuryOrder>>,
+    #[account(has_one = treasury)]
+    pub dao: Box<Account<'info, Dao>>,
+    pub treasury: Signer<'info>,
+    #[account(
+        constraint = amm.base_mint == dao.token_mint @ AutocratError::InvalidOrderAmm,
+        constraint = amm.quote_mint == dao.usdc_mint @ AutocratError::InvalidOrderAmm,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    #[account(address = amm.base_mint)]
+    pub base_mint: Box<Account<'info, Mint>>,
+    #[account(address = amm.quote_mint)]
+    pub quote_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = base_mint,
+        associated_token::authority = order,
+    )]
+    pub order_base_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = order,
+    )]
+    pub order_quote_account: Box<Account<'info, TokenAccount>>,
+    /// Holds the order's input, so it's a quote account for buys and a base
+    /// account for sells
+    #[account(mut, token::authority = treasury)]
+    pub treasury_input_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeTreasuryOrder<'_> {
+    pub fn validate(&self, params: &InitializeTreasuryOrderParams) -> Result<()> {
+        require_gt!(params.amount, 0, AutocratError::OrderAmountZero);
+        require_gt!(
+            params.max_input_per_slice,
+            0,
+            AutocratError::OrderAmountZero
+        );
+
+        require_gt!(
+            params.end_slot,
+            params.start_slot,
+            AutocratError::InvalidOrderDuration
+        );
+
+        let input_mint = match params.swap_type {
+            SwapType::Buy => self.amm.quote_mint,
+            SwapType::Sell => self.amm.base_mint,
+        };
+
+        require_keys_eq!(
+            self.treasury_input_account.mint,
+            input_mint,
+            AutocratError::InvalidOrderInputAccount
+        );
+
+        require_gte!(
+            self.treasury_input_account.amount,
+            params.amount,
+            AutocratError::InsufficientTreasuryBalance
+        );
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>, params: InitializeTreasuryOrderParams) -> Result<()> {
+        let Self {
+            order,
+            dao,
+            treasury,
+            amm,
+            base_mint: _,
+            quote_mint: _,
+            order_base_account,
+            order_quote_account,
+            treasury_input_account,
+            payer: _,
+            token_program,
+            associated_token_program: _,
+            system_program: _,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let InitializeTreasuryOrderParams {
+            swap_type,
+            amount,
+            start_slot,
+            end_slot,
+            max_input_per_slice,
+            limit_price,
+            nonce,
+        } = params;
+
+        let order_input_account = match swap_type {
+            SwapType::Buy => order_quote_account,
+            SwapType::Sell => order_base_account,
+        };
+
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Transfer {
+                    from: treasury_input_account.to_account_info(),
+                    to: order_input_account.to_account_info(),
+                    authority: treasury.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        order.set_inner(TreasuryOrder {
+            dao: dao.key(),
+            amm: amm.key(),
+            swap_type,
+            total_input_amount: amount,
+            input_amount_spent: 0,
+            output_amount_received: 0,
+            start_slot,
+            end_slot,
+            max_input_per_slice,
+            limit_price,
+            last_slice_slot: 0,
+            cancelled: false,
+            nonce,
+            pda_bump: ctx.bumps.order,
+            seq_num: 0,
+        });
+
+        let clock = Clock::get()?;
+        emit_cpi!(InitializeTreasuryOrderEvent {
+            common: CommonFields::new(&clock),
+            order: order.key(),
+            dao: dao.key(),
+            amm: amm.key(),
+            swap_type,
+            amount,
+            start_slot,
+            end_slot,
+            max_input_per_slice,
+            limit_price,
+            nonce,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+
+use anchor_lang::{system_program, Discriminator};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct MigrateDao<'info> {
+    /// CHECK: a DAO created before its newer fields existed doesn't
+    /// deserialize as a `Dao`, so we check it by hand in `validate`
+    #[account(mut, owner = crate::ID)]
+    pub dao: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateDao<'_> {
+    pub fn validate(&self) -> Result<()> {
+        let data = self.dao.try_borrow_data()?;
+
+        require!(
+            data.len() >= 8 && data[..8] == Dao::DISCRIMINATOR,
+            AutocratError::InvalidDao
+        );
+        require_gt!(Dao::space(), data.len(), AutocratError::DaoAlreadyMigrated);
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let Self {
+            dao,
+            payer,
+            system_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let new_space = Dao::space();
+
+        let rent_owed = Rent::get()?
+            .minimum_balance(new_space)
+            .saturating_sub(dao.lamports());
+
+        if rent_owed > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: dao.to_account_info(),
+                    },
+                ),
+                rent_owed,
+            )?;
+        }
+
+        // the new fields all read as zeroes, which are their defaults: no
+        // delegated proposers, no limits, no keeper rewards, `Ignore` and
+        // `PassOverFail`
+        dao.to_account_info().realloc(new_space, true)?;
+
+        let mut dao_data: &[u8] = &dao.try_borrow_data()?;
+        let migrated_dao = Dao::try_deserialize(&mut dao_data)?;
+
+        let clock = Clock::get()?;
+        emit_cpi!(MigrateDaoEvent {
+            common: CommonFields::new(&clock),
+            dao: dao.key(),
+            payer: payer.key(),
+            seq_num: migrated_dao.seq_num,
+        });
+
+        Ok(())
+    }
+}
@@ -1,13 +1,37 @@
 use super::*;
 
+pub mod cancel_stream;
+pub mod cancel_treasury_order;
+pub mod close_proposal;
+pub mod common;
+pub mod crank_proposal_twaps;
+pub mod crank_treasury_order;
 pub mod execute_proposal;
 pub mod finalize_proposal;
 pub mod initialize_dao;
 pub mod initialize_proposal;
+pub mod initialize_stream;
+pub mod initialize_treasury_order;
+pub mod migrate_dao;
 pub mod update_dao;
+pub mod update_dao_settings;
+pub mod upgrade_program;
+pub mod withdraw_from_stream;
 
+pub use cancel_stream::*;
+pub use cancel_treasury_order::*;
+pub use close_proposal::*;
+pub use common::*;
+pub use crank_proposal_twaps::*;
+pub use crank_treasury_order::*;
 pub use execute_proposal::*;
 pub use finalize_proposal::*;
 pub use initialize_dao::*;
 pub use initialize_proposal::*;
+pub use initialize_stream::*;
+pub use initialize_treasury_order::*;
+pub use migrate_dao::*;
 pub use update_dao::*;
+pub use update_dao_settings::*;
+pub use upgrade_program::*;
+pub use withdraw_from_stream::*;
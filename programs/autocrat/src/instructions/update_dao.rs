@@ -40,16 +40,7 @@ impl UpdateDao<'_> {
         dao.seq_num += 1;
 
         let clock = Clock::get()?;
-        emit_cpi!(UpdateDaoEvent {
-            common: CommonFields::new(&clock),
-            dao: dao.key(),
-            pass_threshold_bps: dao.pass_threshold_bps,
-            slots_per_proposal: dao.slots_per_proposal,
-            twap_initial_observation: dao.twap_initial_observation,
-            twap_max_observation_change_per_update: dao.twap_max_observation_change_per_update,
-            min_quote_futarchic_liquidity: dao.min_quote_futarchic_liquidity,
-            min_base_futarchic_liquidity: dao.min_base_futarchic_liquidity,
-        });
+        emit_cpi!(UpdateDaoEvent::new(&clock, dao));
 
         Ok(())
     }
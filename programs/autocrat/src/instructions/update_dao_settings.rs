@@ -0,0 +1,136 @@
+use super::*;
+
+/// Settings that were added after `update_dao`'s params were fixed. They're
+/// updated separately so that existing `update_dao` proposals keep working.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct UpdateDaoSettingsParams {
+    pub delegated_proposer_cooldown_slots: Option<u64>,
+    pub delegated_proposer_min_quote_futarchic_liquidity: Option<u64>,
+    pub delegated_proposer_min_base_futarchic_liquidity: Option<u64>,
+    pub max_concurrent_proposals: Option<u32>,
+    pub max_concurrent_proposals_per_proposer: Option<u32>,
+    pub proposal_conflict_mode: Option<ProposalConflictMode>,
+    pub keeper_reward_lamports: Option<u64>,
+    pub decision_rule: Option<DecisionRule>,
+    pub min_quote_volume: Option<u64>,
+    pub delegated_proposers_to_add: Vec<Pubkey>,
+    pub delegated_proposers_to_remove: Vec<Pubkey>,
+}
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct UpdateDaoSettings<'info> {
+    #[account(mut, has_one = treasury)]
+    pub dao: Account<'info, Dao>,
+    pub treasury: Signer<'info>,
+    /// Only needed if the decision rule is being set to compare against a
+    /// spot AMM
+    pub spot_amm: Option<Account<'info, Amm>>,
+    /// CHECK: only needed if the decision rule is being set to compare
+    /// against a Raydium pool, and parsed in `validate`
+    pub spot_raydium_pool: Option<UncheckedAccount<'info>>,
+}
+
+impl UpdateDaoSettings<'_> {
+    pub fn validate(&self, params: &UpdateDaoSettingsParams) -> Result<()> {
+        // a spot market whose TWAP we can't read would make every proposal
+        // impossible to initialize
+        let Some(DecisionRule::PassOverFailAndSpot { spot_market }) = params.decision_rule else {
+            return Ok(());
+        };
+
+        match spot_market {
+            SpotMarket::Amm { amm: spot_amm } => {
+                require!(
+                    self.spot_amm.as_ref().is_some_and(|amm| {
+                        amm.key() == spot_amm
+                            && amm.base_mint == self.dao.token_mint
+                            && amm.quote_mint == self.dao.usdc_mint
+                    }),
+                    AutocratError::UnsupportedSpotMarket
+                );
+            }
+            SpotMarket::Raydium {
+                observation_state,
+                token_mint_is_token_0,
+            } => {
+                let Some(pool) = &self.spot_raydium_pool else {
+                    return err!(AutocratError::UnsupportedSpotMarket);
+                };
+                let pool = RaydiumPool::try_from_account(pool)?;
+
+                let (token_mint, usdc_mint) = if token_mint_is_token_0 {
+                    (pool.token_0_mint, pool.token_1_mint)
+                } else {
+                    (pool.token_1_mint, pool.token_0_mint)
+                };
+
+                require!(
+                    token_mint == self.dao.token_mint
+                        && usdc_mint == self.dao.usdc_mint
+                        && pool.observation_key == observation_state,
+                    AutocratError::UnsupportedSpotMarket
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>, params: UpdateDaoSettingsParams) -> Result<()> {
+        let dao = &mut ctx.accounts.dao;
+
+        macro_rules! update_dao_if_passed {
+            ($field:ident) => {
+                if let Some(value) = params.$field {
+                    dao.$field = value;
+                }
+            };
+        }
+
+        update_dao_if_passed!(delegated_proposer_cooldown_slots);
+        update_dao_if_passed!(delegated_proposer_min_quote_futarchic_liquidity);
+        update_dao_if_passed!(delegated_proposer_min_base_futarchic_liquidity);
+        update_dao_if_passed!(max_concurrent_proposals);
+        update_dao_if_passed!(max_concurrent_proposals_per_proposer);
+        update_dao_if_passed!(proposal_conflict_mode);
+        update_dao_if_passed!(keeper_reward_lamports);
+        update_dao_if_passed!(decision_rule);
+        update_dao_if_passed!(min_quote_volume);
+
+        // both are idempotent, like the timelock's optimistic proposers
+        dao.delegated_proposers.retain(|delegated_proposer| {
+            !params
+                .delegated_proposers_to_remove
+                .contains(&delegated_proposer.pubkey)
+        });
+
+        for proposer in params.delegated_proposers_to_add {
+            if dao
+                .delegated_proposers
+                .iter()
+                .any(|delegated_proposer| delegated_proposer.pubkey == proposer)
+            {
+                continue;
+            }
+
+            dao.delegated_proposers.push(DelegatedProposer {
+                pubkey: proposer,
+                last_slot_proposed: 0,
+            });
+        }
+
+        require_gte!(
+            MAX_DELEGATED_PROPOSERS,
+            dao.delegated_proposers.len(),
+            AutocratError::TooManyDelegatedProposers
+        );
+
+        dao.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(UpdateDaoEvent::new(&clock, dao));
+
+        Ok(())
+    }
+}
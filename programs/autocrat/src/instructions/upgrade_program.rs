@@ -0,0 +1,213 @@
+use super::*;
+
+use anchor_lang::solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct UpgradeProgramParams {
+    /// The SHA-256 hash of the program's ELF, i.e. of the buffer's data after
+    /// its metadata. Because this is stored in the proposal's instruction, it's
+    /// pinned when the proposal is created.
+    pub buffer_hash: [u8; 32],
+}
+
+/// Upgrades a program whose upgrade authority is a DAO's treasury.
+///
+/// This is meant to be the instruction of a proposal. A buffer's authority
+/// can write to it at any time, so we check that its contents still match the
+/// hash the market voted on before performing the upgrade.
+#[derive(Accounts)]
+#[event_cpi]
+pub struct UpgradeProgram<'info> {
+    #[account(has_one = treasury)]
+    pub dao: Box<Account<'info, Dao>>,
+    pub treasury: Signer<'info>,
+    /// CHECK: checked by the BPF loader, which upgrades the program that
+    /// `program_data` belongs to
+    #[account(mut)]
+    pub target_program: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [target_program.key().as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(treasury.key()) @ AutocratError::InvalidUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(mut)]
+    pub buffer: Account<'info, UpgradeableLoaderState>,
+    /// CHECK: receives the buffer's lamports
+    #[account(mut)]
+    pub spill: UncheckedAccount<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+    /// CHECK: checked by address
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+}
+
+/// Checks that a buffer is still owned by `treasury` and that the ELF after
+/// its metadata hashes to `buffer_hash`.
+pub fn verify_buffer(
+    buffer: &UpgradeableLoaderState,
+    buffer_data: &[u8],
+    treasury: Pubkey,
+    buffer_hash: [u8; 32],
+) -> Result<()> {
+    match *buffer {
+        UpgradeableLoaderState::Buffer { authority_address } => require!(
+            authority_address == Some(treasury),
+            AutocratError::InvalidUpgradeAuthority
+        ),
+        _ => return err!(AutocratError::InvalidBuffer),
+    }
+
+    let program_elf = buffer_data
+        .get(UpgradeableLoaderState::size_of_buffer_metadata()..)
+        .ok_or(error!(AutocratError::InvalidBuffer))?;
+
+    require!(
+        solana_program::hash::hash(program_elf).to_bytes() == buffer_hash,
+        AutocratError::BufferHashMismatch
+    );
+
+    Ok(())
+}
+
+impl UpgradeProgram<'_> {
+    pub fn validate(&self, params: &UpgradeProgramParams) -> Result<()> {
+        let buffer_info = self.buffer.to_account_info();
+        let buffer_data = buffer_info.try_borrow_data()?;
+
+        verify_buffer(
+            &self.buffer,
+            &buffer_data,
+            self.treasury.key(),
+            params.buffer_hash,
+        )
+    }
+
+    pub fn handle(ctx: Context<Self>, params: UpgradeProgramParams) -> Result<()> {
+        let Self {
+            dao,
+            treasury,
+            target_program,
+            program_data,
+            buffer,
+            spill,
+            rent,
+            clock,
+            bpf_loader_upgradeable_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let upgrade_ix = bpf_loader_upgradeable::upgrade(
+            &target_program.key(),
+            &buffer.key(),
+            &treasury.key(),
+            &spill.key(),
+        );
+
+        // the treasury already signed this instruction, so no need for seeds
+        solana_program::program::invoke(
+            &upgrade_ix,
+            &[
+                program_data.to_account_info(),
+                target_program.to_account_info(),
+                buffer.to_account_info(),
+                spill.to_account_info(),
+                rent.to_account_info(),
+                clock.to_account_info(),
+                treasury.to_account_info(),
+                bpf_loader_upgradeable_program.to_account_info(),
+            ],
+        )?;
+
+        emit_cpi!(UpgradeProgramEvent {
+            common: CommonFields::new(clock),
+            dao: dao.key(),
+            program: target_program.key(),
+            buffer: buffer.key(),
+            buffer_hash: params.buffer_hash,
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod upgrade_program_tests {
+    use super::*;
+
+    const ELF: &[u8] = b"\x7fELF pretend this is a program";
+
+    fn buffer_data() -> Vec<u8> {
+        let mut data = vec![0; UpgradeableLoaderState::size_of_buffer_metadata()];
+        data.extend_from_slice(ELF);
+        data
+    }
+
+    fn buffer(authority: Pubkey) -> UpgradeableLoaderState {
+        UpgradeableLoaderState::Buffer {
+            authority_address: Some(authority),
+        }
+    }
+
+    #[test]
+    fn accepts_a_buffer_matching_the_pinned_hash() {
+        let treasury = Pubkey::new_unique();
+        let buffer_hash = solana_program::hash::hash(ELF).to_bytes();
+
+        assert!(verify_buffer(&buffer(treasury), &buffer_data(), treasury, buffer_hash).is_ok());
+    }
+
+    #[test]
+    fn rejects_a_buffer_that_changed_after_the_hash_was_pinned() {
+        let treasury = Pubkey::new_unique();
+        let buffer_hash = solana_program::hash::hash(ELF).to_bytes();
+
+        let mut data = buffer_data();
+        *data.last_mut().unwrap() ^= 1;
+
+        assert_eq!(
+            verify_buffer(&buffer(treasury), &data, treasury, buffer_hash).unwrap_err(),
+            AutocratError::BufferHashMismatch.into()
+        );
+    }
+
+    #[test]
+    fn rejects_a_buffer_the_treasury_doesnt_own() {
+        let treasury = Pubkey::new_unique();
+        let buffer_hash = solana_program::hash::hash(ELF).to_bytes();
+
+        assert_eq!(
+            verify_buffer(
+                &buffer(Pubkey::new_unique()),
+                &buffer_data(),
+                treasury,
+                buffer_hash
+            )
+            .unwrap_err(),
+            AutocratError::InvalidUpgradeAuthority.into()
+        );
+
+        let no_authority = UpgradeableLoaderState::Buffer {
+            authority_address: None,
+        };
+        assert_eq!(
+            verify_buffer(&no_authority, &buffer_data(), treasury, buffer_hash).unwrap_err(),
+            AutocratError::InvalidUpgradeAuthority.into()
+        );
+
+        assert_eq!(
+            verify_buffer(
+                &UpgradeableLoaderState::Uninitialized,
+                &buffer_data(),
+                treasury,
+                buffer_hash
+            )
+            .unwrap_err(),
+            AutocratError::InvalidBuffer.into()
+        );
+    }
+}
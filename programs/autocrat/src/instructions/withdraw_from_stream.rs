@@ -0,0 +1,73 @@
+use super::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct WithdrawFromStream<'info> {
+    #[account(mut, has_one = recipient)]
+    pub stream: Box<Account<'info, Stream>>,
+    pub recipient: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = stream.mint,
+        associated_token::authority = stream,
+    )]
+    pub stream_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = stream.mint,
+        token::authority = recipient,
+    )]
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl WithdrawFromStream<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let Self {
+            stream,
+            recipient,
+            stream_token_account,
+            recipient_token_account,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+
+        let amount = stream.withdrawable_amount(clock.unix_timestamp);
+
+        require_gt!(amount, 0, AutocratError::NothingToWithdraw);
+
+        let nonce_bytes = stream.nonce.to_le_bytes();
+        let seeds = generate_stream_seeds!(stream, nonce_bytes);
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: stream_token_account.to_account_info(),
+                    to: recipient_token_account.to_account_info(),
+                    authority: stream.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+
+        stream.withdrawn_amount += amount;
+        stream.seq_num += 1;
+
+        emit_cpi!(WithdrawFromStreamEvent {
+            common: CommonFields::new(&clock),
+            stream: stream.key(),
+            recipient: recipient.key(),
+            amount,
+            total_withdrawn: stream.withdrawn_amount,
+            seq_num: stream.seq_num,
+        });
+
+        Ok(())
+    }
+}
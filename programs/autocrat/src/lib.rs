@@ -23,6 +23,19 @@
 //!   redeem.
 //! - Executed: if a proposal passes, anyone can make autocrat execute its SVM
 //!   instruction by calling `execute_proposal`.
+//!
+//! Proposals can also create streams, which pay a recipient from the treasury
+//! linearly over time. A stream can be cancelled by a later proposal, which
+//! returns its unvested tokens to the treasury.
+//!
+//! DAOs that hold the upgrade authority of a program in their treasury can
+//! upgrade it with a proposal that calls `upgrade_program`. The proposal pins
+//! the hash of the buffer, so the buffer can't be swapped out from under the
+//! market.
+//!
+//! Proposals can also create treasury orders, which buy back or sell the DAO's
+//! token against an AMM in capped slices over a range of slots. Anyone can
+//! crank an order, and a later proposal can cancel it.
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
@@ -71,13 +84,20 @@ pub const TEN_DAYS_IN_SECONDS: i64 = 10 * 24 * 60 * 60;
 // by default, the pass price needs to be 3% higher than the fail price
 pub const DEFAULT_PASS_THRESHOLD_BPS: u16 = 300;
 
-pub const MAX_BPS: u16 = 10_000;
+pub use price_math::MAX_BPS;
+
+// in bytes
+pub const MAX_TITLE_LENGTH: usize = 128;
+pub const MAX_DESCRIPTION_URL_LENGTH: usize = 256;
 
 // the index of the fail and pass outcomes in the question and the index of
 // the pass and fail conditional tokens in the conditional vault
 pub const FAIL_INDEX: usize = 0;
 pub const PASS_INDEX: usize = 1;
 
+// bounds the size of the `Dao` account, which is allocated up front
+pub const MAX_DELEGATED_PROPOSERS: usize = 10;
+
 // TWAP can only move by $5 per slot
 pub const DEFAULT_MAX_OBSERVATION_CHANGE_PER_UPDATE_LOTS: u64 = 5_000;
 
@@ -89,7 +109,7 @@ pub mod autocrat {
         InitializeDao::handle(ctx, params)
     }
 
-    #[access_control(ctx.accounts.validate())]
+    #[access_control(ctx.accounts.validate(&params))]
     pub fn initialize_proposal(
         ctx: Context<InitializeProposal>,
         params: InitializeProposalParams,
@@ -107,7 +127,73 @@ pub mod autocrat {
         ExecuteProposal::handle(ctx)
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        CloseProposal::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn crank_proposal_twaps(ctx: Context<CrankProposalTwaps>) -> Result<()> {
+        CrankProposalTwaps::handle(ctx)
+    }
+
     pub fn update_dao(ctx: Context<UpdateDao>, dao_params: UpdateDaoParams) -> Result<()> {
         UpdateDao::handle(ctx, dao_params)
     }
+
+    #[access_control(ctx.accounts.validate(&params))]
+    pub fn update_dao_settings(
+        ctx: Context<UpdateDaoSettings>,
+        params: UpdateDaoSettingsParams,
+    ) -> Result<()> {
+        UpdateDaoSettings::handle(ctx, params)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_dao(ctx: Context<MigrateDao>) -> Result<()> {
+        MigrateDao::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(&params))]
+    pub fn initialize_stream(
+        ctx: Context<InitializeStream>,
+        params: InitializeStreamParams,
+    ) -> Result<()> {
+        InitializeStream::handle(ctx, params)
+    }
+
+    pub fn withdraw_from_stream(ctx: Context<WithdrawFromStream>) -> Result<()> {
+        WithdrawFromStream::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+        CancelStream::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(&params))]
+    pub fn upgrade_program(
+        ctx: Context<UpgradeProgram>,
+        params: UpgradeProgramParams,
+    ) -> Result<()> {
+        UpgradeProgram::handle(ctx, params)
+    }
+
+    #[access_control(ctx.accounts.validate(&params))]
+    pub fn initialize_treasury_order(
+        ctx: Context<InitializeTreasuryOrder>,
+        params: InitializeTreasuryOrderParams,
+    ) -> Result<()> {
+        InitializeTreasuryOrder::handle(ctx, params)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn crank_treasury_order(ctx: Context<CrankTreasuryOrder>) -> Result<()> {
+        CrankTreasuryOrder::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_treasury_order(ctx: Context<CancelTreasuryOrder>) -> Result<()> {
+        CancelTreasuryOrder::handle(ctx)
+    }
 }
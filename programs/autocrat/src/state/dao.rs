@@ -1,5 +1,64 @@
 pub use super::*;
 
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub struct DelegatedProposer {
+    pub pubkey: Pubkey,
+    pub last_slot_proposed: u64,
+}
+
+/// What to do when a new proposal writes to an account that a trading proposal
+/// also writes to. Both could pass and execute, with outcomes that only made
+/// sense in isolation.
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub enum ProposalConflictMode {
+    Ignore,
+    /// Allow the proposal, but list the proposals it conflicts with in
+    /// `InitializeProposalEvent`
+    Flag,
+    Reject,
+}
+
+/// How a proposal's markets decide whether it passes.
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub enum DecisionRule {
+    /// Pass if the pass TWAP beats the fail TWAP by `pass_threshold_bps`.
+    PassOverFail,
+    /// Pass if the pass TWAP beats both the fail TWAP and the TWAP of
+    /// `spot_market` over the proposal's lifetime by `pass_threshold_bps`.
+    /// This filters out noise from the spot price moving a lot while the
+    /// proposal is trading.
+    PassOverFailAndSpot { spot_market: SpotMarket },
+}
+
+/// A market that trades the DAO's token against its USDC outside of
+/// proposals.
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub enum SpotMarket {
+    /// An AMM from our AMM program, which gets cranked before its TWAP is
+    /// read.
+    Amm { amm: Pubkey },
+    /// A Raydium CPMM pool, read from its observation account. Raydium only
+    /// records an observation on a swap at least 15 seconds after the last
+    /// one, so the TWAP runs from the last observation before the proposal
+    /// to the last one before it's finalized.
+    Raydium {
+        observation_state: Pubkey,
+        token_mint_is_token_0: bool,
+    },
+}
+
+impl SpotMarket {
+    /// The account the market's TWAP is read from
+    pub fn oracle(&self) -> Pubkey {
+        match self {
+            Self::Amm { amm } => *amm,
+            Self::Raydium {
+                observation_state, ..
+            } => *observation_state,
+        }
+    }
+}
+
 #[account]
 pub struct Dao {
     pub treasury_pda_bump: u8,
@@ -36,4 +95,105 @@ pub struct Dao {
     pub min_quote_futarchic_liquidity: u64,
     pub min_base_futarchic_liquidity: u64,
     pub seq_num: u64,
+    // Everything below was added after DAOs were already live. Older DAOs need
+    // to call `migrate_dao` to make room for it.
+    /// Delegated proposers can create proposals with a smaller LP lock than
+    /// everyone else, but only once every `delegated_proposer_cooldown_slots`.
+    /// This lets a DAO hand out proposal rights to trusted contributors who
+    /// don't have much liquidity. Setting the delegated minimums to 0 lets
+    /// them propose without locking any LP at all.
+    pub delegated_proposers: Vec<DelegatedProposer>,
+    pub delegated_proposer_cooldown_slots: u64,
+    pub delegated_proposer_min_quote_futarchic_liquidity: u64,
+    pub delegated_proposer_min_base_futarchic_liquidity: u64,
+    /// The number of proposals that are currently trading.
+    pub active_proposal_count: u32,
+    /// To keep proposals from splitting liquidity and attention, a DAO can cap
+    /// how many proposals can trade at once, both in total and per proposer.
+    /// 0 means no limit.
+    pub max_concurrent_proposals: u32,
+    pub max_concurrent_proposals_per_proposer: u32,
+    pub proposal_conflict_mode: ProposalConflictMode,
+    /// Keepers who crank a proposal's TWAPs, finalize it, or execute it get
+    /// paid this many lamports from the DAO's keeper pool, a system account at
+    /// the PDA [b"keeper_pool", dao]. Anyone can fund the pool by transferring
+    /// SOL to it. To keep keepers from farming the pool, at most one reward is
+    /// paid per slot. 0 disables rewards.
+    pub keeper_reward_lamports: u64,
+    pub last_keeper_reward_slot: u64,
+    pub decision_rule: DecisionRule,
+    /// A proposal can only pass if both of its markets have traded at least
+    /// this much quote volume while it was trading. Otherwise it fails by default, so that a
+    /// proposal nobody traded can't pass on drifting observations. 0 disables
+    /// the check.
+    pub min_quote_volume: u64,
+}
+
+impl Dao {
+    pub fn space() -> usize {
+        // `size_of::<Dao>()` already counts a `Vec`'s length prefix
+        8 + std::mem::size_of::<Dao>()
+            + MAX_DELEGATED_PROPOSERS * std::mem::size_of::<DelegatedProposer>()
+    }
+}
+
+#[cfg(test)]
+mod dao_tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    // the fields a DAO had before `migrate_dao` existed
+    #[derive(AnchorSerialize)]
+    struct DaoV0 {
+        treasury_pda_bump: u8,
+        treasury: Pubkey,
+        token_mint: Pubkey,
+        usdc_mint: Pubkey,
+        proposal_count: u32,
+        pass_threshold_bps: u16,
+        slots_per_proposal: u64,
+        twap_initial_observation: u128,
+        twap_max_observation_change_per_update: u128,
+        min_quote_futarchic_liquidity: u64,
+        min_base_futarchic_liquidity: u64,
+        seq_num: u64,
+    }
+
+    #[test]
+    fn migrated_dao_keeps_old_fields_and_zeroes_new_ones() {
+        let old_dao = DaoV0 {
+            treasury_pda_bump: 254,
+            treasury: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            usdc_mint: Pubkey::new_unique(),
+            proposal_count: 12,
+            pass_threshold_bps: 300,
+            slots_per_proposal: THREE_DAYS_IN_SLOTS,
+            twap_initial_observation: 400_000,
+            twap_max_observation_change_per_update: 8_000,
+            min_quote_futarchic_liquidity: 5_000_000_000,
+            min_base_futarchic_liquidity: 10_000_000_000,
+            seq_num: 42,
+        };
+
+        let mut data = Dao::DISCRIMINATOR.to_vec();
+        old_dao.serialize(&mut data).unwrap();
+        // `realloc` zero-initializes the new bytes
+        data.resize(Dao::space(), 0);
+
+        let dao = Dao::try_deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(dao.treasury, old_dao.treasury);
+        assert_eq!(dao.proposal_count, 12);
+        assert_eq!(dao.min_base_futarchic_liquidity, 10_000_000_000);
+        assert_eq!(dao.seq_num, 42);
+        assert!(dao.delegated_proposers.is_empty());
+        assert_eq!(dao.delegated_proposer_cooldown_slots, 0);
+        assert_eq!(dao.active_proposal_count, 0);
+        assert_eq!(dao.max_concurrent_proposals, 0);
+        assert_eq!(dao.keeper_reward_lamports, 0);
+        assert_eq!(dao.proposal_conflict_mode, ProposalConflictMode::Ignore);
+        assert_eq!(dao.decision_rule, DecisionRule::PassOverFail);
+        assert_eq!(dao.min_quote_volume, 0);
+    }
 }
@@ -1,7 +1,15 @@
 pub mod dao;
 pub mod proposal;
+pub mod proposer_record;
+pub mod raydium;
+pub mod stream;
+pub mod treasury_order;
 
 pub use dao::*;
 pub use proposal::*;
+pub use proposer_record::*;
+pub use raydium::*;
+pub use stream::*;
+pub use treasury_order::*;
 
 pub use super::*;
@@ -8,6 +8,36 @@ pub enum ProposalState {
     Executed,
 }
 
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub enum ProposalCategory {
+    General,
+    Treasury,
+    Governance,
+    ProgramUpgrade,
+}
+
+/// The state of a spot market's TWAP oracle at some point, so that we can
+/// compute the TWAP between then and a later reading.
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub struct TwapSnapshot {
+    pub spot_market: SpotMarket,
+    pub aggregator: u128,
+    /// A slot for AMMs, or a unix timestamp for Raydium pools
+    pub time: u64,
+}
+
+impl TwapSnapshot {
+    /// The TWAP between this snapshot and a later reading of the same oracle.
+    pub fn twap_until(&self, aggregator: u128, time: u64) -> Result<u128> {
+        let time_passed = time.saturating_sub(self.time) as u128;
+
+        require_neq!(time_passed, 0, AutocratError::SpotTwapUnavailable);
+
+        // the aggregator wraps on overflow, so wrap here too
+        Ok(aggregator.wrapping_sub(self.aggregator) / time_passed)
+    }
+}
+
 #[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
 pub struct ProposalAccount {
     pub pubkey: Pubkey,
@@ -44,6 +74,79 @@ pub struct Proposal {
     pub nonce: u64,
     pub pda_bump: u8,
     pub question: Pubkey,
+    // Everything below was added after proposals were already live. Older
+    // proposals were created with `space = 2000`, so these fields decode from
+    // their zero padding as empty, `None`, `false` or 0.
+    /// Whether this proposal was counted in the proposer's `ProposerRecord`,
+    /// so that finalizing it knows whether to uncount it.
+    pub counted_in_proposer_record: bool,
+    /// The accounts `instruction` writes to, used to detect conflicting
+    /// proposals.
+    pub writable_accounts: Vec<Pubkey>,
+    pub title: String,
+    /// A hash of the proposal's description (e.g. the SHA-256 of its markdown),
+    /// so that indexers can check the description at `description_url` hasn't
+    /// been edited since the markets opened.
+    pub content_hash: [u8; 32],
+    pub category: ProposalCategory,
+    /// Set when the DAO's decision rule compares against a spot market, so
+    /// that we can compute the spot TWAP over the proposal's lifetime.
+    pub spot_twap_snapshot: Option<TwapSnapshot>,
+    /// The markets' cumulative quote volume when the proposal was initialized.
+    /// The DAO's `min_quote_volume` applies to the volume traded after this,
+    /// so that trades made before the proposal existed don't count.
+    pub pass_quote_volume_at_start: u128,
+    pub fail_quote_volume_at_start: u128,
+    /// AMM positions the proposer locked on top of their LP tokens, which
+    /// are unlocked when the proposal is finalized
+    pub pass_position: Option<Pubkey>,
+    pub fail_position: Option<Pubkey>,
+}
+
+impl Proposal {
+    /// The size of a proposal account, including its discriminator, given its
+    /// variable-length fields.
+    pub fn space(description_url: &str, title: &str, instruction: &ProposalInstruction) -> usize {
+        let instruction_space = 32 // program_id
+            + 4 + instruction.accounts.len() * (32 + 1 + 1)
+            + 4 + instruction.data.len();
+
+        8 // discriminator
+            + 4 // number
+            + 32 // proposer
+            + 4 + description_url.len()
+            + 8 // slot_enqueued
+            + 1 // state
+            + instruction_space
+            + 5 * 32 // pass_amm, fail_amm, base_vault, quote_vault, dao
+            + 2 * 8 // pass_lp_tokens_locked, fail_lp_tokens_locked
+            + 8 // nonce
+            + 1 // pda_bump
+            + 32 // question
+            + 1 // counted_in_proposer_record
+            // every account could be writable
+            + 4 + instruction.accounts.len() * 32
+            + 4 + title.len()
+            + 32 // content_hash
+            + 1 // category
+            + 1 + (1 + 32 + 1) + 16 + 8 // spot_twap_snapshot
+            + 2 * 16 // pass_quote_volume_at_start, fail_quote_volume_at_start
+            + 2 * (1 + 32) // pass_position, fail_position
+    }
+}
+
+impl ProposalInstruction {
+    pub fn writable_accounts(&self) -> Vec<Pubkey> {
+        let mut writable_accounts: Vec<Pubkey> = vec![];
+
+        for acc in self.accounts.iter().filter(|acc| acc.is_writable) {
+            if !writable_accounts.contains(&acc.pubkey) {
+                writable_accounts.push(acc.pubkey);
+            }
+        }
+
+        writable_accounts
+    }
 }
 
 impl From<&ProposalInstruction> for Instruction {
@@ -65,3 +168,201 @@ impl From<&ProposalAccount> for AccountMeta {
         }
     }
 }
+
+#[cfg(test)]
+mod proposal_tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    // the fields a proposal had before any were added
+    #[derive(AnchorSerialize)]
+    struct ProposalV0 {
+        number: u32,
+        proposer: Pubkey,
+        description_url: String,
+        slot_enqueued: u64,
+        state: ProposalState,
+        instruction: ProposalInstruction,
+        pass_amm: Pubkey,
+        fail_amm: Pubkey,
+        base_vault: Pubkey,
+        quote_vault: Pubkey,
+        dao: Pubkey,
+        pass_lp_tokens_locked: u64,
+        fail_lp_tokens_locked: u64,
+        nonce: u64,
+        pda_bump: u8,
+        question: Pubkey,
+    }
+
+    fn proposal(instruction: ProposalInstruction) -> Proposal {
+        Proposal {
+            number: 1,
+            proposer: Pubkey::new_unique(),
+            description_url: "https://example.com/proposal".into(),
+            title: "Buy back META".into(),
+            content_hash: [7; 32],
+            category: ProposalCategory::Treasury,
+            slot_enqueued: 100,
+            state: ProposalState::Pending,
+            writable_accounts: instruction.writable_accounts(),
+            instruction,
+            pass_amm: Pubkey::new_unique(),
+            fail_amm: Pubkey::new_unique(),
+            base_vault: Pubkey::new_unique(),
+            quote_vault: Pubkey::new_unique(),
+            dao: Pubkey::new_unique(),
+            pass_lp_tokens_locked: 1_000,
+            fail_lp_tokens_locked: 1_000,
+            nonce: 0,
+            pda_bump: 255,
+            question: Pubkey::new_unique(),
+            counted_in_proposer_record: true,
+            spot_twap_snapshot: Some(TwapSnapshot {
+                spot_market: SpotMarket::Raydium {
+                    observation_state: Pubkey::new_unique(),
+                    token_mint_is_token_0: true,
+                },
+                aggregator: 1_000,
+                time: 50,
+            }),
+            pass_quote_volume_at_start: 10_000,
+            fail_quote_volume_at_start: 0,
+            pass_position: Some(Pubkey::new_unique()),
+            fail_position: Some(Pubkey::new_unique()),
+        }
+    }
+
+    fn serialized_len(proposal: &Proposal) -> usize {
+        let mut data = vec![];
+        proposal.try_serialize(&mut data).unwrap();
+        data.len()
+    }
+
+    #[test]
+    fn twap_between_snapshots() {
+        let snapshot = TwapSnapshot {
+            spot_market: SpotMarket::Amm {
+                amm: Pubkey::default(),
+            },
+            aggregator: 1_000,
+            time: 100,
+        };
+
+        assert!(snapshot.twap_until(1_000, 100).is_err());
+
+        assert_eq!(snapshot.twap_until(1_000 + 50 * 20, 150).unwrap(), 20);
+
+        // the aggregator wrapped since the snapshot
+        let snapshot = TwapSnapshot {
+            aggregator: u128::MAX - 99,
+            ..snapshot
+        };
+        assert_eq!(snapshot.twap_until(900, 150).unwrap(), 20);
+    }
+
+    #[test]
+    fn space_fits_all_writable_accounts() {
+        let instruction = ProposalInstruction {
+            program_id: Pubkey::new_unique(),
+            accounts: (0..10)
+                .map(|_| ProposalAccount {
+                    pubkey: Pubkey::new_unique(),
+                    is_signer: false,
+                    is_writable: true,
+                })
+                .collect(),
+            data: vec![1; 200],
+        };
+        let proposal = proposal(instruction);
+
+        assert_eq!(
+            Proposal::space(
+                &proposal.description_url,
+                &proposal.title,
+                &proposal.instruction
+            ),
+            serialized_len(&proposal)
+        );
+    }
+
+    #[test]
+    fn space_fits_no_writable_accounts() {
+        let instruction = ProposalInstruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![ProposalAccount {
+                pubkey: Pubkey::new_unique(),
+                is_signer: true,
+                is_writable: false,
+            }],
+            data: vec![],
+        };
+        let proposal = proposal(instruction);
+
+        assert!(
+            Proposal::space(
+                &proposal.description_url,
+                &proposal.title,
+                &proposal.instruction
+            ) >= serialized_len(&proposal)
+        );
+    }
+
+    #[test]
+    fn old_proposal_decodes_with_new_fields_empty() {
+        let old_proposal = ProposalV0 {
+            number: 7,
+            proposer: Pubkey::new_unique(),
+            description_url: "https://example.com/proposal".into(),
+            slot_enqueued: 100,
+            state: ProposalState::Passed,
+            instruction: ProposalInstruction {
+                program_id: Pubkey::new_unique(),
+                accounts: vec![ProposalAccount {
+                    pubkey: Pubkey::new_unique(),
+                    is_signer: false,
+                    is_writable: true,
+                }],
+                data: vec![1, 2, 3],
+            },
+            pass_amm: Pubkey::new_unique(),
+            fail_amm: Pubkey::new_unique(),
+            base_vault: Pubkey::new_unique(),
+            quote_vault: Pubkey::new_unique(),
+            dao: Pubkey::new_unique(),
+            pass_lp_tokens_locked: 1_000,
+            fail_lp_tokens_locked: 2_000,
+            nonce: 42,
+            pda_bump: 254,
+            question: Pubkey::new_unique(),
+        };
+
+        let mut data = Proposal::DISCRIMINATOR.to_vec();
+        old_proposal.serialize(&mut data).unwrap();
+        // proposals used to be created with `space = 2000`
+        data.resize(2000, 0);
+
+        let proposal = Proposal::try_deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(proposal.number, 7);
+        assert_eq!(proposal.description_url, old_proposal.description_url);
+        assert!(proposal.state == ProposalState::Passed);
+        assert_eq!(proposal.instruction, old_proposal.instruction);
+        assert_eq!(proposal.fail_lp_tokens_locked, 2_000);
+        assert_eq!(proposal.nonce, 42);
+        assert_eq!(proposal.question, old_proposal.question);
+        assert!(proposal.writable_accounts.is_empty());
+        assert_eq!(proposal.title, "");
+        assert_eq!(proposal.content_hash, [0; 32]);
+        assert_eq!(proposal.category, ProposalCategory::General);
+        assert_eq!(proposal.spot_twap_snapshot, None);
+        assert_eq!(proposal.pass_quote_volume_at_start, 0);
+        assert_eq!(proposal.pass_position, None);
+        assert!(!proposal.counted_in_proposer_record);
+
+        // and it still fits once it's written back, e.g. when it's finalized
+        let mut new_data = vec![];
+        proposal.try_serialize(&mut new_data).unwrap();
+        assert!(new_data.len() <= 2000);
+    }
+}
@@ -0,0 +1,11 @@
+use super::*;
+
+/// Tracks how many proposals a proposer has trading in a DAO, so that the DAO
+/// can enforce `max_concurrent_proposals_per_proposer`.
+#[account]
+pub struct ProposerRecord {
+    pub dao: Pubkey,
+    pub proposer: Pubkey,
+    pub active_proposal_count: u32,
+    pub pda_bump: u8,
+}
@@ -0,0 +1,207 @@
+//! Just enough of Raydium's CPMM accounts to read a pool's price oracle, so
+//! that a DAO whose token trades on a Raydium pool, like the one a launch
+//! seeds, can use it as its spot market. We read the accounts by hand rather
+//! than depend on Raydium's crate.
+use super::*;
+
+pub mod raydium_cpmm {
+    use anchor_lang::declare_id;
+
+    #[cfg(feature = "devnet")]
+    declare_id!("CPMDWBwJDtYax9qW7AyRuVC19Cc4L4Vcy4n2BHAbHkCW");
+    #[cfg(not(feature = "devnet"))]
+    declare_id!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+}
+
+/// The first 8 bytes of sha256("account:PoolState")
+const POOL_STATE_DISCRIMINATOR: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
+/// The first 8 bytes of sha256("account:ObservationState")
+const OBSERVATION_STATE_DISCRIMINATOR: [u8; 8] = [122, 174, 197, 53, 129, 9, 165, 132];
+
+// offsets into a `PoolState`, counting the discriminator
+const POOL_TOKEN_0_MINT_OFFSET: usize = 168;
+const POOL_TOKEN_1_MINT_OFFSET: usize = 200;
+const POOL_OBSERVATION_KEY_OFFSET: usize = 296;
+
+// an `ObservationState` is packed, and holds a ring buffer of observations
+// after its header
+const OBSERVATION_INITIALIZED_OFFSET: usize = 8;
+const OBSERVATION_INDEX_OFFSET: usize = 9;
+const OBSERVATIONS_OFFSET: usize = 43;
+const OBSERVATION_LEN: usize = 8 + 16 + 16;
+const OBSERVATION_NUM: usize = 100;
+
+/// The parts of a Raydium CPMM pool we need to check that it's a DAO's spot
+/// market.
+pub struct RaydiumPool {
+    pub token_0_mint: Pubkey,
+    pub token_1_mint: Pubkey,
+    pub observation_key: Pubkey,
+}
+
+impl RaydiumPool {
+    pub fn try_from_account(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            raydium_cpmm::ID,
+            AutocratError::UnsupportedSpotMarket
+        );
+
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= POOL_OBSERVATION_KEY_OFFSET + 32 && data[..8] == POOL_STATE_DISCRIMINATOR,
+            AutocratError::UnsupportedSpotMarket
+        );
+
+        Ok(Self {
+            token_0_mint: read_pubkey(&data, POOL_TOKEN_0_MINT_OFFSET),
+            token_1_mint: read_pubkey(&data, POOL_TOKEN_1_MINT_OFFSET),
+            observation_key: read_pubkey(&data, POOL_OBSERVATION_KEY_OFFSET),
+        })
+    }
+}
+
+/// A Raydium pool's most recent observation. Like our AMM's aggregator, the
+/// cumulative prices are price * time summed since the pool was created,
+/// except that time is in seconds and prices are in Q32.32 fixed point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RaydiumObservation {
+    pub block_timestamp: u64,
+    /// The price of token 0 in token 1
+    pub cumulative_token_0_price_x32: u128,
+    /// The price of token 1 in token 0
+    pub cumulative_token_1_price_x32: u128,
+}
+
+impl RaydiumObservation {
+    pub fn latest(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            raydium_cpmm::ID,
+            AutocratError::InvalidSpotMarket
+        );
+
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= OBSERVATIONS_OFFSET + OBSERVATION_NUM * OBSERVATION_LEN
+                && data[..8] == OBSERVATION_STATE_DISCRIMINATOR,
+            AutocratError::InvalidSpotMarket
+        );
+
+        // Raydium only records observations on swaps
+        require!(
+            data[OBSERVATION_INITIALIZED_OFFSET] != 0,
+            AutocratError::SpotTwapUnavailable
+        );
+
+        let index = u16::from_le_bytes(
+            data[OBSERVATION_INDEX_OFFSET..OBSERVATION_INDEX_OFFSET + 2]
+                .try_into()
+                .unwrap(),
+        ) as usize;
+        require_gt!(OBSERVATION_NUM, index, AutocratError::InvalidSpotMarket);
+
+        let offset = OBSERVATIONS_OFFSET + index * OBSERVATION_LEN;
+
+        Ok(Self {
+            block_timestamp: u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()),
+            cumulative_token_0_price_x32: u128::from_le_bytes(
+                data[offset + 8..offset + 24].try_into().unwrap(),
+            ),
+            cumulative_token_1_price_x32: u128::from_le_bytes(
+                data[offset + 24..offset + 40].try_into().unwrap(),
+            ),
+        })
+    }
+
+    /// The cumulative price of the DAO's token in USDC
+    pub fn cumulative_token_price_x32(&self, token_mint_is_token_0: bool) -> u128 {
+        if token_mint_is_token_0 {
+            self.cumulative_token_0_price_x32
+        } else {
+            self.cumulative_token_1_price_x32
+        }
+    }
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod raydium_tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    #[test]
+    fn discriminators_match_raydium_accounts() {
+        assert_eq!(
+            hash(b"account:PoolState").to_bytes()[..8],
+            POOL_STATE_DISCRIMINATOR
+        );
+        assert_eq!(
+            hash(b"account:ObservationState").to_bytes()[..8],
+            OBSERVATION_STATE_DISCRIMINATOR
+        );
+    }
+
+    #[test]
+    fn reads_latest_observation() {
+        let mut data = vec![0; OBSERVATIONS_OFFSET + OBSERVATION_NUM * OBSERVATION_LEN + 32];
+        data[..8].copy_from_slice(&OBSERVATION_STATE_DISCRIMINATOR);
+        data[OBSERVATION_INITIALIZED_OFFSET] = 1;
+        data[OBSERVATION_INDEX_OFFSET..OBSERVATION_INDEX_OFFSET + 2]
+            .copy_from_slice(&7u16.to_le_bytes());
+
+        let offset = OBSERVATIONS_OFFSET + 7 * OBSERVATION_LEN;
+        data[offset..offset + 8].copy_from_slice(&1_700_000_000u64.to_le_bytes());
+        data[offset + 8..offset + 24].copy_from_slice(&(5u128 << 32).to_le_bytes());
+        data[offset + 24..offset + 40].copy_from_slice(&(3u128 << 31).to_le_bytes());
+
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &raydium_cpmm::ID,
+            false,
+            0,
+        );
+
+        let observation = RaydiumObservation::latest(&account).unwrap();
+        assert_eq!(
+            observation,
+            RaydiumObservation {
+                block_timestamp: 1_700_000_000,
+                cumulative_token_0_price_x32: 5 << 32,
+                cumulative_token_1_price_x32: 3 << 31,
+            }
+        );
+        assert_eq!(observation.cumulative_token_price_x32(false), 3 << 31);
+    }
+
+    #[test]
+    fn rejects_accounts_not_owned_by_raydium() {
+        let mut data = vec![0; POOL_OBSERVATION_KEY_OFFSET + 32];
+        data[..8].copy_from_slice(&POOL_STATE_DISCRIMINATOR);
+
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        assert!(RaydiumPool::try_from_account(&account).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+use super::*;
+
+/// A stream pays `total_amount` of a token from a DAO's treasury to a
+/// recipient, vesting linearly between `start_timestamp` and `end_timestamp`.
+///
+/// Streams are created by a proposal (the treasury must sign), so that a DAO
+/// can pay a contributor over time without needing a lump-sum transfer or a
+/// new proposal for every payment. A later proposal can cancel the stream,
+/// which sends the unvested tokens back to the treasury. Tokens that had
+/// already vested at cancellation can still be withdrawn by the recipient.
+#[account]
+pub struct Stream {
+    pub dao: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub total_amount: u64,
+    pub withdrawn_amount: u64,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    /// Set when a proposal cancels the stream. Nothing vests after this time.
+    pub cancelled_timestamp: Option<i64>,
+    pub nonce: u64,
+    pub pda_bump: u8,
+    pub seq_num: u64,
+}
+
+impl Stream {
+    /// The total amount that has vested as of `unix_timestamp`, including
+    /// anything that has already been withdrawn.
+    pub fn vested_amount(&self, unix_timestamp: i64) -> u64 {
+        let vesting_timestamp = match self.cancelled_timestamp {
+            Some(cancelled_timestamp) => std::cmp::min(unix_timestamp, cancelled_timestamp),
+            None => unix_timestamp,
+        };
+
+        if vesting_timestamp <= self.start_timestamp {
+            return 0;
+        }
+
+        if vesting_timestamp >= self.end_timestamp {
+            return self.total_amount;
+        }
+
+        // in i128 because the difference of two i64s can overflow an i64
+        let elapsed = (vesting_timestamp as i128 - self.start_timestamp as i128) as u128;
+        let duration = (self.end_timestamp as i128 - self.start_timestamp as i128) as u128;
+
+        // can't overflow a u64 because elapsed < duration
+        ((self.total_amount as u128 * elapsed) / duration) as u64
+    }
+
+    /// The amount the recipient can withdraw right now.
+    pub fn withdrawable_amount(&self, unix_timestamp: i64) -> u64 {
+        self.vested_amount(unix_timestamp)
+            .saturating_sub(self.withdrawn_amount)
+    }
+}
+
+#[macro_export]
+macro_rules! generate_stream_seeds {
+    ($stream:expr, $nonce_bytes:expr) => {{
+        &[
+            b"stream".as_ref(),
+            $stream.dao.as_ref(),
+            $nonce_bytes.as_ref(),
+            &[$stream.pda_bump],
+        ]
+    }};
+}
+
+#[cfg(test)]
+mod stream_tests {
+    use super::*;
+
+    fn stream(total_amount: u64) -> Stream {
+        Stream {
+            dao: Pubkey::default(),
+            recipient: Pubkey::default(),
+            mint: Pubkey::default(),
+            total_amount,
+            withdrawn_amount: 0,
+            start_timestamp: 1_000,
+            end_timestamp: 2_000,
+            cancelled_timestamp: None,
+            nonce: 0,
+            pda_bump: 255,
+            seq_num: 0,
+        }
+    }
+
+    #[test]
+    fn vests_linearly() {
+        let stream = stream(6_000);
+
+        assert_eq!(stream.vested_amount(0), 0);
+        assert_eq!(stream.vested_amount(1_000), 0);
+        assert_eq!(stream.vested_amount(1_250), 1_500);
+        assert_eq!(stream.vested_amount(1_500), 3_000);
+        assert_eq!(stream.vested_amount(2_000), 6_000);
+        assert_eq!(stream.vested_amount(i64::MAX), 6_000);
+    }
+
+    #[test]
+    fn stops_vesting_when_cancelled() {
+        let mut stream = stream(6_000);
+        stream.cancelled_timestamp = Some(1_500);
+        stream.withdrawn_amount = 1_000;
+
+        assert_eq!(stream.vested_amount(1_250), 1_500);
+        assert_eq!(stream.vested_amount(1_900), 3_000);
+        assert_eq!(stream.withdrawable_amount(1_900), 2_000);
+    }
+
+    #[test]
+    fn doesnt_overflow_for_large_amounts() {
+        let mut stream = stream(u64::MAX);
+        stream.start_timestamp = 0;
+        stream.end_timestamp = i64::MAX;
+
+        assert_eq!(stream.vested_amount(i64::MAX / 2), u64::MAX / 2 - 1);
+    }
+
+    #[test]
+    fn doesnt_overflow_for_distant_timestamps() {
+        let mut stream = stream(1_000);
+        stream.start_timestamp = i64::MIN;
+        stream.end_timestamp = i64::MAX;
+
+        assert_eq!(stream.vested_amount(0), 500);
+        assert_eq!(stream.vested_amount(i64::MAX), 1_000);
+    }
+}
@@ -0,0 +1,171 @@
+use super::*;
+
+use amm::state::{SwapType, PRICE_SCALE};
+
+/// A treasury order buys or sells a DAO's token against an AMM in slices
+/// between `start_slot` and `end_slot`, so that a DAO can do a buyback or a
+/// token sale without executing one large market order.
+///
+/// Orders are created by a proposal, which moves the input tokens from the
+/// treasury into the order. From then on, anyone can crank the order. Each
+/// crank swaps whatever input has been released by the schedule, up to
+/// `max_input_per_slice`, and sends the output to the treasury. A later
+/// proposal can cancel the order to get back the input that hasn't been
+/// swapped.
+#[account]
+pub struct TreasuryOrder {
+    pub dao: Pubkey,
+    pub amm: Pubkey,
+    /// `Buy` spends quote tokens to buy back the DAO's token, `Sell` sells the
+    /// DAO's token for quote tokens.
+    pub swap_type: SwapType,
+    pub total_input_amount: u64,
+    pub input_amount_spent: u64,
+    pub output_amount_received: u64,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub max_input_per_slice: u64,
+    /// The worst price the order will trade at, in the same units as AMM
+    /// prices. For buys this is a maximum and for sells it's a minimum.
+    pub limit_price: Option<u128>,
+    pub last_slice_slot: u64,
+    pub cancelled: bool,
+    pub nonce: u64,
+    pub pda_bump: u8,
+    pub seq_num: u64,
+}
+
+impl TreasuryOrder {
+    /// The total input the schedule has released as of `slot`, including
+    /// anything that has already been swapped.
+    pub fn released_input_amount(&self, slot: u64) -> u64 {
+        if slot <= self.start_slot {
+            return 0;
+        }
+
+        if slot >= self.end_slot {
+            return self.total_input_amount;
+        }
+
+        let elapsed = (slot - self.start_slot) as u128;
+        let duration = (self.end_slot - self.start_slot) as u128;
+
+        // can't overflow a u64 because elapsed < duration
+        ((self.total_input_amount as u128 * elapsed) / duration) as u64
+    }
+
+    /// The amount of input the next crank will swap.
+    pub fn next_slice_amount(&self, slot: u64) -> u64 {
+        let unspent_released_amount = self
+            .released_input_amount(slot)
+            .saturating_sub(self.input_amount_spent);
+
+        std::cmp::min(unspent_released_amount, self.max_input_per_slice)
+    }
+
+    /// The least output a slice of `input_amount` can receive without trading
+    /// past `limit_price`.
+    pub fn min_output_amount(&self, input_amount: u64) -> Result<u64> {
+        let limit_price = match self.limit_price {
+            Some(limit_price) => limit_price,
+            None => return Ok(0),
+        };
+
+        let min_output_amount = match self.swap_type {
+            SwapType::Buy => (input_amount as u128 * PRICE_SCALE)
+                .checked_div(limit_price)
+                .ok_or(error!(AutocratError::InvalidLimitPrice))?,
+            SwapType::Sell => {
+                (input_amount as u128)
+                    .checked_mul(limit_price)
+                    .ok_or(error!(AutocratError::InvalidLimitPrice))?
+                    / PRICE_SCALE
+            }
+        };
+
+        min_output_amount
+            .try_into()
+            .map_err(|_| error!(AutocratError::InvalidLimitPrice))
+    }
+}
+
+#[macro_export]
+macro_rules! generate_treasury_order_seeds {
+    ($order:expr, $nonce_bytes:expr) => {{
+        &[
+            b"treasury_order".as_ref(),
+            $order.dao.as_ref(),
+            $nonce_bytes.as_ref(),
+            &[$order.pda_bump],
+        ]
+    }};
+}
+
+#[cfg(test)]
+mod treasury_order_tests {
+    use super::*;
+
+    fn order(swap_type: SwapType) -> TreasuryOrder {
+        TreasuryOrder {
+            dao: Pubkey::default(),
+            amm: Pubkey::default(),
+            swap_type,
+            total_input_amount: 10_000,
+            input_amount_spent: 0,
+            output_amount_received: 0,
+            start_slot: 100,
+            end_slot: 200,
+            max_input_per_slice: 3_000,
+            limit_price: None,
+            last_slice_slot: 0,
+            cancelled: false,
+            nonce: 0,
+            pda_bump: 255,
+            seq_num: 0,
+        }
+    }
+
+    #[test]
+    fn releases_input_linearly() {
+        let order = order(SwapType::Buy);
+
+        assert_eq!(order.released_input_amount(50), 0);
+        assert_eq!(order.released_input_amount(100), 0);
+        assert_eq!(order.released_input_amount(125), 2_500);
+        assert_eq!(order.released_input_amount(200), 10_000);
+        assert_eq!(order.released_input_amount(u64::MAX), 10_000);
+    }
+
+    #[test]
+    fn caps_slices() {
+        let mut order = order(SwapType::Buy);
+
+        assert_eq!(order.next_slice_amount(125), 2_500);
+        assert_eq!(order.next_slice_amount(150), 3_000);
+
+        order.input_amount_spent = 2_500;
+        assert_eq!(order.next_slice_amount(150), 2_500);
+        assert_eq!(order.next_slice_amount(125), 0);
+
+        order.input_amount_spent = 9_000;
+        assert_eq!(order.next_slice_amount(300), 1_000);
+    }
+
+    #[test]
+    fn min_output_respects_limit_price() {
+        let mut buy = order(SwapType::Buy);
+        assert_eq!(buy.min_output_amount(1_000).unwrap(), 0);
+
+        // pay at most 2 quote units per base unit
+        buy.limit_price = Some(2 * PRICE_SCALE);
+        assert_eq!(buy.min_output_amount(1_000).unwrap(), 500);
+
+        // receive at least 2 quote units per base unit
+        let mut sell = order(SwapType::Sell);
+        sell.limit_price = Some(2 * PRICE_SCALE);
+        assert_eq!(sell.min_output_amount(1_000).unwrap(), 2_000);
+
+        buy.limit_price = Some(0);
+        assert!(buy.min_output_amount(1_000).is_err());
+    }
+}
@@ -34,4 +34,10 @@ pub enum VaultError {
     QuestionAlreadyResolved,
     #[msg("Conditional token metadata already set")]
     ConditionalTokenMetadataAlreadySet,
+    #[msg("Question needs to be resolved before it or its vaults can be closed")]
+    QuestionNotResolved,
+    #[msg("A vault can't be closed while any of its conditional tokens are outstanding")]
+    ConditionalTokensOutstanding,
+    #[msg("A question can't be closed while any of its vaults are open")]
+    QuestionHasOpenVaults,
 }
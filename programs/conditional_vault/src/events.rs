@@ -93,3 +93,20 @@ pub struct SplitTokensEvent {
     pub post_conditional_token_supplies: Vec<u64>,
     pub seq_num: u64,
 }
+
+#[event]
+pub struct CloseConditionalVaultEvent {
+    pub common: CommonFields,
+    pub vault: Pubkey,
+    pub question: Pubkey,
+    pub payer: Pubkey,
+    pub leftover_underlying_amount: u64,
+    pub seq_num: u64,
+}
+
+#[event]
+pub struct CloseQuestionEvent {
+    pub common: CommonFields,
+    pub question: Pubkey,
+    pub payer: Pubkey,
+}
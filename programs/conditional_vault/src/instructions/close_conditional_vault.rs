@@ -0,0 +1,138 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseConditionalVault<'info> {
+    pub question: Account<'info, Question>,
+    /// CHECK: questions created before closing existed don't have a record,
+    /// and their vaults weren't counted in one
+    #[account(
+        mut,
+        seeds = [b"question_record", question.key().as_ref()],
+        bump
+    )]
+    pub question_record: UncheckedAccount<'info>,
+    #[account(mut, has_one = question, close = payer)]
+    pub vault: Account<'info, ConditionalVault>,
+    #[account(
+        mut,
+        has_one = payer,
+        close = payer,
+        seeds = [b"vault_record", vault.key().as_ref()],
+        bump = vault_record.pda_bump
+    )]
+    pub vault_record: Account<'info, VaultRecord>,
+    #[account(
+        mut,
+        constraint = vault_underlying_token_account.key() == vault.underlying_token_account @ VaultError::InvalidVaultUnderlyingTokenAccount
+    )]
+    pub vault_underlying_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Receives any underlying tokens left over from rounding in redemptions
+    #[account(
+        mut,
+        token::authority = payer,
+        token::mint = vault.underlying_token_mint
+    )]
+    pub payer_underlying_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info, 'c: 'info> CloseConditionalVault<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.question.is_resolved(), VaultError::QuestionNotResolved);
+
+        Ok(())
+    }
+
+    /// Closes a vault and its underlying token account once every conditional
+    /// token has been redeemed or merged. The conditional token mints stay
+    /// open, because the token program doesn't allow closing mints.
+    pub fn handle(ctx: Context<'_, '_, 'c, 'info, Self>) -> Result<()> {
+        let Self {
+            question,
+            question_record,
+            vault,
+            vault_record: _,
+            vault_underlying_token_account,
+            payer,
+            payer_underlying_token_account,
+            token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let remaining_accs = &mut ctx.remaining_accounts.iter();
+
+        require_eq!(
+            remaining_accs.len(),
+            vault.conditional_token_mints.len(),
+            VaultError::InvalidConditionals
+        );
+
+        for conditional_token_mint_address in vault.conditional_token_mints.iter() {
+            let conditional_token_mint = next_account_info(remaining_accs)?;
+            require_eq!(
+                *conditional_token_mint_address,
+                conditional_token_mint.key(),
+                VaultError::ConditionalMintMismatch
+            );
+
+            let conditional_token_mint = Account::<Mint>::try_from(conditional_token_mint)
+                .or(Err(VaultError::BadConditionalMint))?;
+
+            require_eq!(
+                conditional_token_mint.supply,
+                0,
+                VaultError::ConditionalTokensOutstanding
+            );
+        }
+
+        let seeds = generate_vault_seeds!(vault);
+        let signer = &[&seeds[..]];
+
+        let leftover_underlying_amount = vault_underlying_token_account.amount;
+
+        if leftover_underlying_amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: vault_underlying_token_account.to_account_info(),
+                        to: payer_underlying_token_account.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    signer,
+                ),
+                leftover_underlying_amount,
+            )?;
+        }
+
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: vault_underlying_token_account.to_account_info(),
+                destination: payer.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            signer,
+        ))?;
+
+        QuestionRecord::update_if_exists(question_record, |question_record| {
+            question_record.open_vault_count = question_record.open_vault_count.saturating_sub(1);
+        })?;
+
+        let clock = Clock::get()?;
+        emit_cpi!(CloseConditionalVaultEvent {
+            common: CommonFields::new(&clock),
+            vault: vault.key(),
+            question: question.key(),
+            payer: payer.key(),
+            leftover_underlying_amount,
+            seq_num: vault.seq_num,
+        });
+
+        Ok(())
+    }
+}
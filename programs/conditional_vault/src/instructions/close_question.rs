@@ -0,0 +1,51 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseQuestion<'info> {
+    #[account(mut, close = payer)]
+    pub question: Account<'info, Question>,
+    #[account(
+        mut,
+        has_one = payer,
+        close = payer,
+        seeds = [b"question_record", question.key().as_ref()],
+        bump = question_record.pda_bump
+    )]
+    pub question_record: Account<'info, QuestionRecord>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+impl CloseQuestion<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.question.is_resolved(), VaultError::QuestionNotResolved);
+
+        require_eq!(
+            self.question_record.open_vault_count,
+            0,
+            VaultError::QuestionHasOpenVaults
+        );
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let Self {
+            question,
+            question_record: _,
+            payer,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+        emit_cpi!(CloseQuestionEvent {
+            common: CommonFields::new(&clock),
+            question: question.key(),
+            payer: payer.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -19,6 +19,22 @@ pub struct InitializeConditionalVault<'info> {
     )]
     pub vault: Box<Account<'info, ConditionalVault>>,
     pub question: Account<'info, Question>,
+    /// CHECK: questions created before closing existed don't have a record,
+    /// so we only count this vault if it does
+    #[account(
+        mut,
+        seeds = [b"question_record", question.key().as_ref()],
+        bump
+    )]
+    pub question_record: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<VaultRecord>(),
+        seeds = [b"vault_record", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_record: Box<Account<'info, VaultRecord>>,
     pub underlying_token_mint: Account<'info, Mint>,
     #[account(
         associated_token::authority = vault,
@@ -105,6 +121,15 @@ impl<'info, 'c: 'info> InitializeConditionalVault<'info> {
             seq_num: 0,
         });
 
+        ctx.accounts.vault_record.set_inner(VaultRecord {
+            payer: ctx.accounts.payer.key(),
+            pda_bump: ctx.bumps.vault_record,
+        });
+
+        QuestionRecord::update_if_exists(&ctx.accounts.question_record, |question_record| {
+            question_record.open_vault_count += 1;
+        })?;
+
         let clock = Clock::get()?;
         emit_cpi!(InitializeConditionalVaultEvent {
             common: CommonFields {
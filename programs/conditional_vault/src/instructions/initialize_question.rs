@@ -24,6 +24,14 @@ pub struct InitializeQuestion<'info> {
         bump
     )]
     pub question: Box<Account<'info, Question>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<QuestionRecord>(),
+        seeds = [b"question_record", question.key().as_ref()],
+        bump
+    )]
+    pub question_record: Box<Account<'info, QuestionRecord>>,
     #[account(mut)]
     pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -48,6 +56,12 @@ impl InitializeQuestion<'_> {
             payout_denominator: 0,
         });
 
+        ctx.accounts.question_record.set_inner(QuestionRecord {
+            payer: ctx.accounts.payer.key(),
+            open_vault_count: 0,
+            pda_bump: ctx.bumps.question_record,
+        });
+
         let clock = Clock::get()?;
         emit_cpi!(InitializeQuestionEvent {
             common: CommonFields {
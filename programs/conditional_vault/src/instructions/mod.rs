@@ -1,6 +1,8 @@
 use super::*;
 
 pub mod add_metadata_to_conditional_tokens;
+pub mod close_conditional_vault;
+pub mod close_question;
 pub mod common;
 pub mod initialize_conditional_vault;
 pub mod initialize_question;
@@ -10,6 +12,8 @@ pub mod resolve_question;
 pub mod split_tokens;
 
 pub use add_metadata_to_conditional_tokens::*;
+pub use close_conditional_vault::*;
+pub use close_question::*;
 pub use common::*;
 pub use initialize_conditional_vault::*;
 pub use initialize_question::*;
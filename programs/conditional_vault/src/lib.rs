@@ -5,7 +5,7 @@ use anchor_spl::metadata::{
 };
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer},
+    token::{self, Burn, CloseAccount, Mint, MintTo, Token, TokenAccount, Transfer},
 };
 
 pub mod error;
@@ -87,4 +87,16 @@ pub mod conditional_vault {
     ) -> Result<()> {
         AddMetadataToConditionalTokens::handle(ctx, args)
     }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_conditional_vault<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CloseConditionalVault<'info>>,
+    ) -> Result<()> {
+        CloseConditionalVault::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_question(ctx: Context<CloseQuestion>) -> Result<()> {
+        CloseQuestion::handle(ctx)
+    }
 }
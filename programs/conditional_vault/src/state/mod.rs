@@ -2,6 +2,8 @@ use super::*;
 
 pub mod conditional_vault;
 pub mod question;
+pub mod rent_record;
 
 pub use conditional_vault::*;
 pub use question::*;
+pub use rent_record::*;
@@ -0,0 +1,38 @@
+use super::*;
+
+/// Lets a question be closed once it's resolved and all of its vaults have
+/// been closed. Kept separate from `Question` so that questions created
+/// before closing existed still deserialize. Those questions don't have a
+/// record, so they can't be closed.
+#[account]
+pub struct QuestionRecord {
+    /// Receives the question's and this record's rent when they're closed.
+    pub payer: Pubkey,
+    pub open_vault_count: u32,
+    pub pda_bump: u8,
+}
+
+/// Lets a vault be closed once all of its conditional tokens are gone. Like
+/// `QuestionRecord`, vaults created before closing existed don't have one.
+#[account]
+pub struct VaultRecord {
+    /// Receives the vault's and this record's rent when they're closed.
+    pub payer: Pubkey,
+    pub pda_bump: u8,
+}
+
+impl QuestionRecord {
+    /// Updates the question record at `info`, if the question has one.
+    pub fn update_if_exists(info: &AccountInfo, update: impl FnOnce(&mut Self)) -> Result<()> {
+        if info.owner != &crate::ID {
+            return Ok(());
+        }
+
+        let mut data = info.try_borrow_mut_data()?;
+        let mut question_record = Self::try_deserialize(&mut &data[..])?;
+        update(&mut question_record);
+        question_record.try_serialize(&mut &mut data[..])?;
+
+        Ok(())
+    }
+}
@@ -22,8 +22,7 @@ use raydium_cpmm_cpi::{
 
 use autocrat::program::Autocrat;
 use autocrat::InitializeDaoParams;
-
-pub const PRICE_SCALE: u128 = 1_000_000_000_000;
+use price_math::Price;
 
 /// Completes a launch, which if the minimum raise is met:
 /// - Creates a DAO
@@ -258,8 +257,9 @@ impl CompleteLaunch<'_> {
         // per update (300% per hour), and for proposers to need to lock up 1%
         // of the supply and an equivalent value of USDC.
 
-        let price_1e12 =
-            ((total_committed_amount as u128) * PRICE_SCALE) / (AVAILABLE_TOKENS as u128);
+        let price_1e12 = Price::from_amounts(total_committed_amount, AVAILABLE_TOKENS)
+            .ok_or(error!(LaunchpadError::InvalidAmount))?
+            .to_scaled();
 
         let launch_key = launch.key();
 
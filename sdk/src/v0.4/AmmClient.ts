@@ -4,14 +4,31 @@ import {
   AddressLookupTableAccount,
   Keypair,
   PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
 
 import { Amm as AmmIDLType, IDL as AmmIDL } from "./types/amm.js";
 
 import BN from "bn.js";
 import { AMM_PROGRAM_ID } from "./constants.js";
-import { Amm, LowercaseKeys } from "./types/index.js";
-import { getAmmLpMintAddr, getAmmAddr } from "./utils/pda.js";
+import {
+  Amm,
+  Batch,
+  LowercaseKeys,
+  OrderBook,
+  Position,
+} from "./types/index.js";
+import {
+  getAmmLpMintAddr,
+  getAmmAddr,
+  getGlobalConfigAddr,
+  getTraderRecordAddr,
+  getOrderBookAddr,
+  getBatchAddr,
+  getBatchOrderAddr,
+  getPositionAddr,
+} from "./utils/pda.js";
 // import { MethodsBuilder } from "@coral-xyz/anchor/dist/cjs/program/namespace/methods";
 import {
   MintLayout,
@@ -113,10 +130,15 @@ export class AmmClient {
     baseMint: PublicKey,
     quoteMint: PublicKey,
     twapInitialObservation: BN,
-    twapMaxObservationChangePerUpdate: BN
+    twapMaxObservationChangePerUpdate: BN,
+    {
+      admin = null,
+      batchAuctionSlots = null,
+    }: { admin?: PublicKey | null; batchAuctionSlots?: BN | null } = {}
   ) {
     let [amm] = getAmmAddr(this.getProgramId(), baseMint, quoteMint);
     let [lpMint] = getAmmLpMintAddr(this.getProgramId(), amm);
+    let [globalConfig] = getGlobalConfigAddr(this.getProgramId());
 
     let vaultAtaBase = getAssociatedTokenAddressSync(baseMint, amm, true);
     let vaultAtaQuote = getAssociatedTokenAddressSync(quoteMint, amm, true);
@@ -125,10 +147,14 @@ export class AmmClient {
       .createAmm({
         twapInitialObservation,
         twapMaxObservationChangePerUpdate,
+        admin,
+        maxPriceImpactBps: null,
+        batchAuctionSlots,
       })
       .accounts({
         user: this.provider.publicKey,
         amm,
+        globalConfig,
         lpMint,
         baseMint,
         quoteMint,
@@ -221,7 +247,8 @@ export class AmmClient {
     quoteAmount: BN,
     maxBaseAmount: BN,
     minLpTokens: BN,
-    user: PublicKey = this.provider.publicKey
+    user: PublicKey = this.provider.publicKey,
+    position: PublicKey | null = null
   ) {
     const [lpMint] = getAmmLpMintAddr(this.program.programId, amm);
 
@@ -238,6 +265,10 @@ export class AmmClient {
         amm,
         lpMint,
         userLpAccount,
+        position,
+        positionLpAccount: position
+          ? getAssociatedTokenAddressSync(lpMint, position, true)
+          : null,
         userBaseAccount: getAssociatedTokenAddressSync(baseMint, user),
         userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user),
         vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
@@ -290,6 +321,149 @@ export class AmmClient {
       });
   }
 
+  zapInIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    swapType: SwapType,
+    inputAmount: BN,
+    minLpTokens: BN,
+    user: PublicKey = this.provider.publicKey,
+    position: PublicKey | null = null
+  ) {
+    const [lpMint] = getAmmLpMintAddr(this.program.programId, amm);
+
+    const userLpAccount = getAssociatedTokenAddressSync(lpMint, user);
+
+    return this.program.methods
+      .zapIn({
+        swapType,
+        inputAmount,
+        minLpTokens,
+      })
+      .accounts({
+        user,
+        amm,
+        traderRecord: getTraderRecordAddr(this.getProgramId(), amm, user)[0],
+        lpMint,
+        userLpAccount,
+        position,
+        positionLpAccount: position
+          ? getAssociatedTokenAddressSync(lpMint, position, true)
+          : null,
+        userBaseAccount: getAssociatedTokenAddressSync(baseMint, user),
+        userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user),
+        vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+        vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          userLpAccount,
+          user,
+          lpMint
+        ),
+      ]);
+  }
+
+  zapOutIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    swapType: SwapType,
+    lpTokensToBurn: BN,
+    minOutputAmount: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [lpMint] = getAmmLpMintAddr(this.program.programId, amm);
+
+    return this.program.methods
+      .zapOut({
+        swapType,
+        lpTokensToBurn,
+        minOutputAmount,
+      })
+      .accounts({
+        user,
+        amm,
+        traderRecord: getTraderRecordAddr(this.getProgramId(), amm, user)[0],
+        lpMint,
+        userLpAccount: getAssociatedTokenAddressSync(lpMint, user),
+        userBaseAccount: getAssociatedTokenAddressSync(baseMint, user),
+        userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user),
+        vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+        vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+        systemProgram: SystemProgram.programId,
+      });
+  }
+
+  async getPosition(position: PublicKey): Promise<Position> {
+    return await this.program.account.position.fetch(position);
+  }
+
+  // positions start out empty, so this is usually followed by an
+  // `addLiquidityIx` or `zapInIx` with the position in the same transaction
+  createPositionIx(
+    amm: PublicKey,
+    nonce: BN,
+    owner: PublicKey = this.provider.publicKey
+  ) {
+    const [lpMint] = getAmmLpMintAddr(this.program.programId, amm);
+    const [position] = getPositionAddr(
+      this.program.programId,
+      amm,
+      owner,
+      nonce
+    );
+
+    return this.program.methods.createPosition({ nonce }).accounts({
+      owner,
+      amm,
+      position,
+      lpMint,
+      positionLpAccount: getAssociatedTokenAddressSync(lpMint, position, true),
+    });
+  }
+
+  lockPositionIx(
+    amm: PublicKey,
+    position: PublicKey,
+    locker: PublicKey,
+    owner: PublicKey = this.provider.publicKey
+  ) {
+    return this.program.methods.lockPosition().accounts({
+      owner,
+      locker,
+      amm,
+      position,
+    });
+  }
+
+  unlockPositionIx(amm: PublicKey, position: PublicKey, locker: PublicKey) {
+    return this.program.methods.unlockPosition().accounts({
+      locker,
+      amm,
+      position,
+    });
+  }
+
+  closePositionIx(
+    amm: PublicKey,
+    position: PublicKey,
+    owner: PublicKey = this.provider.publicKey
+  ) {
+    const [lpMint] = getAmmLpMintAddr(this.program.programId, amm);
+
+    return this.program.methods.closePosition().accounts({
+      owner,
+      amm,
+      position,
+      ownerLpAccount: getAssociatedTokenAddressSync(lpMint, owner),
+      positionLpAccount: getAssociatedTokenAddressSync(lpMint, position, true),
+    });
+  }
+
   async swap(
     amm: PublicKey,
     swapType: SwapType,
@@ -340,7 +514,9 @@ export class AmmClient {
       })
       .accounts({
         user,
+        payer: this.provider.publicKey,
         amm,
+        traderRecord: getTraderRecordAddr(this.getProgramId(), amm, user)[0],
         userBaseAccount: getAssociatedTokenAddressSync(baseMint, user, true),
         userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user, true),
         vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
@@ -357,6 +533,64 @@ export class AmmClient {
       ]);
   }
 
+  // the loan has to be repaid later in the same transaction, so this is
+  // meant to be used with `repayFlashLoanIx` as a post-instruction
+  flashLoanIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    baseAmount: BN,
+    quoteAmount: BN,
+    borrower: PublicKey = this.provider.publicKey
+  ) {
+    return this.program.methods
+      .flashLoan({
+        baseAmount,
+        quoteAmount,
+      })
+      .accounts({
+        borrower,
+        amm,
+        borrowerBaseAccount: getAssociatedTokenAddressSync(
+          baseMint,
+          borrower,
+          true
+        ),
+        borrowerQuoteAccount: getAssociatedTokenAddressSync(
+          quoteMint,
+          borrower,
+          true
+        ),
+        vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+        vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      });
+  }
+
+  repayFlashLoanIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    repayer: PublicKey = this.provider.publicKey
+  ) {
+    return this.program.methods.repayFlashLoan().accounts({
+      repayer,
+      amm,
+      repayerBaseAccount: getAssociatedTokenAddressSync(
+        baseMint,
+        repayer,
+        true
+      ),
+      repayerQuoteAccount: getAssociatedTokenAddressSync(
+        quoteMint,
+        repayer,
+        true
+      ),
+      vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+      vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+    });
+  }
+
   async crankThatTwap(amm: PublicKey) {
     return this.crankThatTwapIx(amm).rpc();
   }
@@ -367,6 +601,323 @@ export class AmmClient {
     });
   }
 
+  // `orderOwners` are the owners of any orders left in the AMM's order book,
+  // who get those orders refunded to their associated token accounts
+  closeAmmIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    orderOwners: PublicKey[] = []
+  ) {
+    const [orderBook] = getOrderBookAddr(this.program.programId, amm);
+    const [lpMint] = getAmmLpMintAddr(this.program.programId, amm);
+
+    const hasOrders = orderOwners.length > 0;
+
+    return this.program.methods
+      .closeAmm()
+      .accounts({
+        payer: this.provider.publicKey,
+        amm,
+        orderBook,
+        orderBookBaseAccount: hasOrders
+          ? getAssociatedTokenAddressSync(baseMint, orderBook, true)
+          : null,
+        orderBookQuoteAccount: hasOrders
+          ? getAssociatedTokenAddressSync(quoteMint, orderBook, true)
+          : null,
+        lpMint,
+        payerBaseAccount: getAssociatedTokenAddressSync(
+          baseMint,
+          this.provider.publicKey
+        ),
+        payerQuoteAccount: getAssociatedTokenAddressSync(
+          quoteMint,
+          this.provider.publicKey
+        ),
+        vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+        vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+      })
+      .remainingAccounts(
+        orderOwners.flatMap((owner) =>
+          [baseMint, quoteMint].map((mint) => ({
+            pubkey: getAssociatedTokenAddressSync(mint, owner, true),
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+      );
+  }
+
+  pauseAmmIx(amm: PublicKey, admin: PublicKey = this.provider.publicKey) {
+    return this.program.methods.pauseAmm().accounts({
+      admin,
+      amm,
+    });
+  }
+
+  unpauseAmmIx(amm: PublicKey, admin: PublicKey = this.provider.publicKey) {
+    return this.program.methods.unpauseAmm().accounts({
+      admin,
+      amm,
+    });
+  }
+
+  migrateAmmIx(amm: PublicKey) {
+    return this.program.methods.migrateAmm().accounts({
+      amm,
+      payer: this.provider.publicKey,
+    });
+  }
+
+  async getBatch(batch: PublicKey): Promise<Batch> {
+    return await this.program.account.batch.fetch(batch);
+  }
+
+  submitBatchOrderIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    batchId: BN,
+    swapType: SwapType,
+    inputAmount: BN,
+    minOutputAmount: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [batch] = getBatchAddr(this.program.programId, amm, batchId);
+
+    return this.program.methods
+      .submitBatchOrder({
+        batchId,
+        swapType,
+        inputAmount,
+        minOutputAmount,
+      })
+      .accounts({
+        user,
+        amm,
+        batch,
+        batchOrder: getBatchOrderAddr(this.program.programId, batch, user)[0],
+        traderRecord: getTraderRecordAddr(this.getProgramId(), amm, user)[0],
+        userBaseAccount: getAssociatedTokenAddressSync(baseMint, user, true),
+        userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user, true),
+        vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+        vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+      });
+  }
+
+  // every unclaimed order in the batch has to be passed in
+  clearBatchIx(amm: PublicKey, batchId: BN, batchOrders: PublicKey[]) {
+    return this.program.methods
+      .clearBatch()
+      .accounts({
+        amm,
+        batch: getBatchAddr(this.program.programId, amm, batchId)[0],
+      })
+      .remainingAccounts(
+        batchOrders.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        }))
+      );
+  }
+
+  // `batchPayer` is whoever submitted the batch's first order, who gets the
+  // batch's rent back once every order has claimed
+  claimBatchOrderIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    batchId: BN,
+    batchPayer: PublicKey,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [batch] = getBatchAddr(this.program.programId, amm, batchId);
+
+    return this.program.methods.claimBatchOrder().accounts({
+      user,
+      amm,
+      batch,
+      batchOrder: getBatchOrderAddr(this.program.programId, batch, user)[0],
+      batchPayer,
+      userBaseAccount: getAssociatedTokenAddressSync(baseMint, user, true),
+      userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user, true),
+      vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+      vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+    });
+  }
+
+  async getOrderBook(orderBook: PublicKey): Promise<OrderBook> {
+    return await this.program.account.orderBook.fetch(orderBook);
+  }
+
+  initializeOrderBookIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey
+  ) {
+    const [orderBook] = getOrderBookAddr(this.program.programId, amm);
+
+    return this.program.methods.initializeOrderBook().accounts({
+      payer: this.provider.publicKey,
+      amm,
+      orderBook,
+      baseMint,
+      quoteMint,
+      orderBookBaseAccount: getAssociatedTokenAddressSync(
+        baseMint,
+        orderBook,
+        true
+      ),
+      orderBookQuoteAccount: getAssociatedTokenAddressSync(
+        quoteMint,
+        orderBook,
+        true
+      ),
+    });
+  }
+
+  // `limitPrice` needs to be scaled beforehand, like AMM prices
+  placeLimitOrderIx(
+    amm: PublicKey,
+    inputMint: PublicKey,
+    swapType: SwapType,
+    inputAmount: BN,
+    limitPrice: BN,
+    expiresInSlots: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [orderBook] = getOrderBookAddr(this.program.programId, amm);
+
+    return this.program.methods
+      .placeLimitOrder({
+        swapType,
+        inputAmount,
+        limitPrice,
+        expiresInSlots,
+      })
+      .accounts({
+        user,
+        amm,
+        traderRecord: getTraderRecordAddr(this.getProgramId(), amm, user)[0],
+        orderBook,
+        inputMint,
+        userInputAccount: getAssociatedTokenAddressSync(inputMint, user, true),
+        orderBookInputAccount: getAssociatedTokenAddressSync(
+          inputMint,
+          orderBook,
+          true
+        ),
+      });
+  }
+
+  removeLimitOrderIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    orderId: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [orderBook] = getOrderBookAddr(this.program.programId, amm);
+
+    return this.program.methods.removeLimitOrder({ orderId }).accounts({
+      user,
+      amm,
+      orderBook,
+      userBaseAccount: getAssociatedTokenAddressSync(baseMint, user, true),
+      userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user, true),
+      orderBookBaseAccount: getAssociatedTokenAddressSync(
+        baseMint,
+        orderBook,
+        true
+      ),
+      orderBookQuoteAccount: getAssociatedTokenAddressSync(
+        quoteMint,
+        orderBook,
+        true
+      ),
+    });
+  }
+
+  cancelExpiredLimitOrderIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    orderId: BN,
+    owner: PublicKey
+  ) {
+    const [orderBook] = getOrderBookAddr(this.program.programId, amm);
+
+    return this.program.methods.cancelExpiredLimitOrder({ orderId }).accounts({
+      amm,
+      orderBook,
+      owner,
+      ownerBaseAccount: getAssociatedTokenAddressSync(baseMint, owner, true),
+      ownerQuoteAccount: getAssociatedTokenAddressSync(quoteMint, owner, true),
+      orderBookBaseAccount: getAssociatedTokenAddressSync(
+        baseMint,
+        orderBook,
+        true
+      ),
+      orderBookQuoteAccount: getAssociatedTokenAddressSync(
+        quoteMint,
+        orderBook,
+        true
+      ),
+    });
+  }
+
+  // `ownerOutputAccounts` are the token accounts that filled orders get paid
+  // out to. Orders whose owner's account isn't passed stay in the book until
+  // their owner removes them.
+  fillOrdersIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    ownerOutputAccounts: PublicKey[] = [],
+    filler: PublicKey = this.provider.publicKey
+  ) {
+    const [orderBook] = getOrderBookAddr(this.program.programId, amm);
+
+    return this.program.methods
+      .fillOrders()
+      .accounts({
+        filler,
+        amm,
+        orderBook,
+        orderBookBaseAccount: getAssociatedTokenAddressSync(
+          baseMint,
+          orderBook,
+          true
+        ),
+        orderBookQuoteAccount: getAssociatedTokenAddressSync(
+          quoteMint,
+          orderBook,
+          true
+        ),
+        vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+        vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+        fillerBaseAccount: getAssociatedTokenAddressSync(
+          baseMint,
+          filler,
+          true
+        ),
+        fillerQuoteAccount: getAssociatedTokenAddressSync(
+          quoteMint,
+          filler,
+          true
+        ),
+      })
+      .remainingAccounts(
+        ownerOutputAccounts.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        }))
+      );
+  }
+
   async getDecimals(mint: PublicKey): Promise<number> {
     return unpackMint(mint, await this.provider.connection.getAccountInfo(mint))
       .decimals;
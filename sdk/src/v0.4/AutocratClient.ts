@@ -11,7 +11,12 @@ import {
   TransactionInstruction,
 } from "@solana/web3.js";
 import { PriceMath } from "./utils/priceMath.js";
-import { ProposalInstruction, InitializeDaoParams } from "./types/index.js";
+import {
+  ProposalInstruction,
+  InitializeDaoParams,
+  ProposalCategory,
+  SpotMarket,
+} from "./types/index.js";
 
 import { Autocrat, IDL as AutocratIDL } from "./types/autocrat.js";
 import {
@@ -36,7 +41,9 @@ import {
   getConditionalTokenMintAddr,
   getDaoTreasuryAddr,
   getEventAuthorityAddr,
+  getKeeperPoolAddr,
   getProposalAddr,
+  getProposerRecordAddr,
   getQuestionAddr,
   getVaultAddr,
 } from "./utils/index.js";
@@ -304,12 +311,23 @@ export class AutocratClient {
       .signers([daoKeypair]);
   }
 
+  // makes room for the fields added to `Dao` after it was created
+  migrateDaoIx(dao: PublicKey) {
+    return this.autocrat.methods.migrateDao().accounts({
+      dao,
+      payer: this.provider.publicKey,
+    });
+  }
+
   async initializeProposal(
     dao: PublicKey,
     descriptionUrl: string,
     instruction: ProposalInstruction,
     baseTokensToLP: BN,
-    quoteTokensToLP: BN
+    quoteTokensToLP: BN,
+    title: string = "",
+    category: ProposalCategory = { general: {} },
+    contentHash: number[] = Array(32).fill(0)
   ): Promise<PublicKey> {
     const storedDao = await this.getDao(dao);
 
@@ -422,7 +440,14 @@ export class AutocratClient {
       lpTokens,
       lpTokens,
       nonce,
-      question
+      question,
+      title,
+      category,
+      contentHash,
+      storedDao.decisionRule.passOverFailAndSpot?.spotMarket ?? null,
+      null,
+      null,
+      storedDao.maxConcurrentProposalsPerProposer != 0
     ).rpc();
 
     return proposal;
@@ -566,7 +591,15 @@ export class AutocratClient {
     passLpTokensToLock: BN,
     failLpTokensToLock: BN,
     nonce: BN,
-    question: PublicKey
+    question: PublicKey,
+    title: string = "",
+    category: ProposalCategory = { general: {} },
+    contentHash: number[] = Array(32).fill(0),
+    spotMarket: SpotMarket | null = null,
+    passPosition: PublicKey | null = null,
+    failPosition: PublicKey | null = null,
+    // only needed if the DAO limits each proposer's trading proposals
+    useProposerRecord: boolean = false
   ) {
     let [proposal] = getProposalAddr(
       this.autocrat.programId,
@@ -601,9 +634,23 @@ export class AutocratClient {
       true
     );
 
+    const proposerRecord = useProposerRecord
+      ? getProposerRecordAddr(
+          this.autocrat.programId,
+          dao,
+          this.provider.publicKey
+        )[0]
+      : null;
+    const [ammEventAuthority] = getEventAuthorityAddr(
+      this.ammClient.program.programId
+    );
+
     return this.autocrat.methods
       .initializeProposal({
         descriptionUrl,
+        title,
+        contentHash,
+        category,
         instruction,
         passLpTokensToLock,
         failLpTokensToLock,
@@ -613,10 +660,13 @@ export class AutocratClient {
         question,
         proposal,
         dao,
+        proposerRecord,
         baseVault,
         quoteVault,
         passAmm,
         failAmm,
+        spotAmm: spotMarket?.amm?.amm ?? null,
+        spotRaydiumObservation: spotMarket?.raydium?.observationState ?? null,
         passLpMint: passLp,
         failLpMint: failLp,
         passLpUserAccount: getAssociatedTokenAddressSync(
@@ -629,7 +679,11 @@ export class AutocratClient {
         ),
         passLpVaultAccount,
         failLpVaultAccount,
+        passPosition,
+        failPosition,
         proposer: this.provider.publicKey,
+        ammProgram: this.ammClient.program.programId,
+        ammEventAuthority,
       })
       .preInstructions([
         createAssociatedTokenAccountIdempotentInstruction(
@@ -657,7 +711,11 @@ export class AutocratClient {
       storedProposal.dao,
       storedDao.tokenMint,
       storedDao.usdcMint,
-      storedProposal.proposer
+      storedProposal.proposer,
+      storedProposal.spotTwapSnapshot?.spotMarket ?? null,
+      storedProposal.passPosition,
+      storedProposal.failPosition,
+      storedProposal.countedInProposerRecord
     ).rpc();
   }
 
@@ -667,7 +725,12 @@ export class AutocratClient {
     dao: PublicKey,
     daoToken: PublicKey,
     usdc: PublicKey,
-    proposer: PublicKey
+    proposer: PublicKey,
+    spotMarket: SpotMarket | null = null,
+    passPosition: PublicKey | null = null,
+    failPosition: PublicKey | null = null,
+    useProposerRecord: boolean = false,
+    keeper: PublicKey = this.provider.publicKey
   ) {
     let vaultProgramId = this.vaultClient.vaultProgram.programId;
 
@@ -689,12 +752,22 @@ export class AutocratClient {
     );
 
     const [vaultEventAuthority] = getEventAuthorityAddr(vaultProgramId);
+    const [ammEventAuthority] = getEventAuthorityAddr(
+      this.ammClient.program.programId
+    );
+    const proposerRecord = useProposerRecord
+      ? getProposerRecordAddr(this.autocrat.programId, dao, proposer)[0]
+      : null;
+    const [keeperPool] = getKeeperPoolAddr(this.autocrat.programId, dao);
 
     return this.autocrat.methods.finalizeProposal().accounts({
       proposal,
       passAmm,
       failAmm,
+      spotAmm: spotMarket?.amm?.amm ?? null,
+      spotRaydiumObservation: spotMarket?.raydium?.observationState ?? null,
       dao,
+      proposerRecord,
       question,
       // baseVault,
       // quoteVault,
@@ -710,9 +783,15 @@ export class AutocratClient {
         daoTreasury,
         true
       ),
+      passPosition,
+      failPosition,
       vaultProgram: this.vaultClient.vaultProgram.programId,
       treasury: daoTreasury,
       vaultEventAuthority,
+      ammProgram: this.ammClient.program.programId,
+      ammEventAuthority,
+      keeperPool,
+      keeper,
     });
   }
 
@@ -726,13 +805,21 @@ export class AutocratClient {
     ).rpc();
   }
 
-  executeProposalIx(proposal: PublicKey, dao: PublicKey, instruction: any) {
+  executeProposalIx(
+    proposal: PublicKey,
+    dao: PublicKey,
+    instruction: any,
+    keeper: PublicKey = this.provider.publicKey
+  ) {
     const [daoTreasury] = getDaoTreasuryAddr(this.autocrat.programId, dao);
+    const [keeperPool] = getKeeperPoolAddr(this.autocrat.programId, dao);
     return this.autocrat.methods
       .executeProposal()
       .accounts({
         proposal,
         dao,
+        keeperPool,
+        keeper,
         // daoTreasury,
       })
       .remainingAccounts(
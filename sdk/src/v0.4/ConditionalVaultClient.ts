@@ -19,6 +19,8 @@ import {
   getMetadataAddr,
   getVaultAddr,
   getConditionalTokenMintAddr,
+  getQuestionRecordAddr,
+  getVaultRecordAddr,
 } from "./utils/index.js";
 import {
   createAssociatedTokenAccountIdempotentInstruction,
@@ -94,6 +96,10 @@ export class ConditionalVaultClient {
       oracle,
       numOutcomes
     );
+    const [questionRecord] = getQuestionRecordAddr(
+      this.vaultProgram.programId,
+      question
+    );
 
     return this.vaultProgram.methods
       .initializeQuestion({
@@ -103,6 +109,7 @@ export class ConditionalVaultClient {
       })
       .accounts({
         question,
+        questionRecord,
       });
   }
 
@@ -134,6 +141,14 @@ export class ConditionalVaultClient {
       question,
       underlyingTokenMint
     );
+    const [questionRecord] = getQuestionRecordAddr(
+      this.vaultProgram.programId,
+      question
+    );
+    const [vaultRecord] = getVaultRecordAddr(
+      this.vaultProgram.programId,
+      vault
+    );
 
     let conditionalTokenMintAddrs = [];
     for (let i = 0; i < numOutcomes; i++) {
@@ -156,6 +171,8 @@ export class ConditionalVaultClient {
       .accounts({
         vault,
         question,
+        questionRecord,
+        vaultRecord,
         underlyingTokenMint,
         vaultUnderlyingTokenAccount,
       })
@@ -15,6 +15,12 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["charge a protocol fee"];
+        },
         {
           name: "lpMint";
           isMut: true;
@@ -88,6 +94,15 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "traderRecord";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only used by the zaps, which swap and so count `user` as a trader."
+          ];
+        },
         {
           name: "lpMint";
           isMut: true;
@@ -98,6 +113,22 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only used by `add_liquidity` and `zap_in`, which mint the LP tokens to",
+            "this position instead of `user_lp_account` when it's passed in"
+          ];
+        },
+        {
+          name: "positionLpAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
         {
           name: "userBaseAccount";
           isMut: true;
@@ -123,6 +154,12 @@ export type Amm = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
         {
           name: "eventAuthority";
           isMut: false;
@@ -156,6 +193,15 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "traderRecord";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only used by the zaps, which swap and so count `user` as a trader."
+          ];
+        },
         {
           name: "lpMint";
           isMut: true;
@@ -166,6 +212,22 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only used by `add_liquidity` and `zap_in`, which mint the LP tokens to",
+            "this position instead of `user_lp_account` when it's passed in"
+          ];
+        },
+        {
+          name: "positionLpAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
         {
           name: "userBaseAccount";
           isMut: true;
@@ -191,6 +253,12 @@ export type Amm = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
         {
           name: "eventAuthority";
           isMut: false;
@@ -211,6 +279,204 @@ export type Amm = {
         }
       ];
     },
+    {
+      name: "zapIn";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "traderRecord";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only used by the zaps, which swap and so count `user` as a trader."
+          ];
+        },
+        {
+          name: "lpMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userLpAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only used by `add_liquidity` and `zap_in`, which mint the LP tokens to",
+            "this position instead of `user_lp_account` when it's passed in"
+          ];
+        },
+        {
+          name: "positionLpAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "ZapInArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "zapOut";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "traderRecord";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only used by the zaps, which swap and so count `user` as a trader."
+          ];
+        },
+        {
+          name: "lpMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userLpAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only used by `add_liquidity` and `zap_in`, which mint the LP tokens to",
+            "this position instead of `user_lp_account` when it's passed in"
+          ];
+        },
+        {
+          name: "positionLpAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "ZapOutArgs";
+          };
+        }
+      ];
+    },
     {
       name: "swap";
       accounts: [
@@ -219,11 +485,25 @@ export type Amm = {
           isMut: true;
           isSigner: true;
         },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+          docs: [
+            "Pays for `trader_record` the first time `user` trades. This is",
+            "separate from `user` so that program-owned accounts can swap."
+          ];
+        },
         {
           name: "amm";
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "traderRecord";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "userBaseAccount";
           isMut: true;
@@ -249,6 +529,11 @@ export type Amm = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "eventAuthority";
           isMut: false;
@@ -289,1086 +574,6820 @@ export type Amm = {
         }
       ];
       args: [];
-    }
-  ];
-  accounts: [
+    },
     {
-      name: "amm";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "createdAtSlot";
-            type: "u64";
-          },
-          {
-            name: "lpMint";
-            type: "publicKey";
-          },
-          {
-            name: "baseMint";
-            type: "publicKey";
-          },
-          {
-            name: "quoteMint";
-            type: "publicKey";
-          },
-          {
-            name: "baseMintDecimals";
-            type: "u8";
-          },
-          {
-            name: "quoteMintDecimals";
-            type: "u8";
-          },
-          {
-            name: "baseAmount";
-            type: "u64";
+      name: "crankMany";
+      accounts: [
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateAmm";
+      accounts: [
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "deserialize as an `Amm`, so we check it by hand in `validate`"
+          ];
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeAmm";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+          docs: ["exists"];
+        },
+        {
+          name: "orderBookBaseAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Only needed if the order book has orders left to refund"];
+        },
+        {
+          name: "orderBookQuoteAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "lpMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payerBaseAccount";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Receives the locked minimum liquidity and any base tokens left over",
+            "from rounding"
+          ];
+        },
+        {
+          name: "payerQuoteAccount";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Receives the locked minimum liquidity and any quote tokens left over",
+            "from rounding"
+          ];
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initializeGlobalConfig";
+      accounts: [
+        {
+          name: "upgradeAuthority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "ammProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "programData";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "InitializeGlobalConfigArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "pauseAmm";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "unpauseAmm";
+      accounts: [
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "submitBatchOrder";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "batch";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "batchOrder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "traderRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "SubmitBatchOrderArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "clearBatch";
+      accounts: [
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "batch";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "claimBatchOrder";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "batch";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "batchOrder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "batchPayer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initializeOrderBook";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "orderBookBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBookQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "placeLimitOrder";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "traderRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "inputMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The quote mint for buys and the base mint for sells"];
+        },
+        {
+          name: "userInputAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBookInputAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "PlaceLimitOrderArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "removeLimitOrder";
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBookBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBookQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "RemoveLimitOrderArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "cancelExpiredLimitOrder";
+      accounts: [
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "owner";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ownerBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "ownerQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBookBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBookQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "CancelExpiredLimitOrderArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "fillOrders";
+      accounts: [
+        {
+          name: "filler";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBookBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBookQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "fillerBaseAccount";
+          isMut: true;
+          isSigner: false;
+          docs: ["Receives the bounty for filled buys"];
+        },
+        {
+          name: "fillerQuoteAccount";
+          isMut: true;
+          isSigner: false;
+          docs: ["Receives the bounty for filled sells"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "flashLoan";
+      accounts: [
+        {
+          name: "borrower";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "borrowerBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "borrowerQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "FlashLoanArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "repayFlashLoan";
+      accounts: [
+        {
+          name: "repayer";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+          docs: ["`flash_loan` expects this to be the second account"];
+        },
+        {
+          name: "repayerBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "repayerQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createPosition";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lpMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "positionLpAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "CreatePositionArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "closePosition";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "ownerLpAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "positionLpAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "lockPosition";
+      accounts: [
+        {
+          name: "owner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "locker";
+          isMut: false;
+          isSigner: true;
+          docs: [
+            "Usually a PDA of the program that's locking the position, such as a",
+            "proposal"
+          ];
+        },
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "unlockPosition";
+      accounts: [
+        {
+          name: "locker";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "collectProtocolFees";
+      accounts: [
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updateProtocolFee";
+      accounts: [
+        {
+          name: "feeAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "globalConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "UpdateProtocolFeeArgs";
+          };
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "amm";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "createdAtSlot";
+            type: "u64";
+          },
+          {
+            name: "lpMint";
+            type: "publicKey";
+          },
+          {
+            name: "baseMint";
+            type: "publicKey";
+          },
+          {
+            name: "quoteMint";
+            type: "publicKey";
+          },
+          {
+            name: "baseMintDecimals";
+            type: "u8";
+          },
+          {
+            name: "quoteMintDecimals";
+            type: "u8";
+          },
+          {
+            name: "baseAmount";
+            type: "u64";
+          },
+          {
+            name: "quoteAmount";
+            type: "u64";
+          },
+          {
+            name: "oracle";
+            type: {
+              defined: "TwapOracle";
+            };
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          },
+          {
+            name: "payer";
+            docs: ["Receives the AMM's rent when it's closed."];
+            type: "publicKey";
+          },
+          {
+            name: "cumulativeQuoteVolume";
+            docs: [
+              "The total quote tokens traded through this AMM, counting both buys",
+              "and sells. Lets consumers like autocrat require real participation."
+            ];
+            type: "u128";
+          },
+          {
+            name: "swapCount";
+            docs: ["The number of swaps through this AMM."];
+            type: "u64";
+          },
+          {
+            name: "traderCount";
+            docs: [
+              "The number of distinct users who have traded on this AMM, counted",
+              "through their `TraderRecord`s. Unlike `swap_count`, one user can't",
+              "inflate this by splitting their trades."
+            ];
+            type: "u64";
+          },
+          {
+            name: "cumulativeBaseVolume";
+            docs: [
+              "The total base tokens traded through this AMM, counting both buys",
+              "and sells."
+            ];
+            type: "u128";
+          },
+          {
+            name: "cumulativeBaseFees";
+            docs: [
+              "The swap fees paid in base tokens, which are paid on sells. Includes",
+              "the protocol's share."
+            ];
+            type: "u128";
+          },
+          {
+            name: "cumulativeQuoteFees";
+            docs: [
+              "The swap fees paid in quote tokens, which are paid on buys. Includes",
+              "the protocol's share."
+            ];
+            type: "u128";
+          },
+          {
+            name: "protocolFeeBps";
+            docs: [
+              "The share of each swap fee that goes to the protocol instead of LPs,",
+              "in basis points of the fee. Taken from the `GlobalConfig` when the AMM",
+              "is created and fixed from then on, so it can't change while the AMM",
+              "backs a proposal."
+            ];
+            type: "u16";
+          },
+          {
+            name: "protocolBaseFeesOwed";
+            docs: [
+              "Protocol fees sit in the vaults until they're collected, but they",
+              "aren't part of `base_amount` or `quote_amount`, so LPs can't withdraw",
+              "them and swaps don't trade against them."
+            ];
+            type: "u64";
+          },
+          {
+            name: "protocolQuoteFeesOwed";
+            type: "u64";
+          },
+          {
+            name: "lockedLpTokens";
+            docs: [
+              "LP tokens that count towards the LP supply but were never minted, so",
+              "nobody can burn them. Set to `MINIMUM_LIQUIDITY` on the first deposit."
+            ];
+            type: "u64";
+          },
+          {
+            name: "admin";
+            docs: [
+              "Can pause and unpause the AMM, e.g. the DAO treasury if a proposal's",
+              "conditional tokens turn out to be misconfigured. `None` means the AMM",
+              "can never be paused."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "pausedAtSlot";
+            docs: [
+              "The slot the AMM was paused at, if it's paused. While an AMM is paused,",
+              "swaps and deposits are disabled but withdrawals aren't, and its TWAP",
+              "oracle is frozen."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "totalPausedSlots";
+            docs: [
+              "The total slots the AMM has spent paused, not counting a pause that's",
+              "still ongoing. These slots don't count towards the TWAP."
+            ];
+            type: "u64";
+          },
+          {
+            name: "maxPriceImpactBps";
+            docs: [
+              "The furthest a swap can move the price away from the oracle's last",
+              "observation, in basis points. Swaps that move the price back towards",
+              "the observation are always allowed. `None` means no limit."
+            ];
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "batchAuctionSlots";
+            docs: [
+              "If set, swaps aren't executed immediately. Instead, orders submitted in",
+              "each window of this many slots are batched and cleared together at a",
+              "single price, so there's nothing to sandwich."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "unclaimedBatchOrders";
+            docs: ["Batch orders whose tokens are still in the vaults."];
+            type: "u64";
+          },
+          {
+            name: "flashLoan";
+            docs: [
+              "A flash loan that's been lent out and must be repaid later in the",
+              "same transaction."
+            ];
+            type: {
+              option: {
+                defined: "FlashLoan";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "batch";
+      docs: [
+        "The orders submitted to a batch-auction AMM during one window. Their",
+        "input sits in the AMM's vaults, outside of its reserves, until the batch",
+        "is cleared and each order claims its output."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "endSlot";
+            docs: ["The first slot the batch can be cleared at"];
+            type: "u64";
+          },
+          {
+            name: "totalQuoteIn";
+            type: "u64";
+          },
+          {
+            name: "totalBaseIn";
+            type: "u64";
+          },
+          {
+            name: "unclaimedOrderCount";
+            type: "u32";
+          },
+          {
+            name: "clearingPrice";
+            docs: [
+              "The price every order in the batch traded at, once it's cleared"
+            ];
+            type: {
+              option: "u128";
+            };
+          },
+          {
+            name: "baseOwed";
+            docs: [
+              "What's left to pay out to the batch's orders. Payouts round down, so",
+              "some dust can be left over after every order has claimed."
+            ];
+            type: "u64";
+          },
+          {
+            name: "quoteOwed";
+            type: "u64";
+          },
+          {
+            name: "payer";
+            docs: [
+              "Paid for the batch account and gets its rent back once every order",
+              "has claimed"
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "batchOrder";
+      docs: [
+        "A user's orders in a batch. Buys and sells from the same user are tracked",
+        "separately, since both clear at the same price."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "batch";
+            type: "publicKey";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "quoteIn";
+            type: "u64";
+          },
+          {
+            name: "baseIn";
+            type: "u64";
+          },
+          {
+            name: "minBaseOut";
+            docs: [
+              "The least the order will accept for its buys and its sells. If the",
+              "batch would clear at a price that pays less than either, the order is",
+              "left out of the batch and gets its input back instead."
+            ];
+            type: "u64";
+          },
+          {
+            name: "minQuoteOut";
+            type: "u64";
+          },
+          {
+            name: "refunded";
+            docs: ["Set when the batch is cleared without this order"];
+            type: "bool";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "globalConfig";
+      docs: [
+        "The protocol fee configuration that every AMM is created with. There's",
+        "one per program, so the protocol rather than each AMM's creator decides",
+        "who collects protocol fees."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "feeAuthority";
+            docs: ["Can collect protocol fees and change this configuration."];
+            type: "publicKey";
+          },
+          {
+            name: "protocolFeeRecipient";
+            docs: [
+              "The owner of the token accounts that protocol fees are collected to."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "protocolFeeBps";
+            docs: [
+              "The protocol fee that new AMMs are created with, in basis points of",
+              "each swap fee. An AMM's fee can't change after it's created, so",
+              "changing this doesn't affect the markets of proposals that are",
+              "already trading."
+            ];
+            type: "u16";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "orderBook";
+      docs: [
+        "Limit orders that rest against an AMM. Each order's input is escrowed in",
+        "the order book's token accounts until it's filled or removed, and anyone",
+        "can fill orders whose limit price the pool has crossed in exchange for a",
+        "bounty."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "nextOrderId";
+            type: "u64";
+          },
+          {
+            name: "orders";
+            docs: [
+              "Filled orders are paid out and removed when whoever fills them passes",
+              "in their owners' token accounts. Otherwise they stay here until their",
+              "owners remove them or they expire and someone cancels them."
+            ];
+            type: {
+              vec: {
+                defined: "LimitOrder";
+              };
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "position";
+      docs: [
+        "An LP position that records where its liquidity came from. The position",
+        "holds its LP tokens in an account it owns, so unlike fungible LP tokens it",
+        "can be locked as a whole, e.g. by a proposal."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "owner";
+            type: "publicKey";
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          },
+          {
+            name: "lpTokens";
+            docs: [
+              "The LP tokens minted to the position by `add_liquidity` and `zap_in`"
+            ];
+            type: "u64";
+          },
+          {
+            name: "baseDeposited";
+            docs: ["What was actually deposited for those LP tokens"];
+            type: "u64";
+          },
+          {
+            name: "quoteDeposited";
+            type: "u64";
+          },
+          {
+            name: "depositSlot";
+            docs: ["The slot of the latest deposit"];
+            type: "u64";
+          },
+          {
+            name: "locker";
+            docs: [
+              "Whoever has locked the position. Only they can unlock it, and the",
+              "owner can't close it while it's locked."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "traderRecord";
+      docs: [
+        "Marks that a user has traded on an AMM, so that the AMM counts each trader",
+        "once in `trader_count` no matter how many trades they split their volume",
+        "into. Trading instructions create it the first time a user trades."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "trader";
+            type: "publicKey";
+          },
+          {
+            name: "firstTradeSlot";
+            type: "u64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "CommonFields";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "slot";
+            type: "u64";
+          },
+          {
+            name: "unixTimestamp";
+            type: "i64";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "postBaseReserves";
+            type: "u64";
+          },
+          {
+            name: "postQuoteReserves";
+            type: "u64";
+          },
+          {
+            name: "oracleLastPrice";
+            type: "u128";
+          },
+          {
+            name: "oracleLastObservation";
+            type: "u128";
+          },
+          {
+            name: "oracleAggregator";
+            type: "u128";
+          },
+          {
+            name: "cumulativeBaseVolume";
+            type: "u128";
+          },
+          {
+            name: "cumulativeQuoteVolume";
+            type: "u128";
+          },
+          {
+            name: "cumulativeBaseFees";
+            type: "u128";
+          },
+          {
+            name: "cumulativeQuoteFees";
+            type: "u128";
+          },
+          {
+            name: "swapCount";
+            type: "u64";
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "AddLiquidityArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "quoteAmount";
+            docs: ["How much quote token you will deposit to the pool"];
+            type: "u64";
+          },
+          {
+            name: "maxBaseAmount";
+            docs: ["The maximum base token you will deposit to the pool"];
+            type: "u64";
+          },
+          {
+            name: "minLpTokens";
+            docs: ["The minimum LP token you will get back"];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "CancelExpiredLimitOrderArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "orderId";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "CreateAmmArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "twapInitialObservation";
+            type: "u128";
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate";
+            type: "u128";
+          },
+          {
+            name: "admin";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "maxPriceImpactBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "batchAuctionSlots";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "CreatePositionArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "nonce";
+            docs: ["Lets an owner hold more than one position in an AMM"];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "FlashLoanArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "baseAmount";
+            type: "u64";
+          },
+          {
+            name: "quoteAmount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "InitializeGlobalConfigArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "feeAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "protocolFeeRecipient";
+            type: "publicKey";
+          },
+          {
+            name: "protocolFeeBps";
+            type: "u16";
+          }
+        ];
+      };
+    },
+    {
+      name: "PlaceLimitOrderArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "inputAmount";
+            type: "u64";
+          },
+          {
+            name: "limitPrice";
+            type: "u128";
+          },
+          {
+            name: "expiresInSlots";
+            docs: [
+              "How many slots the order rests in the book before it expires, at most",
+              "`MAX_LIMIT_ORDER_LIFETIME_SLOTS`"
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "RemoveLimitOrderArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "orderId";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "RemoveLiquidityArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "lpTokensToBurn";
+            type: "u64";
+          },
+          {
+            name: "minQuoteAmount";
+            type: "u64";
+          },
+          {
+            name: "minBaseAmount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "SubmitBatchOrderArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "batchId";
+            docs: ["Must be the batch for the current slot"];
+            type: "u64";
+          },
+          {
+            name: "swapType";
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "inputAmount";
+            type: "u64";
+          },
+          {
+            name: "minOutputAmount";
+            docs: [
+              "The least output to accept for `input_amount`. It's added to any",
+              "minimum from the user's earlier orders on the same side of the batch."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "SwapArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "inputAmount";
+            type: "u64";
+          },
+          {
+            name: "outputAmountMin";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "UpdateProtocolFeeArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "protocolFeeBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "protocolFeeRecipient";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "feeAuthority";
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "ZapInArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            docs: [
+              "`Buy` deposits quote tokens and swaps some of them into base tokens,",
+              "`Sell` deposits base tokens and swaps some of them into quote tokens"
+            ];
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "inputAmount";
+            docs: ["How much of the input token you will deposit to the pool"];
+            type: "u64";
+          },
+          {
+            name: "minLpTokens";
+            docs: ["The minimum LP token you will get back"];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "ZapOutArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            docs: [
+              "`Sell` swaps the withdrawn base tokens so that you receive only quote",
+              "tokens, `Buy` swaps the withdrawn quote tokens so that you receive",
+              "only base tokens"
+            ];
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "lpTokensToBurn";
+            type: "u64";
+          },
+          {
+            name: "minOutputAmount";
+            docs: ["The minimum you will get back of the token you receive"];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "TwapOracle";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "lastUpdatedSlot";
+            type: "u64";
+          },
+          {
+            name: "lastPrice";
+            docs: [
+              "A price is the number of quote units per base unit multiplied by 1e12.",
+              "You cannot simply divide by 1e12 to get a price you can display in the UI",
+              "because the base and quote decimals may be different. Instead, use",
+              "`price_math::Price::to_ui`."
+            ];
+            type: "u128";
+          },
+          {
+            name: "lastObservation";
+            docs: [
+              "If we did a raw TWAP over prices, someone could push the TWAP heavily with",
+              "a few extremely large outliers. So we use observations, which can only move",
+              "by `max_observation_change_per_update` per update."
+            ];
+            type: "u128";
+          },
+          {
+            name: "aggregator";
+            docs: [
+              "Running sum of slots_per_last_update * last_observation.",
+              "",
+              "Assuming latest observations are as big as possible (u64::MAX * 1e12),",
+              "we can store 18 million slots worth of observations, which turns out to",
+              "be ~85 days worth of slots.",
+              "",
+              "Assuming that latest observations are 100x smaller than they could theoretically",
+              "be, we can store 8500 days (23 years) worth of them. Even this is a very",
+              "very conservative assumption - META/USDC prices should be between 1e9 and",
+              "1e15, which would overflow after 1e15 years worth of slots.",
+              "",
+              "So in the case of an overflow, the aggregator rolls back to 0. It's the",
+              "client's responsibility to sanity check the assets or to handle an",
+              "aggregator at T2 being smaller than an aggregator at T1."
+            ];
+            type: "u128";
+          },
+          {
+            name: "maxObservationChangePerUpdate";
+            docs: ["The most that an observation can change per update."];
+            type: "u128";
+          },
+          {
+            name: "initialObservation";
+            docs: ["What the initial `latest_observation` is set to."];
+            type: "u128";
+          }
+        ];
+      };
+    },
+    {
+      name: "FlashLoan";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "baseAmount";
+            type: "u64";
+          },
+          {
+            name: "quoteAmount";
+            type: "u64";
+          },
+          {
+            name: "baseFee";
+            type: "u64";
+          },
+          {
+            name: "quoteFee";
+            type: "u64";
+          },
+          {
+            name: "vaultBaseBefore";
+            docs: [
+              "What the vaults held before the loan, so that repaying it can check",
+              "that they end up with at least as much"
+            ];
+            type: "u64";
+          },
+          {
+            name: "vaultQuoteBefore";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "LimitOrder";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "owner";
+            type: "publicKey";
+          },
+          {
+            name: "swapType";
+            docs: [
+              "`Buy` spends quote tokens on base tokens, `Sell` spends base tokens on",
+              "quote tokens"
+            ];
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "inputAmount";
+            type: "u64";
+          },
+          {
+            name: "limitPrice";
+            docs: [
+              "The worst average price the order will fill at, in the same units as",
+              "AMM prices. For buys this is a maximum and for sells it's a minimum."
+            ];
+            type: "u128";
+          },
+          {
+            name: "outputAmount";
+            docs: [
+              "What the owner receives, net of the filler's bounty. `None` until the",
+              "order is filled."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "expiresAtSlot";
+            docs: [
+              "Once this slot has passed, the order can't be filled and anyone can",
+              "cancel it."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "SwapType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Buy";
+          },
+          {
+            name: "Sell";
+          }
+        ];
+      };
+    }
+  ];
+  events: [
+    {
+      name: "SwapEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "inputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "outputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AddLiquidityEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "maxBaseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minLpTokens";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "lpTokensMinted";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RemoveLiquidityEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "lpTokensBurned";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minQuoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minBaseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ZapInEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        },
+        {
+          name: "inputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minLpTokens";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapInputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapOutputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "inputDeposited";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "outputDeposited";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "outputRefunded";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "lpTokensMinted";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ZapOutEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        },
+        {
+          name: "lpTokensBurned";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minOutputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseWithdrawn";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteWithdrawn";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapInputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapOutputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "outputAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CreateAmmEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "twapInitialObservation";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "twapMaxObservationChangePerUpdate";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "lpMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "baseMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "quoteMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vaultAtaBase";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "protocolFeeBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "admin";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "maxPriceImpactBps";
+          type: {
+            option: "u16";
+          };
+          index: false;
+        },
+        {
+          name: "batchAuctionSlots";
+          type: {
+            option: "u64";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CrankThatTwapEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CloseAmmEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "leftoverBaseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "leftoverQuoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "refundedOrderIds";
+          type: {
+            vec: "u64";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MigrateAmmEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CollectProtocolFeesEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "protocolFeeRecipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "InitializeGlobalConfigEvent";
+      fields: [
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "unixTimestamp";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "upgradeAuthority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "globalConfig";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "feeAuthority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "protocolFeeRecipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "protocolFeeBps";
+          type: "u16";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "UpdateProtocolFeeEvent";
+      fields: [
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "unixTimestamp";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "user";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "globalConfig";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "protocolFeeBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "protocolFeeRecipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "feeAuthority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "seqNum";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PauseAmmEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "UnpauseAmmEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "pausedSlots";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "InitializeOrderBookEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "orderBook";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlaceLimitOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "orderBook";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "orderId";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        },
+        {
+          name: "inputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "limitPrice";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "expiresAtSlot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RemoveLimitOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "orderBook";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "orderId";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "filled";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CancelExpiredLimitOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "orderBook";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "orderId";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "owner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "filled";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FillOrdersEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "orderBook";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "filledOrderIds";
+          type: {
+            vec: "u64";
+          };
+          index: false;
+        },
+        {
+          name: "settledOrderIds";
+          type: {
+            vec: "u64";
+          };
+          index: false;
+        },
+        {
+          name: "baseBounty";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteBounty";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "SubmitBatchOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "batch";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "batchId";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        },
+        {
+          name: "inputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minOutputAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ClearBatchEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "batch";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "batchId";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "totalQuoteIn";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "totalBaseIn";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "clearingPrice";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "baseOwed";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteOwed";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "refundedOrders";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ClaimBatchOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "batch";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "clearingPrice";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "refunded";
+          type: "bool";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FlashLoanEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseFee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteFee";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RepayFlashLoanEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseFee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteFee";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CreatePositionEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "position";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "nonce";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ClosePositionEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "position";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "lpTokens";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LockPositionEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "position";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "locker";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "lpTokens";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "UnlockPositionEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "position";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "locker";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "AssertFailed";
+      msg: "An assertion failed";
+    },
+    {
+      code: 6001;
+      name: "NoSlotsPassed";
+      msg: "Can't get a TWAP before some observations have been stored";
+    },
+    {
+      code: 6002;
+      name: "NoReserves";
+      msg: "Can't swap through a pool without token reserves on either side";
+    },
+    {
+      code: 6003;
+      name: "InputAmountOverflow";
+      msg: "Input token amount is too large for a swap, causes overflow";
+    },
+    {
+      code: 6004;
+      name: "AddLiquidityCalculationError";
+      msg: "Add liquidity calculation error";
+    },
+    {
+      code: 6005;
+      name: "DecimalScaleError";
+      msg: "Error in decimal scale conversion";
+    },
+    {
+      code: 6006;
+      name: "SameTokenMints";
+      msg: "You can't create an AMM pool where the token mints are the same";
+    },
+    {
+      code: 6007;
+      name: "SwapSlippageExceeded";
+      msg: "A user wouldn't have gotten back their `output_amount_min`, reverting";
+    },
+    {
+      code: 6008;
+      name: "InsufficientBalance";
+      msg: "The user had insufficient balance to do this";
+    },
+    {
+      code: 6009;
+      name: "ZeroLiquidityRemove";
+      msg: "Must remove a non-zero amount of liquidity";
+    },
+    {
+      code: 6010;
+      name: "ZeroLiquidityToAdd";
+      msg: "Cannot add liquidity with 0 tokens on either side";
+    },
+    {
+      code: 6011;
+      name: "ZeroMinLpTokens";
+      msg: "Must specify a non-zero `min_lp_tokens` when adding to an existing pool";
+    },
+    {
+      code: 6012;
+      name: "AddLiquiditySlippageExceeded";
+      msg: "LP wouldn't have gotten back `lp_token_min`";
+    },
+    {
+      code: 6013;
+      name: "AddLiquidityMaxBaseExceeded";
+      msg: "LP would have spent more than `max_base_amount`";
+    },
+    {
+      code: 6014;
+      name: "InsufficientQuoteAmount";
+      msg: "`quote_amount` must be greater than 100000000 when initializing a pool";
+    },
+    {
+      code: 6015;
+      name: "ZeroSwapAmount";
+      msg: "Users must swap a non-zero amount";
+    },
+    {
+      code: 6016;
+      name: "ConstantProductInvariantFailed";
+      msg: "K should always be increasing";
+    },
+    {
+      code: 6017;
+      name: "CastingOverflow";
+      msg: "Casting has caused an overflow";
+    },
+    {
+      code: 6018;
+      name: "LpTokensOutstanding";
+      msg: "An AMM can't be closed while any of its LP tokens are outstanding";
+    },
+    {
+      code: 6019;
+      name: "InvalidAmm";
+      msg: "This account isn't an AMM";
+    },
+    {
+      code: 6020;
+      name: "AmmAlreadyMigrated";
+      msg: "This AMM has already been migrated";
+    },
+    {
+      code: 6021;
+      name: "InvalidProtocolFee";
+      msg: "The protocol fee can be at most `MAX_PROTOCOL_FEE_BPS`";
+    },
+    {
+      code: 6022;
+      name: "ProtocolFeesUncollected";
+      msg: "An AMM can't be closed until its protocol fees have been collected";
+    },
+    {
+      code: 6023;
+      name: "AmmPaused";
+      msg: "This AMM is paused";
+    },
+    {
+      code: 6024;
+      name: "AmmNotPaused";
+      msg: "This AMM isn't paused";
+    },
+    {
+      code: 6025;
+      name: "InvalidAdmin";
+      msg: "This AMM doesn't have an admin, or the signer isn't its admin";
+    },
+    {
+      code: 6026;
+      name: "PriceImpactTooHigh";
+      msg: "This swap would move the price too far from the oracle's last observation";
+    },
+    {
+      code: 6027;
+      name: "OrderBookFull";
+      msg: "This order book already has `MAX_LIMIT_ORDERS` orders";
+    },
+    {
+      code: 6028;
+      name: "LimitOrderNotFound";
+      msg: "No limit order with this id is in the order book";
+    },
+    {
+      code: 6029;
+      name: "InvalidLimitOrder";
+      msg: "Limit orders need a non-zero input amount and limit price";
+    },
+    {
+      code: 6030;
+      name: "NoOrdersFilled";
+      msg: "None of the order book's orders could be filled";
+    },
+    {
+      code: 6031;
+      name: "LimitOrderTooSmall";
+      msg: "Limit orders must be at least `MIN_LIMIT_ORDER_BPS` of the AMM's input reserve";
+    },
+    {
+      code: 6032;
+      name: "TooManyLimitOrders";
+      msg: "This user already has `MAX_LIMIT_ORDERS_PER_OWNER` orders in this order book";
+    },
+    {
+      code: 6033;
+      name: "InvalidLimitOrderExpiry";
+      msg: "Limit orders must expire within `MAX_LIMIT_ORDER_LIFETIME_SLOTS`";
+    },
+    {
+      code: 6034;
+      name: "LimitOrderNotExpired";
+      msg: "Only the owner can remove a limit order before it expires";
+    },
+    {
+      code: 6035;
+      name: "MissingOrderOwnerAccount";
+      msg: "Each order left in the order book needs its owner's associated token account passed in";
+    },
+    {
+      code: 6036;
+      name: "BatchAuctionMode";
+      msg: "This AMM is in batch auction mode, so orders have to go through batches";
+    },
+    {
+      code: 6037;
+      name: "NotInBatchAuctionMode";
+      msg: "This AMM isn't in batch auction mode";
+    },
+    {
+      code: 6038;
+      name: "InvalidBatchAuctionConfig";
+      msg: "Batched AMMs need a non-zero window and can't have a price impact limit";
+    },
+    {
+      code: 6039;
+      name: "BatchClosed";
+      msg: "Orders can only be submitted to the current batch";
+    },
+    {
+      code: 6040;
+      name: "BatchNotEnded";
+      msg: "This batch's window hasn't ended yet";
+    },
+    {
+      code: 6041;
+      name: "BatchAlreadyCleared";
+      msg: "This batch has already been cleared";
+    },
+    {
+      code: 6042;
+      name: "BatchNotCleared";
+      msg: "This batch hasn't been cleared yet";
+    },
+    {
+      code: 6043;
+      name: "BatchOrdersUnclaimed";
+      msg: "An AMM can't be closed while any of its batch orders are unclaimed";
+    },
+    {
+      code: 6044;
+      name: "BatchFull";
+      msg: "This batch already has `MAX_BATCH_ORDERS` orders";
+    },
+    {
+      code: 6045;
+      name: "InvalidBatchOrders";
+      msg: "Clearing a batch needs each of its orders passed in exactly once";
+    },
+    {
+      code: 6046;
+      name: "FlashLoanOutstanding";
+      msg: "This AMM already has a flash loan outstanding";
+    },
+    {
+      code: 6047;
+      name: "NoFlashLoan";
+      msg: "This AMM doesn't have a flash loan outstanding";
+    },
+    {
+      code: 6048;
+      name: "InvalidFlashLoanAmount";
+      msg: "Flash loans must borrow something and at most the AMM's reserves";
+    },
+    {
+      code: 6049;
+      name: "FlashLoanNotRepaid";
+      msg: "A flash loan must be followed by a `repay_flash_loan` for the same AMM";
+    },
+    {
+      code: 6050;
+      name: "VaultsUndercollateralized";
+      msg: "The AMM's vaults don't hold its reserves and protocol fees";
+    },
+    {
+      code: 6051;
+      name: "PositionLocked";
+      msg: "This position is locked";
+    },
+    {
+      code: 6052;
+      name: "PositionNotLocked";
+      msg: "This position isn't locked";
+    },
+    {
+      code: 6053;
+      name: "InvalidLocker";
+      msg: "Only the position's locker can unlock it";
+    }
+  ];
+};
+
+export const IDL: Amm = {
+  version: "0.4.0",
+  name: "amm",
+  instructions: [
+    {
+      name: "createAmm",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["charge a protocol fee"],
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "CreateAmmArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "addLiquidity",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "traderRecord",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only used by the zaps, which swap and so count `user` as a trader.",
+          ],
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only used by `add_liquidity` and `zap_in`, which mint the LP tokens to",
+            "this position instead of `user_lp_account` when it's passed in",
+          ],
+        },
+        {
+          name: "positionLpAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "AddLiquidityArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "removeLiquidity",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "traderRecord",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only used by the zaps, which swap and so count `user` as a trader.",
+          ],
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only used by `add_liquidity` and `zap_in`, which mint the LP tokens to",
+            "this position instead of `user_lp_account` when it's passed in",
+          ],
+        },
+        {
+          name: "positionLpAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "RemoveLiquidityArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "zapIn",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "traderRecord",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only used by the zaps, which swap and so count `user` as a trader.",
+          ],
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only used by `add_liquidity` and `zap_in`, which mint the LP tokens to",
+            "this position instead of `user_lp_account` when it's passed in",
+          ],
+        },
+        {
+          name: "positionLpAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "ZapInArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "zapOut",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "traderRecord",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only used by the zaps, which swap and so count `user` as a trader.",
+          ],
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only used by `add_liquidity` and `zap_in`, which mint the LP tokens to",
+            "this position instead of `user_lp_account` when it's passed in",
+          ],
+        },
+        {
+          name: "positionLpAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "ZapOutArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "swap",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+          docs: [
+            "Pays for `trader_record` the first time `user` trades. This is",
+            "separate from `user` so that program-owned accounts can swap.",
+          ],
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "traderRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "SwapArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "crankThatTwap",
+      accounts: [
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "crankMany",
+      accounts: [
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateAmm",
+      accounts: [
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "deserialize as an `Amm`, so we check it by hand in `validate`",
+          ],
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeAmm",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+          docs: ["exists"],
+        },
+        {
+          name: "orderBookBaseAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Only needed if the order book has orders left to refund"],
+        },
+        {
+          name: "orderBookQuoteAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "lpMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payerBaseAccount",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Receives the locked minimum liquidity and any base tokens left over",
+            "from rounding",
+          ],
+        },
+        {
+          name: "payerQuoteAccount",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Receives the locked minimum liquidity and any quote tokens left over",
+            "from rounding",
+          ],
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initializeGlobalConfig",
+      accounts: [
+        {
+          name: "upgradeAuthority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "ammProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "programData",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "InitializeGlobalConfigArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "pauseAmm",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "unpauseAmm",
+      accounts: [
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "submitBatchOrder",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "batch",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "batchOrder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "traderRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "SubmitBatchOrderArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "clearBatch",
+      accounts: [
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "batch",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimBatchOrder",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "batch",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "batchOrder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "batchPayer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initializeOrderBook",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "orderBookBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBookQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "placeLimitOrder",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "traderRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "inputMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The quote mint for buys and the base mint for sells"],
+        },
+        {
+          name: "userInputAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBookInputAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "PlaceLimitOrderArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "removeLimitOrder",
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBookBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBookQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "RemoveLimitOrderArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "cancelExpiredLimitOrder",
+      accounts: [
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ownerBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "ownerQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBookBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBookQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "CancelExpiredLimitOrderArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "fillOrders",
+      accounts: [
+        {
+          name: "filler",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBookBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBookQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "fillerBaseAccount",
+          isMut: true,
+          isSigner: false,
+          docs: ["Receives the bounty for filled buys"],
+        },
+        {
+          name: "fillerQuoteAccount",
+          isMut: true,
+          isSigner: false,
+          docs: ["Receives the bounty for filled sells"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "flashLoan",
+      accounts: [
+        {
+          name: "borrower",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "borrowerBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "borrowerQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "FlashLoanArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "repayFlashLoan",
+      accounts: [
+        {
+          name: "repayer",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+          docs: ["`flash_loan` expects this to be the second account"],
+        },
+        {
+          name: "repayerBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "repayerQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createPosition",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lpMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "positionLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "CreatePositionArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "closePosition",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "ownerLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "positionLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "lockPosition",
+      accounts: [
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "locker",
+          isMut: false,
+          isSigner: true,
+          docs: [
+            "Usually a PDA of the program that's locking the position, such as a",
+            "proposal",
+          ],
+        },
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "unlockPosition",
+      accounts: [
+        {
+          name: "locker",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "collectProtocolFees",
+      accounts: [
+        {
+          name: "feeAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "updateProtocolFee",
+      accounts: [
+        {
+          name: "feeAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "globalConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "UpdateProtocolFeeArgs",
+          },
+        },
+      ],
+    },
+  ],
+  accounts: [
+    {
+      name: "amm",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "createdAtSlot",
+            type: "u64",
+          },
+          {
+            name: "lpMint",
+            type: "publicKey",
+          },
+          {
+            name: "baseMint",
+            type: "publicKey",
+          },
+          {
+            name: "quoteMint",
+            type: "publicKey",
+          },
+          {
+            name: "baseMintDecimals",
+            type: "u8",
+          },
+          {
+            name: "quoteMintDecimals",
+            type: "u8",
+          },
+          {
+            name: "baseAmount",
+            type: "u64",
+          },
+          {
+            name: "quoteAmount",
+            type: "u64",
+          },
+          {
+            name: "oracle",
+            type: {
+              defined: "TwapOracle",
+            },
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+          {
+            name: "payer",
+            docs: ["Receives the AMM's rent when it's closed."],
+            type: "publicKey",
+          },
+          {
+            name: "cumulativeQuoteVolume",
+            docs: [
+              "The total quote tokens traded through this AMM, counting both buys",
+              "and sells. Lets consumers like autocrat require real participation.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "swapCount",
+            docs: ["The number of swaps through this AMM."],
+            type: "u64",
+          },
+          {
+            name: "traderCount",
+            docs: [
+              "The number of distinct users who have traded on this AMM, counted",
+              "through their `TraderRecord`s. Unlike `swap_count`, one user can't",
+              "inflate this by splitting their trades.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "cumulativeBaseVolume",
+            docs: [
+              "The total base tokens traded through this AMM, counting both buys",
+              "and sells.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "cumulativeBaseFees",
+            docs: [
+              "The swap fees paid in base tokens, which are paid on sells. Includes",
+              "the protocol's share.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "cumulativeQuoteFees",
+            docs: [
+              "The swap fees paid in quote tokens, which are paid on buys. Includes",
+              "the protocol's share.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "protocolFeeBps",
+            docs: [
+              "The share of each swap fee that goes to the protocol instead of LPs,",
+              "in basis points of the fee. Taken from the `GlobalConfig` when the AMM",
+              "is created and fixed from then on, so it can't change while the AMM",
+              "backs a proposal.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "protocolBaseFeesOwed",
+            docs: [
+              "Protocol fees sit in the vaults until they're collected, but they",
+              "aren't part of `base_amount` or `quote_amount`, so LPs can't withdraw",
+              "them and swaps don't trade against them.",
+            ],
+            type: "u64",
           },
           {
-            name: "quoteAmount";
-            type: "u64";
+            name: "protocolQuoteFeesOwed",
+            type: "u64",
           },
           {
-            name: "oracle";
+            name: "lockedLpTokens",
+            docs: [
+              "LP tokens that count towards the LP supply but were never minted, so",
+              "nobody can burn them. Set to `MINIMUM_LIQUIDITY` on the first deposit.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "admin",
+            docs: [
+              "Can pause and unpause the AMM, e.g. the DAO treasury if a proposal's",
+              "conditional tokens turn out to be misconfigured. `None` means the AMM",
+              "can never be paused.",
+            ],
             type: {
-              defined: "TwapOracle";
-            };
+              option: "publicKey",
+            },
           },
           {
-            name: "seqNum";
-            type: "u64";
-          }
-        ];
-      };
-    }
-  ];
-  types: [
+            name: "pausedAtSlot",
+            docs: [
+              "The slot the AMM was paused at, if it's paused. While an AMM is paused,",
+              "swaps and deposits are disabled but withdrawals aren't, and its TWAP",
+              "oracle is frozen.",
+            ],
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "totalPausedSlots",
+            docs: [
+              "The total slots the AMM has spent paused, not counting a pause that's",
+              "still ongoing. These slots don't count towards the TWAP.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "maxPriceImpactBps",
+            docs: [
+              "The furthest a swap can move the price away from the oracle's last",
+              "observation, in basis points. Swaps that move the price back towards",
+              "the observation are always allowed. `None` means no limit.",
+            ],
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "batchAuctionSlots",
+            docs: [
+              "If set, swaps aren't executed immediately. Instead, orders submitted in",
+              "each window of this many slots are batched and cleared together at a",
+              "single price, so there's nothing to sandwich.",
+            ],
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "unclaimedBatchOrders",
+            docs: ["Batch orders whose tokens are still in the vaults."],
+            type: "u64",
+          },
+          {
+            name: "flashLoan",
+            docs: [
+              "A flash loan that's been lent out and must be repaid later in the",
+              "same transaction.",
+            ],
+            type: {
+              option: {
+                defined: "FlashLoan",
+              },
+            },
+          },
+        ],
+      },
+    },
     {
-      name: "CommonFields";
+      name: "batch",
+      docs: [
+        "The orders submitted to a batch-auction AMM during one window. Their",
+        "input sits in the AMM's vaults, outside of its reserves, until the batch",
+        "is cleared and each order claims its output.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "slot";
-            type: "u64";
+            name: "amm",
+            type: "publicKey",
           },
           {
-            name: "unixTimestamp";
-            type: "i64";
+            name: "id",
+            type: "u64",
           },
           {
-            name: "user";
-            type: "publicKey";
+            name: "endSlot",
+            docs: ["The first slot the batch can be cleared at"],
+            type: "u64",
           },
           {
-            name: "amm";
-            type: "publicKey";
+            name: "totalQuoteIn",
+            type: "u64",
           },
           {
-            name: "postBaseReserves";
-            type: "u64";
+            name: "totalBaseIn",
+            type: "u64",
           },
           {
-            name: "postQuoteReserves";
-            type: "u64";
+            name: "unclaimedOrderCount",
+            type: "u32",
           },
           {
-            name: "oracleLastPrice";
-            type: "u128";
+            name: "clearingPrice",
+            docs: [
+              "The price every order in the batch traded at, once it's cleared",
+            ],
+            type: {
+              option: "u128",
+            },
           },
           {
-            name: "oracleLastObservation";
-            type: "u128";
+            name: "baseOwed",
+            docs: [
+              "What's left to pay out to the batch's orders. Payouts round down, so",
+              "some dust can be left over after every order has claimed.",
+            ],
+            type: "u64",
           },
           {
-            name: "oracleAggregator";
-            type: "u128";
+            name: "quoteOwed",
+            type: "u64",
           },
           {
-            name: "seqNum";
-            type: "u64";
-          }
-        ];
-      };
+            name: "payer",
+            docs: [
+              "Paid for the batch account and gets its rent back once every order",
+              "has claimed",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+        ],
+      },
     },
     {
-      name: "AddLiquidityArgs";
+      name: "batchOrder",
+      docs: [
+        "A user's orders in a batch. Buys and sells from the same user are tracked",
+        "separately, since both clear at the same price.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "quoteAmount";
-            docs: ["How much quote token you will deposit to the pool"];
-            type: "u64";
+            name: "batch",
+            type: "publicKey",
           },
           {
-            name: "maxBaseAmount";
-            docs: ["The maximum base token you will deposit to the pool"];
-            type: "u64";
+            name: "user",
+            type: "publicKey",
           },
           {
-            name: "minLpTokens";
-            docs: ["The minimum LP token you will get back"];
-            type: "u64";
-          }
-        ];
-      };
+            name: "quoteIn",
+            type: "u64",
+          },
+          {
+            name: "baseIn",
+            type: "u64",
+          },
+          {
+            name: "minBaseOut",
+            docs: [
+              "The least the order will accept for its buys and its sells. If the",
+              "batch would clear at a price that pays less than either, the order is",
+              "left out of the batch and gets its input back instead.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "minQuoteOut",
+            type: "u64",
+          },
+          {
+            name: "refunded",
+            docs: ["Set when the batch is cleared without this order"],
+            type: "bool",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+        ],
+      },
     },
     {
-      name: "CreateAmmArgs";
+      name: "globalConfig",
+      docs: [
+        "The protocol fee configuration that every AMM is created with. There's",
+        "one per program, so the protocol rather than each AMM's creator decides",
+        "who collects protocol fees.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "twapInitialObservation";
-            type: "u128";
+            name: "feeAuthority",
+            docs: ["Can collect protocol fees and change this configuration."],
+            type: "publicKey",
           },
           {
-            name: "twapMaxObservationChangePerUpdate";
-            type: "u128";
-          }
-        ];
-      };
+            name: "protocolFeeRecipient",
+            docs: [
+              "The owner of the token accounts that protocol fees are collected to.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "protocolFeeBps",
+            docs: [
+              "The protocol fee that new AMMs are created with, in basis points of",
+              "each swap fee. An AMM's fee can't change after it's created, so",
+              "changing this doesn't affect the markets of proposals that are",
+              "already trading.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "RemoveLiquidityArgs";
+      name: "orderBook",
+      docs: [
+        "Limit orders that rest against an AMM. Each order's input is escrowed in",
+        "the order book's token accounts until it's filled or removed, and anyone",
+        "can fill orders whose limit price the pool has crossed in exchange for a",
+        "bounty.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "lpTokensToBurn";
-            type: "u64";
+            name: "amm",
+            type: "publicKey",
           },
           {
-            name: "minQuoteAmount";
-            type: "u64";
+            name: "nextOrderId",
+            type: "u64",
           },
           {
-            name: "minBaseAmount";
-            type: "u64";
-          }
-        ];
-      };
+            name: "orders",
+            docs: [
+              "Filled orders are paid out and removed when whoever fills them passes",
+              "in their owners' token accounts. Otherwise they stay here until their",
+              "owners remove them or they expire and someone cancels them.",
+            ],
+            type: {
+              vec: {
+                defined: "LimitOrder",
+              },
+            },
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "SwapArgs";
+      name: "position",
+      docs: [
+        "An LP position that records where its liquidity came from. The position",
+        "holds its LP tokens in an account it owns, so unlike fungible LP tokens it",
+        "can be locked as a whole, e.g. by a proposal.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amm",
+            type: "publicKey",
+          },
+          {
+            name: "owner",
+            type: "publicKey",
+          },
+          {
+            name: "nonce",
+            type: "u64",
+          },
+          {
+            name: "lpTokens",
+            docs: [
+              "The LP tokens minted to the position by `add_liquidity` and `zap_in`",
+            ],
+            type: "u64",
+          },
+          {
+            name: "baseDeposited",
+            docs: ["What was actually deposited for those LP tokens"],
+            type: "u64",
+          },
+          {
+            name: "quoteDeposited",
+            type: "u64",
+          },
+          {
+            name: "depositSlot",
+            docs: ["The slot of the latest deposit"],
+            type: "u64",
+          },
+          {
+            name: "locker",
+            docs: [
+              "Whoever has locked the position. Only they can unlock it, and the",
+              "owner can't close it while it's locked.",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "traderRecord",
+      docs: [
+        "Marks that a user has traded on an AMM, so that the AMM counts each trader",
+        "once in `trader_count` no matter how many trades they split their volume",
+        "into. Trading instructions create it the first time a user trades.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "swapType";
-            type: {
-              defined: "SwapType";
-            };
+            name: "amm",
+            type: "publicKey",
           },
           {
-            name: "inputAmount";
-            type: "u64";
+            name: "trader",
+            type: "publicKey",
           },
           {
-            name: "outputAmountMin";
-            type: "u64";
-          }
-        ];
-      };
+            name: "firstTradeSlot",
+            type: "u64",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+        ],
+      },
     },
+  ],
+  types: [
     {
-      name: "TwapOracle";
+      name: "CommonFields",
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "lastUpdatedSlot";
-            type: "u64";
+            name: "slot",
+            type: "u64",
           },
           {
-            name: "lastPrice";
-            docs: [
-              "A price is the number of quote units per base unit multiplied by 1e12.",
-              "You cannot simply divide by 1e12 to get a price you can display in the UI",
-              "because the base and quote decimals may be different. Instead, do:",
-              "ui_price = (price * (10**(base_decimals - quote_decimals))) / 1e12"
-            ];
-            type: "u128";
+            name: "unixTimestamp",
+            type: "i64",
           },
           {
-            name: "lastObservation";
-            docs: [
-              "If we did a raw TWAP over prices, someone could push the TWAP heavily with",
-              "a few extremely large outliers. So we use observations, which can only move",
-              "by `max_observation_change_per_update` per update."
-            ];
-            type: "u128";
+            name: "user",
+            type: "publicKey",
           },
           {
-            name: "aggregator";
-            docs: [
-              "Running sum of slots_per_last_update * last_observation.",
-              "",
-              "Assuming latest observations are as big as possible (u64::MAX * 1e12),",
-              "we can store 18 million slots worth of observations, which turns out to",
-              "be ~85 days worth of slots.",
-              "",
-              "Assuming that latest observations are 100x smaller than they could theoretically",
-              "be, we can store 8500 days (23 years) worth of them. Even this is a very",
-              "very conservative assumption - META/USDC prices should be between 1e9 and",
-              "1e15, which would overflow after 1e15 years worth of slots.",
-              "",
-              "So in the case of an overflow, the aggregator rolls back to 0. It's the",
-              "client's responsibility to sanity check the assets or to handle an",
-              "aggregator at T2 being smaller than an aggregator at T1."
-            ];
-            type: "u128";
+            name: "amm",
+            type: "publicKey",
           },
           {
-            name: "maxObservationChangePerUpdate";
-            docs: ["The most that an observation can change per update."];
-            type: "u128";
+            name: "postBaseReserves",
+            type: "u64",
           },
           {
-            name: "initialObservation";
-            docs: ["What the initial `latest_observation` is set to."];
-            type: "u128";
-          }
-        ];
-      };
-    },
-    {
-      name: "SwapType";
-      type: {
-        kind: "enum";
-        variants: [
+            name: "postQuoteReserves",
+            type: "u64",
+          },
           {
-            name: "Buy";
+            name: "oracleLastPrice",
+            type: "u128",
           },
           {
-            name: "Sell";
-          }
-        ];
-      };
-    }
-  ];
-  events: [
-    {
-      name: "SwapEvent";
-      fields: [
-        {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
-        },
-        {
-          name: "inputAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "outputAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "swapType";
-          type: {
-            defined: "SwapType";
-          };
-          index: false;
-        }
-      ];
-    },
-    {
-      name: "AddLiquidityEvent";
-      fields: [
-        {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
-        },
-        {
-          name: "quoteAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "maxBaseAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "minLpTokens";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "baseAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "lpTokensMinted";
-          type: "u64";
-          index: false;
-        }
-      ];
-    },
-    {
-      name: "RemoveLiquidityEvent";
-      fields: [
-        {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
-        },
-        {
-          name: "lpTokensBurned";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "minQuoteAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "minBaseAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "baseAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "quoteAmount";
-          type: "u64";
-          index: false;
-        }
-      ];
-    },
-    {
-      name: "CreateAmmEvent";
-      fields: [
-        {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
-        },
-        {
-          name: "twapInitialObservation";
-          type: "u128";
-          index: false;
-        },
-        {
-          name: "twapMaxObservationChangePerUpdate";
-          type: "u128";
-          index: false;
-        },
-        {
-          name: "lpMint";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "baseMint";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "quoteMint";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "vaultAtaBase";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "vaultAtaQuote";
-          type: "publicKey";
-          index: false;
-        }
-      ];
+            name: "oracleLastObservation",
+            type: "u128",
+          },
+          {
+            name: "oracleAggregator",
+            type: "u128",
+          },
+          {
+            name: "cumulativeBaseVolume",
+            type: "u128",
+          },
+          {
+            name: "cumulativeQuoteVolume",
+            type: "u128",
+          },
+          {
+            name: "cumulativeBaseFees",
+            type: "u128",
+          },
+          {
+            name: "cumulativeQuoteFees",
+            type: "u128",
+          },
+          {
+            name: "swapCount",
+            type: "u64",
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "CrankThatTwapEvent";
-      fields: [
-        {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
-        }
-      ];
-    }
-  ];
-  errors: [
+      name: "AddLiquidityArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "quoteAmount",
+            docs: ["How much quote token you will deposit to the pool"],
+            type: "u64",
+          },
+          {
+            name: "maxBaseAmount",
+            docs: ["The maximum base token you will deposit to the pool"],
+            type: "u64",
+          },
+          {
+            name: "minLpTokens",
+            docs: ["The minimum LP token you will get back"],
+            type: "u64",
+          },
+        ],
+      },
+    },
     {
-      code: 6000;
-      name: "AssertFailed";
-      msg: "An assertion failed";
+      name: "CancelExpiredLimitOrderArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "orderId",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6001;
-      name: "NoSlotsPassed";
-      msg: "Can't get a TWAP before some observations have been stored";
+      name: "CreateAmmArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "twapInitialObservation",
+            type: "u128",
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate",
+            type: "u128",
+          },
+          {
+            name: "admin",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "maxPriceImpactBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "batchAuctionSlots",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
     },
     {
-      code: 6002;
-      name: "NoReserves";
-      msg: "Can't swap through a pool without token reserves on either side";
+      name: "CreatePositionArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "nonce",
+            docs: ["Lets an owner hold more than one position in an AMM"],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6003;
-      name: "InputAmountOverflow";
-      msg: "Input token amount is too large for a swap, causes overflow";
+      name: "FlashLoanArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "baseAmount",
+            type: "u64",
+          },
+          {
+            name: "quoteAmount",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6004;
-      name: "AddLiquidityCalculationError";
-      msg: "Add liquidity calculation error";
+      name: "InitializeGlobalConfigArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "feeAuthority",
+            type: "publicKey",
+          },
+          {
+            name: "protocolFeeRecipient",
+            type: "publicKey",
+          },
+          {
+            name: "protocolFeeBps",
+            type: "u16",
+          },
+        ],
+      },
     },
     {
-      code: 6005;
-      name: "DecimalScaleError";
-      msg: "Error in decimal scale conversion";
+      name: "PlaceLimitOrderArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapType",
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "inputAmount",
+            type: "u64",
+          },
+          {
+            name: "limitPrice",
+            type: "u128",
+          },
+          {
+            name: "expiresInSlots",
+            docs: [
+              "How many slots the order rests in the book before it expires, at most",
+              "`MAX_LIMIT_ORDER_LIFETIME_SLOTS`",
+            ],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6006;
-      name: "SameTokenMints";
-      msg: "You can't create an AMM pool where the token mints are the same";
+      name: "RemoveLimitOrderArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "orderId",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6007;
-      name: "SwapSlippageExceeded";
-      msg: "A user wouldn't have gotten back their `output_amount_min`, reverting";
+      name: "RemoveLiquidityArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "lpTokensToBurn",
+            type: "u64",
+          },
+          {
+            name: "minQuoteAmount",
+            type: "u64",
+          },
+          {
+            name: "minBaseAmount",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6008;
-      name: "InsufficientBalance";
-      msg: "The user had insufficient balance to do this";
+      name: "SubmitBatchOrderArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "batchId",
+            docs: ["Must be the batch for the current slot"],
+            type: "u64",
+          },
+          {
+            name: "swapType",
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "inputAmount",
+            type: "u64",
+          },
+          {
+            name: "minOutputAmount",
+            docs: [
+              "The least output to accept for `input_amount`. It's added to any",
+              "minimum from the user's earlier orders on the same side of the batch.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6009;
-      name: "ZeroLiquidityRemove";
-      msg: "Must remove a non-zero amount of liquidity";
+      name: "SwapArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapType",
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "inputAmount",
+            type: "u64",
+          },
+          {
+            name: "outputAmountMin",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6010;
-      name: "ZeroLiquidityToAdd";
-      msg: "Cannot add liquidity with 0 tokens on either side";
+      name: "UpdateProtocolFeeArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "protocolFeeBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "protocolFeeRecipient",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "feeAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
     },
     {
-      code: 6011;
-      name: "ZeroMinLpTokens";
-      msg: "Must specify a non-zero `min_lp_tokens` when adding to an existing pool";
+      name: "ZapInArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapType",
+            docs: [
+              "`Buy` deposits quote tokens and swaps some of them into base tokens,",
+              "`Sell` deposits base tokens and swaps some of them into quote tokens",
+            ],
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "inputAmount",
+            docs: ["How much of the input token you will deposit to the pool"],
+            type: "u64",
+          },
+          {
+            name: "minLpTokens",
+            docs: ["The minimum LP token you will get back"],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6012;
-      name: "AddLiquiditySlippageExceeded";
-      msg: "LP wouldn't have gotten back `lp_token_min`";
+      name: "ZapOutArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapType",
+            docs: [
+              "`Sell` swaps the withdrawn base tokens so that you receive only quote",
+              "tokens, `Buy` swaps the withdrawn quote tokens so that you receive",
+              "only base tokens",
+            ],
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "lpTokensToBurn",
+            type: "u64",
+          },
+          {
+            name: "minOutputAmount",
+            docs: ["The minimum you will get back of the token you receive"],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6013;
-      name: "AddLiquidityMaxBaseExceeded";
-      msg: "LP would have spent more than `max_base_amount`";
+      name: "TwapOracle",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "lastUpdatedSlot",
+            type: "u64",
+          },
+          {
+            name: "lastPrice",
+            docs: [
+              "A price is the number of quote units per base unit multiplied by 1e12.",
+              "You cannot simply divide by 1e12 to get a price you can display in the UI",
+              "because the base and quote decimals may be different. Instead, use",
+              "`price_math::Price::to_ui`.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "lastObservation",
+            docs: [
+              "If we did a raw TWAP over prices, someone could push the TWAP heavily with",
+              "a few extremely large outliers. So we use observations, which can only move",
+              "by `max_observation_change_per_update` per update.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "aggregator",
+            docs: [
+              "Running sum of slots_per_last_update * last_observation.",
+              "",
+              "Assuming latest observations are as big as possible (u64::MAX * 1e12),",
+              "we can store 18 million slots worth of observations, which turns out to",
+              "be ~85 days worth of slots.",
+              "",
+              "Assuming that latest observations are 100x smaller than they could theoretically",
+              "be, we can store 8500 days (23 years) worth of them. Even this is a very",
+              "very conservative assumption - META/USDC prices should be between 1e9 and",
+              "1e15, which would overflow after 1e15 years worth of slots.",
+              "",
+              "So in the case of an overflow, the aggregator rolls back to 0. It's the",
+              "client's responsibility to sanity check the assets or to handle an",
+              "aggregator at T2 being smaller than an aggregator at T1.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "maxObservationChangePerUpdate",
+            docs: ["The most that an observation can change per update."],
+            type: "u128",
+          },
+          {
+            name: "initialObservation",
+            docs: ["What the initial `latest_observation` is set to."],
+            type: "u128",
+          },
+        ],
+      },
     },
     {
-      code: 6014;
-      name: "InsufficientQuoteAmount";
-      msg: "`quote_amount` must be greater than 100000000 when initializing a pool";
+      name: "FlashLoan",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "baseAmount",
+            type: "u64",
+          },
+          {
+            name: "quoteAmount",
+            type: "u64",
+          },
+          {
+            name: "baseFee",
+            type: "u64",
+          },
+          {
+            name: "quoteFee",
+            type: "u64",
+          },
+          {
+            name: "vaultBaseBefore",
+            docs: [
+              "What the vaults held before the loan, so that repaying it can check",
+              "that they end up with at least as much",
+            ],
+            type: "u64",
+          },
+          {
+            name: "vaultQuoteBefore",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6015;
-      name: "ZeroSwapAmount";
-      msg: "Users must swap a non-zero amount";
+      name: "LimitOrder",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "id",
+            type: "u64",
+          },
+          {
+            name: "owner",
+            type: "publicKey",
+          },
+          {
+            name: "swapType",
+            docs: [
+              "`Buy` spends quote tokens on base tokens, `Sell` spends base tokens on",
+              "quote tokens",
+            ],
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "inputAmount",
+            type: "u64",
+          },
+          {
+            name: "limitPrice",
+            docs: [
+              "The worst average price the order will fill at, in the same units as",
+              "AMM prices. For buys this is a maximum and for sells it's a minimum.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "outputAmount",
+            docs: [
+              "What the owner receives, net of the filler's bounty. `None` until the",
+              "order is filled.",
+            ],
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "expiresAtSlot",
+            docs: [
+              "Once this slot has passed, the order can't be filled and anyone can",
+              "cancel it.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6016;
-      name: "ConstantProductInvariantFailed";
-      msg: "K should always be increasing";
+      name: "SwapType",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Buy",
+          },
+          {
+            name: "Sell",
+          },
+        ],
+      },
     },
+  ],
+  events: [
     {
-      code: 6017;
-      name: "CastingOverflow";
-      msg: "Casting has caused an overflow";
-    }
-  ];
-};
-
-export const IDL: Amm = {
-  version: "0.4.0",
-  name: "amm",
-  instructions: [
-    {
-      name: "createAmm",
-      accounts: [
+      name: "SwapEvent",
+      fields: [
         {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "amm",
-          isMut: true,
-          isSigner: false,
+          name: "inputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "outputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "swapType",
+          type: {
+            defined: "SwapType",
+          },
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AddLiquidityEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "lpMint",
-          isMut: true,
-          isSigner: false,
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "baseMint",
-          isMut: false,
-          isSigner: false,
+          name: "maxBaseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "quoteMint",
-          isMut: false,
-          isSigner: false,
+          name: "minLpTokens",
+          type: "u64",
+          index: false,
         },
         {
-          name: "vaultAtaBase",
-          isMut: true,
-          isSigner: false,
+          name: "baseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "vaultAtaQuote",
-          isMut: true,
-          isSigner: false,
+          name: "lpTokensMinted",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "RemoveLiquidityEvent",
+      fields: [
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "lpTokensBurned",
+          type: "u64",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "minQuoteAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "minBaseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "program",
-          isMut: false,
-          isSigner: false,
+          name: "baseAmount",
+          type: "u64",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "args",
-          type: {
-            defined: "CreateAmmArgs",
-          },
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
         },
       ],
     },
     {
-      name: "addLiquidity",
-      accounts: [
+      name: "ZapInEvent",
+      fields: [
         {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "amm",
-          isMut: true,
-          isSigner: false,
+          name: "swapType",
+          type: {
+            defined: "SwapType",
+          },
+          index: false,
         },
         {
-          name: "lpMint",
-          isMut: true,
-          isSigner: false,
+          name: "inputAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "userLpAccount",
-          isMut: true,
-          isSigner: false,
+          name: "minLpTokens",
+          type: "u64",
+          index: false,
         },
         {
-          name: "userBaseAccount",
-          isMut: true,
-          isSigner: false,
+          name: "swapInputAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "userQuoteAccount",
-          isMut: true,
-          isSigner: false,
+          name: "swapOutputAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "vaultAtaBase",
-          isMut: true,
-          isSigner: false,
+          name: "inputDeposited",
+          type: "u64",
+          index: false,
         },
         {
-          name: "vaultAtaQuote",
-          isMut: true,
-          isSigner: false,
+          name: "outputDeposited",
+          type: "u64",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "outputRefunded",
+          type: "u64",
+          index: false,
         },
         {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "lpTokensMinted",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "ZapOutEvent",
+      fields: [
         {
-          name: "program",
-          isMut: false,
-          isSigner: false,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "args",
+          name: "swapType",
           type: {
-            defined: "AddLiquidityArgs",
+            defined: "SwapType",
           },
+          index: false,
+        },
+        {
+          name: "lpTokensBurned",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "minOutputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "baseWithdrawn",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteWithdrawn",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "swapInputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "swapOutputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "outputAmount",
+          type: "u64",
+          index: false,
         },
       ],
     },
     {
-      name: "removeLiquidity",
-      accounts: [
+      name: "CreateAmmEvent",
+      fields: [
         {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "amm",
-          isMut: true,
-          isSigner: false,
+          name: "twapInitialObservation",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "twapMaxObservationChangePerUpdate",
+          type: "u128",
+          index: false,
         },
         {
           name: "lpMint",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "userLpAccount",
-          isMut: true,
-          isSigner: false,
+          name: "baseMint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "userBaseAccount",
-          isMut: true,
-          isSigner: false,
+          name: "quoteMint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "userQuoteAccount",
-          isMut: true,
-          isSigner: false,
+          name: "vaultAtaBase",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "protocolFeeBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "admin",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "maxPriceImpactBps",
+          type: {
+            option: "u16",
+          },
+          index: false,
         },
         {
-          name: "vaultAtaBase",
-          isMut: true,
-          isSigner: false,
+          name: "batchAuctionSlots",
+          type: {
+            option: "u64",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "CrankThatTwapEvent",
+      fields: [
         {
-          name: "vaultAtaQuote",
-          isMut: true,
-          isSigner: false,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "CloseAmmEvent",
+      fields: [
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "leftoverBaseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "program",
-          isMut: false,
-          isSigner: false,
+          name: "leftoverQuoteAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "refundedOrderIds",
+          type: {
+            vec: "u64",
+          },
+          index: false,
         },
       ],
-      args: [
+    },
+    {
+      name: "MigrateAmmEvent",
+      fields: [
         {
-          name: "args",
+          name: "common",
           type: {
-            defined: "RemoveLiquidityArgs",
+            defined: "CommonFields",
           },
+          index: false,
         },
       ],
     },
     {
-      name: "swap",
-      accounts: [
+      name: "CollectProtocolFeesEvent",
+      fields: [
         {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "amm",
-          isMut: true,
-          isSigner: false,
+          name: "protocolFeeRecipient",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "userBaseAccount",
-          isMut: true,
-          isSigner: false,
+          name: "baseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "userQuoteAccount",
-          isMut: true,
-          isSigner: false,
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "InitializeGlobalConfigEvent",
+      fields: [
         {
-          name: "vaultAtaBase",
-          isMut: true,
-          isSigner: false,
+          name: "slot",
+          type: "u64",
+          index: false,
         },
         {
-          name: "vaultAtaQuote",
-          isMut: true,
-          isSigner: false,
+          name: "unixTimestamp",
+          type: "i64",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "upgradeAuthority",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "globalConfig",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "program",
-          isMut: false,
-          isSigner: false,
+          name: "feeAuthority",
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "args",
-          type: {
-            defined: "SwapArgs",
-          },
+          name: "protocolFeeRecipient",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "protocolFeeBps",
+          type: "u16",
+          index: false,
         },
       ],
     },
     {
-      name: "crankThatTwap",
-      accounts: [
+      name: "UpdateProtocolFeeEvent",
+      fields: [
         {
-          name: "amm",
-          isMut: true,
-          isSigner: false,
+          name: "slot",
+          type: "u64",
+          index: false,
         },
         {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "unixTimestamp",
+          type: "i64",
+          index: false,
         },
         {
-          name: "program",
-          isMut: false,
-          isSigner: false,
+          name: "user",
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [],
-    },
-  ],
-  accounts: [
-    {
-      name: "amm",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "bump",
-            type: "u8",
-          },
-          {
-            name: "createdAtSlot",
-            type: "u64",
-          },
-          {
-            name: "lpMint",
-            type: "publicKey",
-          },
-          {
-            name: "baseMint",
-            type: "publicKey",
-          },
-          {
-            name: "quoteMint",
-            type: "publicKey",
-          },
-          {
-            name: "baseMintDecimals",
-            type: "u8",
-          },
-          {
-            name: "quoteMintDecimals",
-            type: "u8",
-          },
-          {
-            name: "baseAmount",
-            type: "u64",
-          },
-          {
-            name: "quoteAmount",
-            type: "u64",
-          },
-          {
-            name: "oracle",
-            type: {
-              defined: "TwapOracle",
-            },
-          },
-          {
-            name: "seqNum",
-            type: "u64",
-          },
-        ],
-      },
-    },
-  ],
-  types: [
-    {
-      name: "CommonFields",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "slot",
-            type: "u64",
-          },
-          {
-            name: "unixTimestamp",
-            type: "i64",
-          },
-          {
-            name: "user",
-            type: "publicKey",
-          },
-          {
-            name: "amm",
-            type: "publicKey",
-          },
-          {
-            name: "postBaseReserves",
-            type: "u64",
-          },
-          {
-            name: "postQuoteReserves",
-            type: "u64",
-          },
-          {
-            name: "oracleLastPrice",
-            type: "u128",
-          },
-          {
-            name: "oracleLastObservation",
-            type: "u128",
-          },
-          {
-            name: "oracleAggregator",
-            type: "u128",
-          },
-          {
-            name: "seqNum",
-            type: "u64",
-          },
-        ],
-      },
+        {
+          name: "globalConfig",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "protocolFeeBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "protocolFeeRecipient",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "feeAuthority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "seqNum",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "AddLiquidityArgs",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "quoteAmount",
-            docs: ["How much quote token you will deposit to the pool"],
-            type: "u64",
-          },
-          {
-            name: "maxBaseAmount",
-            docs: ["The maximum base token you will deposit to the pool"],
-            type: "u64",
-          },
-          {
-            name: "minLpTokens",
-            docs: ["The minimum LP token you will get back"],
-            type: "u64",
+      name: "PauseAmmEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
     {
-      name: "CreateAmmArgs",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "twapInitialObservation",
-            type: "u128",
-          },
-          {
-            name: "twapMaxObservationChangePerUpdate",
-            type: "u128",
+      name: "UnpauseAmmEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "pausedSlots",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "RemoveLiquidityArgs",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "lpTokensToBurn",
-            type: "u64",
-          },
-          {
-            name: "minQuoteAmount",
-            type: "u64",
-          },
-          {
-            name: "minBaseAmount",
-            type: "u64",
+      name: "InitializeOrderBookEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "orderBook",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "SwapArgs",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "swapType",
-            type: {
-              defined: "SwapType",
-            },
-          },
-          {
-            name: "inputAmount",
-            type: "u64",
+      name: "PlaceLimitOrderEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-          {
-            name: "outputAmountMin",
-            type: "u64",
+          index: false,
+        },
+        {
+          name: "orderBook",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "orderId",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "swapType",
+          type: {
+            defined: "SwapType",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "inputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "limitPrice",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "expiresAtSlot",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "TwapOracle",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "lastUpdatedSlot",
-            type: "u64",
-          },
-          {
-            name: "lastPrice",
-            docs: [
-              "A price is the number of quote units per base unit multiplied by 1e12.",
-              "You cannot simply divide by 1e12 to get a price you can display in the UI",
-              "because the base and quote decimals may be different. Instead, do:",
-              "ui_price = (price * (10**(base_decimals - quote_decimals))) / 1e12",
-            ],
-            type: "u128",
-          },
-          {
-            name: "lastObservation",
-            docs: [
-              "If we did a raw TWAP over prices, someone could push the TWAP heavily with",
-              "a few extremely large outliers. So we use observations, which can only move",
-              "by `max_observation_change_per_update` per update.",
-            ],
-            type: "u128",
-          },
-          {
-            name: "aggregator",
-            docs: [
-              "Running sum of slots_per_last_update * last_observation.",
-              "",
-              "Assuming latest observations are as big as possible (u64::MAX * 1e12),",
-              "we can store 18 million slots worth of observations, which turns out to",
-              "be ~85 days worth of slots.",
-              "",
-              "Assuming that latest observations are 100x smaller than they could theoretically",
-              "be, we can store 8500 days (23 years) worth of them. Even this is a very",
-              "very conservative assumption - META/USDC prices should be between 1e9 and",
-              "1e15, which would overflow after 1e15 years worth of slots.",
-              "",
-              "So in the case of an overflow, the aggregator rolls back to 0. It's the",
-              "client's responsibility to sanity check the assets or to handle an",
-              "aggregator at T2 being smaller than an aggregator at T1.",
-            ],
-            type: "u128",
-          },
-          {
-            name: "maxObservationChangePerUpdate",
-            docs: ["The most that an observation can change per update."],
-            type: "u128",
+      name: "RemoveLimitOrderEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-          {
-            name: "initialObservation",
-            docs: ["What the initial `latest_observation` is set to."],
-            type: "u128",
+          index: false,
+        },
+        {
+          name: "orderBook",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "orderId",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "filled",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "CancelExpiredLimitOrderEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "orderBook",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "orderId",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "owner",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "filled",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "baseAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "SwapType",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Buy",
+      name: "FillOrdersEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-          {
-            name: "Sell",
+          index: false,
+        },
+        {
+          name: "orderBook",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "filledOrderIds",
+          type: {
+            vec: "u64",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "settledOrderIds",
+          type: {
+            vec: "u64",
+          },
+          index: false,
+        },
+        {
+          name: "baseBounty",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteBounty",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
-  ],
-  events: [
     {
-      name: "SwapEvent",
+      name: "SubmitBatchOrderEvent",
       fields: [
         {
           name: "common",
@@ -1378,12 +7397,12 @@ export const IDL: Amm = {
           index: false,
         },
         {
-          name: "inputAmount",
-          type: "u64",
+          name: "batch",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "outputAmount",
+          name: "batchId",
           type: "u64",
           index: false,
         },
@@ -1394,10 +7413,20 @@ export const IDL: Amm = {
           },
           index: false,
         },
+        {
+          name: "inputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "minOutputAmount",
+          type: "u64",
+          index: false,
+        },
       ],
     },
     {
-      name: "AddLiquidityEvent",
+      name: "ClearBatchEvent",
       fields: [
         {
           name: "common",
@@ -1407,34 +7436,88 @@ export const IDL: Amm = {
           index: false,
         },
         {
-          name: "quoteAmount",
+          name: "batch",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "batchId",
           type: "u64",
           index: false,
         },
         {
-          name: "maxBaseAmount",
+          name: "totalQuoteIn",
           type: "u64",
           index: false,
         },
         {
-          name: "minLpTokens",
+          name: "totalBaseIn",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "clearingPrice",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "baseOwed",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteOwed",
           type: "u64",
           index: false,
         },
+        {
+          name: "refundedOrders",
+          type: {
+            vec: "publicKey",
+          },
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ClaimBatchOrderEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "batch",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "clearingPrice",
+          type: "u128",
+          index: false,
+        },
         {
           name: "baseAmount",
           type: "u64",
           index: false,
         },
         {
-          name: "lpTokensMinted",
+          name: "quoteAmount",
           type: "u64",
           index: false,
         },
+        {
+          name: "refunded",
+          type: "bool",
+          index: false,
+        },
       ],
     },
     {
-      name: "RemoveLiquidityEvent",
+      name: "FlashLoanEvent",
       fields: [
         {
           name: "common",
@@ -1444,20 +7527,37 @@ export const IDL: Amm = {
           index: false,
         },
         {
-          name: "lpTokensBurned",
+          name: "baseAmount",
           type: "u64",
           index: false,
         },
         {
-          name: "minQuoteAmount",
+          name: "quoteAmount",
           type: "u64",
           index: false,
         },
         {
-          name: "minBaseAmount",
+          name: "baseFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteFee",
           type: "u64",
           index: false,
         },
+      ],
+    },
+    {
+      name: "RepayFlashLoanEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
         {
           name: "baseAmount",
           type: "u64",
@@ -1468,10 +7568,20 @@ export const IDL: Amm = {
           type: "u64",
           index: false,
         },
+        {
+          name: "baseFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteFee",
+          type: "u64",
+          index: false,
+        },
       ],
     },
     {
-      name: "CreateAmmEvent",
+      name: "CreatePositionEvent",
       fields: [
         {
           name: "common",
@@ -1481,44 +7591,68 @@ export const IDL: Amm = {
           index: false,
         },
         {
-          name: "twapInitialObservation",
-          type: "u128",
+          name: "position",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "twapMaxObservationChangePerUpdate",
-          type: "u128",
+          name: "nonce",
+          type: "u64",
           index: false,
         },
+      ],
+    },
+    {
+      name: "ClosePositionEvent",
+      fields: [
         {
-          name: "lpMint",
-          type: "publicKey",
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
           index: false,
         },
         {
-          name: "baseMint",
+          name: "position",
           type: "publicKey",
           index: false,
         },
         {
-          name: "quoteMint",
-          type: "publicKey",
+          name: "lpTokens",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "LockPositionEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
           index: false,
         },
         {
-          name: "vaultAtaBase",
+          name: "position",
           type: "publicKey",
           index: false,
         },
         {
-          name: "vaultAtaQuote",
+          name: "locker",
           type: "publicKey",
           index: false,
         },
+        {
+          name: "lpTokens",
+          type: "u64",
+          index: false,
+        },
       ],
     },
     {
-      name: "CrankThatTwapEvent",
+      name: "UnlockPositionEvent",
       fields: [
         {
           name: "common",
@@ -1527,6 +7661,16 @@ export const IDL: Amm = {
           },
           index: false,
         },
+        {
+          name: "position",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "locker",
+          type: "publicKey",
+          index: false,
+        },
       ],
     },
   ],
@@ -1621,5 +7765,185 @@ export const IDL: Amm = {
       name: "CastingOverflow",
       msg: "Casting has caused an overflow",
     },
+    {
+      code: 6018,
+      name: "LpTokensOutstanding",
+      msg: "An AMM can't be closed while any of its LP tokens are outstanding",
+    },
+    {
+      code: 6019,
+      name: "InvalidAmm",
+      msg: "This account isn't an AMM",
+    },
+    {
+      code: 6020,
+      name: "AmmAlreadyMigrated",
+      msg: "This AMM has already been migrated",
+    },
+    {
+      code: 6021,
+      name: "InvalidProtocolFee",
+      msg: "The protocol fee can be at most `MAX_PROTOCOL_FEE_BPS`",
+    },
+    {
+      code: 6022,
+      name: "ProtocolFeesUncollected",
+      msg: "An AMM can't be closed until its protocol fees have been collected",
+    },
+    {
+      code: 6023,
+      name: "AmmPaused",
+      msg: "This AMM is paused",
+    },
+    {
+      code: 6024,
+      name: "AmmNotPaused",
+      msg: "This AMM isn't paused",
+    },
+    {
+      code: 6025,
+      name: "InvalidAdmin",
+      msg: "This AMM doesn't have an admin, or the signer isn't its admin",
+    },
+    {
+      code: 6026,
+      name: "PriceImpactTooHigh",
+      msg: "This swap would move the price too far from the oracle's last observation",
+    },
+    {
+      code: 6027,
+      name: "OrderBookFull",
+      msg: "This order book already has `MAX_LIMIT_ORDERS` orders",
+    },
+    {
+      code: 6028,
+      name: "LimitOrderNotFound",
+      msg: "No limit order with this id is in the order book",
+    },
+    {
+      code: 6029,
+      name: "InvalidLimitOrder",
+      msg: "Limit orders need a non-zero input amount and limit price",
+    },
+    {
+      code: 6030,
+      name: "NoOrdersFilled",
+      msg: "None of the order book's orders could be filled",
+    },
+    {
+      code: 6031,
+      name: "LimitOrderTooSmall",
+      msg: "Limit orders must be at least `MIN_LIMIT_ORDER_BPS` of the AMM's input reserve",
+    },
+    {
+      code: 6032,
+      name: "TooManyLimitOrders",
+      msg: "This user already has `MAX_LIMIT_ORDERS_PER_OWNER` orders in this order book",
+    },
+    {
+      code: 6033,
+      name: "InvalidLimitOrderExpiry",
+      msg: "Limit orders must expire within `MAX_LIMIT_ORDER_LIFETIME_SLOTS`",
+    },
+    {
+      code: 6034,
+      name: "LimitOrderNotExpired",
+      msg: "Only the owner can remove a limit order before it expires",
+    },
+    {
+      code: 6035,
+      name: "MissingOrderOwnerAccount",
+      msg: "Each order left in the order book needs its owner's associated token account passed in",
+    },
+    {
+      code: 6036,
+      name: "BatchAuctionMode",
+      msg: "This AMM is in batch auction mode, so orders have to go through batches",
+    },
+    {
+      code: 6037,
+      name: "NotInBatchAuctionMode",
+      msg: "This AMM isn't in batch auction mode",
+    },
+    {
+      code: 6038,
+      name: "InvalidBatchAuctionConfig",
+      msg: "Batched AMMs need a non-zero window and can't have a price impact limit",
+    },
+    {
+      code: 6039,
+      name: "BatchClosed",
+      msg: "Orders can only be submitted to the current batch",
+    },
+    {
+      code: 6040,
+      name: "BatchNotEnded",
+      msg: "This batch's window hasn't ended yet",
+    },
+    {
+      code: 6041,
+      name: "BatchAlreadyCleared",
+      msg: "This batch has already been cleared",
+    },
+    {
+      code: 6042,
+      name: "BatchNotCleared",
+      msg: "This batch hasn't been cleared yet",
+    },
+    {
+      code: 6043,
+      name: "BatchOrdersUnclaimed",
+      msg: "An AMM can't be closed while any of its batch orders are unclaimed",
+    },
+    {
+      code: 6044,
+      name: "BatchFull",
+      msg: "This batch already has `MAX_BATCH_ORDERS` orders",
+    },
+    {
+      code: 6045,
+      name: "InvalidBatchOrders",
+      msg: "Clearing a batch needs each of its orders passed in exactly once",
+    },
+    {
+      code: 6046,
+      name: "FlashLoanOutstanding",
+      msg: "This AMM already has a flash loan outstanding",
+    },
+    {
+      code: 6047,
+      name: "NoFlashLoan",
+      msg: "This AMM doesn't have a flash loan outstanding",
+    },
+    {
+      code: 6048,
+      name: "InvalidFlashLoanAmount",
+      msg: "Flash loans must borrow something and at most the AMM's reserves",
+    },
+    {
+      code: 6049,
+      name: "FlashLoanNotRepaid",
+      msg: "A flash loan must be followed by a `repay_flash_loan` for the same AMM",
+    },
+    {
+      code: 6050,
+      name: "VaultsUndercollateralized",
+      msg: "The AMM's vaults don't hold its reserves and protocol fees",
+    },
+    {
+      code: 6051,
+      name: "PositionLocked",
+      msg: "This position is locked",
+    },
+    {
+      code: 6052,
+      name: "PositionNotLocked",
+      msg: "This position isn't locked",
+    },
+    {
+      code: 6053,
+      name: "InvalidLocker",
+      msg: "Only the position's locker can unlock it",
+    },
   ],
 };
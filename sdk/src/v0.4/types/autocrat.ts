@@ -63,6 +63,16 @@ export type Autocrat = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "proposerRecord";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only needed if the DAO limits how many proposals each proposer can",
+            "have trading, so that other proposers don't pay rent for one"
+          ];
+        },
         {
           name: "question";
           isMut: false;
@@ -98,6 +108,22 @@ export type Autocrat = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "spotAmm";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only needed if the DAO's decision rule compares against a spot AMM"
+          ];
+        },
+        {
+          name: "spotRaydiumObservation";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Raydium pool, and checked against the rule in `validate`"];
+        },
         {
           name: "passLpUserAccount";
           isMut: true;
@@ -118,6 +144,22 @@ export type Autocrat = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "passPosition";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "A position to lock on top of `pass_lp_tokens_to_lock`. Its liquidity",
+            "counts towards the DAO's minimum."
+          ];
+        },
+        {
+          name: "failPosition";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
         {
           name: "proposer";
           isMut: true;
@@ -133,6 +175,16 @@ export type Autocrat = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "ammProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ammEventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "eventAuthority";
           isMut: false;
@@ -163,19 +215,48 @@ export type Autocrat = {
         },
         {
           name: "passAmm";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
           name: "failAmm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "spotAmm";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only needed if the proposal was created under a decision rule that",
+            "compares against a spot AMM"
+          ];
+        },
+        {
+          name: "spotRaydiumObservation";
           isMut: false;
           isSigner: false;
+          isOptional: true;
+          docs: [
+            "that compares against a Raydium pool, and checked against the",
+            "proposal's snapshot"
+          ];
         },
         {
           name: "dao";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
+        {
+          name: "proposerRecord";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only needed if the proposal was counted in the proposer's record"
+          ];
+        },
         {
           name: "question";
           isMut: true;
@@ -206,6 +287,19 @@ export type Autocrat = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "passPosition";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Only needed if the proposer locked positions"];
+        },
+        {
+          name: "failPosition";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
         {
           name: "tokenProgram";
           isMut: false;
@@ -221,6 +315,31 @@ export type Autocrat = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "ammProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ammEventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "keeperPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "keeper";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "eventAuthority";
           isMut: false;
@@ -244,6 +363,108 @@ export type Autocrat = {
         },
         {
           name: "dao";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "keeperPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "keeper";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeProposal";
+      accounts: [
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "proposer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "crankProposalTwaps";
+      accounts: [
+        {
+          name: "proposal";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "dao";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "passAmm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "failAmm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "keeperPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "keeper";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "ammProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ammEventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
@@ -292,1349 +513,5231 @@ export type Autocrat = {
           };
         }
       ];
-    }
-  ];
-  accounts: [
-    {
-      name: "dao";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "treasuryPdaBump";
-            type: "u8";
-          },
-          {
-            name: "treasury";
-            type: "publicKey";
-          },
-          {
-            name: "tokenMint";
-            type: "publicKey";
-          },
-          {
-            name: "usdcMint";
-            type: "publicKey";
-          },
-          {
-            name: "proposalCount";
-            type: "u32";
-          },
-          {
-            name: "passThresholdBps";
-            type: "u16";
-          },
-          {
-            name: "slotsPerProposal";
-            type: "u64";
-          },
-          {
-            name: "twapInitialObservation";
-            docs: [
-              "For manipulation-resistance the TWAP is a time-weighted average observation,",
-              "where observation tries to approximate price but can only move by",
-              "`twap_max_observation_change_per_update` per update. Because it can only move",
-              "a little bit per update, you need to check that it has a good initial observation.",
-              "Otherwise, an attacker could create a very high initial observation in the pass",
-              "market and a very low one in the fail market to force the proposal to pass.",
-              "",
-              "We recommend setting an initial observation around the spot price of the token,",
-              "and max observation change per update around 2% the spot price of the token.",
-              "For example, if the spot price of META is $400, we'd recommend setting an initial",
-              "observation of 400 (converted into the AMM prices) and a max observation change per",
-              "update of 8 (also converted into the AMM prices). Observations can be updated once",
-              "a minute, so 2% allows the proposal market to reach double the spot price or 0",
-              "in 50 minutes."
-            ];
-            type: "u128";
-          },
-          {
-            name: "twapMaxObservationChangePerUpdate";
-            type: "u128";
-          },
-          {
-            name: "minQuoteFutarchicLiquidity";
-            docs: [
-              "As an anti-spam measure and to help liquidity, you need to lock up some liquidity",
-              "in both futarchic markets in order to create a proposal.",
-              "",
-              "For example, for META, we can use a `min_quote_futarchic_liquidity` of",
-              "5000 * 1_000_000 (5000 USDC) and a `min_base_futarchic_liquidity` of",
-              "10 * 1_000_000_000 (10 META)."
-            ];
-            type: "u64";
-          },
-          {
-            name: "minBaseFutarchicLiquidity";
-            type: "u64";
-          },
-          {
-            name: "seqNum";
-            type: "u64";
-          }
-        ];
-      };
     },
     {
-      name: "proposal";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "number";
-            type: "u32";
+      name: "updateDaoSettings";
+      accounts: [
+        {
+          name: "dao";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "spotAmm";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only needed if the decision rule is being set to compare against a",
+            "spot AMM"
+          ];
+        },
+        {
+          name: "spotRaydiumPool";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ["against a Raydium pool, and parsed in `validate`"];
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "UpdateDaoSettingsParams";
+          };
+        }
+      ];
+    },
+    {
+      name: "migrateDao";
+      accounts: [
+        {
+          name: "dao";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "deserialize as a `Dao`, so we check it by hand in `validate`"
+          ];
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initializeStream";
+      accounts: [
+        {
+          name: "stream";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "dao";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasuryTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "streamTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "InitializeStreamParams";
+          };
+        }
+      ];
+    },
+    {
+      name: "withdrawFromStream";
+      accounts: [
+        {
+          name: "stream";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "streamTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "cancelStream";
+      accounts: [
+        {
+          name: "stream";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "dao";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "streamTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasuryTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "upgradeProgram";
+      accounts: [
+        {
+          name: "dao";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "targetProgram";
+          isMut: true;
+          isSigner: false;
+          docs: ["`program_data` belongs to"];
+        },
+        {
+          name: "programData";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buffer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "spill";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "clock";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "bpfLoaderUpgradeableProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "UpgradeProgramParams";
+          };
+        }
+      ];
+    },
+    {
+      name: "initializeTreasuryOrder";
+      accounts: [
+        {
+          name: "order";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "dao";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "orderBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasuryInputAccount";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Holds the order's input, so it's a quote account for buys and a base",
+            "account for sells"
+          ];
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "InitializeTreasuryOrderParams";
+          };
+        }
+      ];
+    },
+    {
+      name: "crankTreasuryOrder";
+      accounts: [
+        {
+          name: "order";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "dao";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+          docs: [
+            "Pays for the order's trader record on the AMM the first time the order",
+            "is cranked, since the order itself can't pay"
+          ];
+        },
+        {
+          name: "traderRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasuryOutputAccount";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Receives the order's output, so it's a base account for buys and a",
+            "quote account for sells"
+          ];
+        },
+        {
+          name: "ammBaseVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "ammQuoteVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ammProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ammEventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "cancelTreasuryOrder";
+      accounts: [
+        {
+          name: "order";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "dao";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "orderBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasuryBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasuryQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "dao";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "treasuryPdaBump";
+            type: "u8";
+          },
+          {
+            name: "treasury";
+            type: "publicKey";
+          },
+          {
+            name: "tokenMint";
+            type: "publicKey";
+          },
+          {
+            name: "usdcMint";
+            type: "publicKey";
+          },
+          {
+            name: "proposalCount";
+            type: "u32";
+          },
+          {
+            name: "passThresholdBps";
+            type: "u16";
+          },
+          {
+            name: "slotsPerProposal";
+            type: "u64";
+          },
+          {
+            name: "twapInitialObservation";
+            docs: [
+              "For manipulation-resistance the TWAP is a time-weighted average observation,",
+              "where observation tries to approximate price but can only move by",
+              "`twap_max_observation_change_per_update` per update. Because it can only move",
+              "a little bit per update, you need to check that it has a good initial observation.",
+              "Otherwise, an attacker could create a very high initial observation in the pass",
+              "market and a very low one in the fail market to force the proposal to pass.",
+              "",
+              "We recommend setting an initial observation around the spot price of the token,",
+              "and max observation change per update around 2% the spot price of the token.",
+              "For example, if the spot price of META is $400, we'd recommend setting an initial",
+              "observation of 400 (converted into the AMM prices) and a max observation change per",
+              "update of 8 (also converted into the AMM prices). Observations can be updated once",
+              "a minute, so 2% allows the proposal market to reach double the spot price or 0",
+              "in 50 minutes."
+            ];
+            type: "u128";
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate";
+            type: "u128";
+          },
+          {
+            name: "minQuoteFutarchicLiquidity";
+            docs: [
+              "As an anti-spam measure and to help liquidity, you need to lock up some liquidity",
+              "in both futarchic markets in order to create a proposal.",
+              "",
+              "For example, for META, we can use a `min_quote_futarchic_liquidity` of",
+              "5000 * 1_000_000 (5000 USDC) and a `min_base_futarchic_liquidity` of",
+              "10 * 1_000_000_000 (10 META)."
+            ];
+            type: "u64";
+          },
+          {
+            name: "minBaseFutarchicLiquidity";
+            type: "u64";
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          },
+          {
+            name: "delegatedProposers";
+            docs: [
+              "Delegated proposers can create proposals with a smaller LP lock than",
+              "everyone else, but only once every `delegated_proposer_cooldown_slots`.",
+              "This lets a DAO hand out proposal rights to trusted contributors who",
+              "don't have much liquidity. Setting the delegated minimums to 0 lets",
+              "them propose without locking any LP at all."
+            ];
+            type: {
+              vec: {
+                defined: "DelegatedProposer";
+              };
+            };
+          },
+          {
+            name: "delegatedProposerCooldownSlots";
+            type: "u64";
+          },
+          {
+            name: "delegatedProposerMinQuoteFutarchicLiquidity";
+            type: "u64";
+          },
+          {
+            name: "delegatedProposerMinBaseFutarchicLiquidity";
+            type: "u64";
+          },
+          {
+            name: "activeProposalCount";
+            docs: ["The number of proposals that are currently trading."];
+            type: "u32";
+          },
+          {
+            name: "maxConcurrentProposals";
+            docs: [
+              "To keep proposals from splitting liquidity and attention, a DAO can cap",
+              "how many proposals can trade at once, both in total and per proposer.",
+              "0 means no limit."
+            ];
+            type: "u32";
+          },
+          {
+            name: "maxConcurrentProposalsPerProposer";
+            type: "u32";
+          },
+          {
+            name: "proposalConflictMode";
+            type: {
+              defined: "ProposalConflictMode";
+            };
+          },
+          {
+            name: "keeperRewardLamports";
+            docs: [
+              "Keepers who crank a proposal's TWAPs, finalize it, or execute it get",
+              "paid this many lamports from the DAO's keeper pool, a system account at",
+              'the PDA [b"keeper_pool", dao]. Anyone can fund the pool by transferring',
+              "SOL to it. To keep keepers from farming the pool, at most one reward is",
+              "paid per slot. 0 disables rewards."
+            ];
+            type: "u64";
+          },
+          {
+            name: "lastKeeperRewardSlot";
+            type: "u64";
+          },
+          {
+            name: "decisionRule";
+            type: {
+              defined: "DecisionRule";
+            };
+          },
+          {
+            name: "minQuoteVolume";
+            docs: [
+              "A proposal can only pass if both of its markets have traded at least",
+              "this much quote volume while it was trading. Otherwise it fails by default, so that a",
+              "proposal nobody traded can't pass on drifting observations. 0 disables",
+              "the check."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "proposal";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "number";
+            type: "u32";
+          },
+          {
+            name: "proposer";
+            type: "publicKey";
+          },
+          {
+            name: "descriptionUrl";
+            type: "string";
+          },
+          {
+            name: "slotEnqueued";
+            type: "u64";
+          },
+          {
+            name: "state";
+            type: {
+              defined: "ProposalState";
+            };
+          },
+          {
+            name: "instruction";
+            type: {
+              defined: "ProposalInstruction";
+            };
+          },
+          {
+            name: "passAmm";
+            type: "publicKey";
+          },
+          {
+            name: "failAmm";
+            type: "publicKey";
+          },
+          {
+            name: "baseVault";
+            type: "publicKey";
+          },
+          {
+            name: "quoteVault";
+            type: "publicKey";
+          },
+          {
+            name: "dao";
+            type: "publicKey";
+          },
+          {
+            name: "passLpTokensLocked";
+            type: "u64";
+          },
+          {
+            name: "failLpTokensLocked";
+            type: "u64";
+          },
+          {
+            name: "nonce";
+            docs: [
+              "We need to include a per-proposer nonce to prevent some weird proposal",
+              "front-running edge cases. Using a `u64` means that proposers are unlikely",
+              "to run into collisions, even if they generate nonces randomly - I've run",
+              "the math :D"
+            ];
+            type: "u64";
+          },
+          {
+            name: "pdaBump";
+            type: "u8";
+          },
+          {
+            name: "question";
+            type: "publicKey";
+          },
+          {
+            name: "countedInProposerRecord";
+            docs: [
+              "Whether this proposal was counted in the proposer's `ProposerRecord`,",
+              "so that finalizing it knows whether to uncount it."
+            ];
+            type: "bool";
+          },
+          {
+            name: "writableAccounts";
+            docs: [
+              "The accounts `instruction` writes to, used to detect conflicting",
+              "proposals."
+            ];
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "title";
+            type: "string";
+          },
+          {
+            name: "contentHash";
+            docs: [
+              "A hash of the proposal's description (e.g. the SHA-256 of its markdown),",
+              "so that indexers can check the description at `description_url` hasn't",
+              "been edited since the markets opened."
+            ];
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "category";
+            type: {
+              defined: "ProposalCategory";
+            };
+          },
+          {
+            name: "spotTwapSnapshot";
+            docs: [
+              "Set when the DAO's decision rule compares against a spot market, so",
+              "that we can compute the spot TWAP over the proposal's lifetime."
+            ];
+            type: {
+              option: {
+                defined: "TwapSnapshot";
+              };
+            };
+          },
+          {
+            name: "passQuoteVolumeAtStart";
+            docs: [
+              "The markets' cumulative quote volume when the proposal was initialized.",
+              "The DAO's `min_quote_volume` applies to the volume traded after this,",
+              "so that trades made before the proposal existed don't count."
+            ];
+            type: "u128";
+          },
+          {
+            name: "failQuoteVolumeAtStart";
+            type: "u128";
+          },
+          {
+            name: "passPosition";
+            docs: [
+              "AMM positions the proposer locked on top of their LP tokens, which",
+              "are unlocked when the proposal is finalized"
+            ];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "failPosition";
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "proposerRecord";
+      docs: [
+        "Tracks how many proposals a proposer has trading in a DAO, so that the DAO",
+        "can enforce `max_concurrent_proposals_per_proposer`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "dao";
+            type: "publicKey";
+          },
+          {
+            name: "proposer";
+            type: "publicKey";
+          },
+          {
+            name: "activeProposalCount";
+            type: "u32";
+          },
+          {
+            name: "pdaBump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "stream";
+      docs: [
+        "A stream pays `total_amount` of a token from a DAO's treasury to a",
+        "recipient, vesting linearly between `start_timestamp` and `end_timestamp`.",
+        "",
+        "Streams are created by a proposal (the treasury must sign), so that a DAO",
+        "can pay a contributor over time without needing a lump-sum transfer or a",
+        "new proposal for every payment. A later proposal can cancel the stream,",
+        "which sends the unvested tokens back to the treasury. Tokens that had",
+        "already vested at cancellation can still be withdrawn by the recipient."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "dao";
+            type: "publicKey";
+          },
+          {
+            name: "recipient";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "totalAmount";
+            type: "u64";
+          },
+          {
+            name: "withdrawnAmount";
+            type: "u64";
+          },
+          {
+            name: "startTimestamp";
+            type: "i64";
+          },
+          {
+            name: "endTimestamp";
+            type: "i64";
+          },
+          {
+            name: "cancelledTimestamp";
+            docs: [
+              "Set when a proposal cancels the stream. Nothing vests after this time."
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          },
+          {
+            name: "pdaBump";
+            type: "u8";
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "treasuryOrder";
+      docs: [
+        "A treasury order buys or sells a DAO's token against an AMM in slices",
+        "between `start_slot` and `end_slot`, so that a DAO can do a buyback or a",
+        "token sale without executing one large market order.",
+        "",
+        "Orders are created by a proposal, which moves the input tokens from the",
+        "treasury into the order. From then on, anyone can crank the order. Each",
+        "crank swaps whatever input has been released by the schedule, up to",
+        "`max_input_per_slice`, and sends the output to the treasury. A later",
+        "proposal can cancel the order to get back the input that hasn't been",
+        "swapped."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "dao";
+            type: "publicKey";
+          },
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "swapType";
+            docs: [
+              "`Buy` spends quote tokens to buy back the DAO's token, `Sell` sells the",
+              "DAO's token for quote tokens."
+            ];
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "totalInputAmount";
+            type: "u64";
+          },
+          {
+            name: "inputAmountSpent";
+            type: "u64";
+          },
+          {
+            name: "outputAmountReceived";
+            type: "u64";
+          },
+          {
+            name: "startSlot";
+            type: "u64";
+          },
+          {
+            name: "endSlot";
+            type: "u64";
+          },
+          {
+            name: "maxInputPerSlice";
+            type: "u64";
+          },
+          {
+            name: "limitPrice";
+            docs: [
+              "The worst price the order will trade at, in the same units as AMM",
+              "prices. For buys this is a maximum and for sells it's a minimum."
+            ];
+            type: {
+              option: "u128";
+            };
+          },
+          {
+            name: "lastSliceSlot";
+            type: "u64";
+          },
+          {
+            name: "cancelled";
+            type: "bool";
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          },
+          {
+            name: "pdaBump";
+            type: "u8";
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "CommonFields";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "slot";
+            type: "u64";
+          },
+          {
+            name: "unixTimestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "InitializeDaoParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "twapInitialObservation";
+            type: "u128";
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate";
+            type: "u128";
+          },
+          {
+            name: "minQuoteFutarchicLiquidity";
+            type: "u64";
+          },
+          {
+            name: "minBaseFutarchicLiquidity";
+            type: "u64";
+          },
+          {
+            name: "passThresholdBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "slotsPerProposal";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "InitializeProposalParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "descriptionUrl";
+            type: "string";
+          },
+          {
+            name: "title";
+            type: "string";
+          },
+          {
+            name: "contentHash";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "category";
+            type: {
+              defined: "ProposalCategory";
+            };
+          },
+          {
+            name: "instruction";
+            type: {
+              defined: "ProposalInstruction";
+            };
+          },
+          {
+            name: "passLpTokensToLock";
+            type: "u64";
+          },
+          {
+            name: "failLpTokensToLock";
+            type: "u64";
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "InitializeStreamParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "recipient";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "startTimestamp";
+            type: "i64";
+          },
+          {
+            name: "endTimestamp";
+            type: "i64";
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "InitializeTreasuryOrderParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "startSlot";
+            type: "u64";
+          },
+          {
+            name: "endSlot";
+            type: "u64";
+          },
+          {
+            name: "maxInputPerSlice";
+            type: "u64";
+          },
+          {
+            name: "limitPrice";
+            type: {
+              option: "u128";
+            };
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "UpdateDaoSettingsParams";
+      docs: [
+        "Settings that were added after `update_dao`'s params were fixed. They're",
+        "updated separately so that existing `update_dao` proposals keep working."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "delegatedProposerCooldownSlots";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "delegatedProposerMinQuoteFutarchicLiquidity";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "delegatedProposerMinBaseFutarchicLiquidity";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxConcurrentProposals";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "maxConcurrentProposalsPerProposer";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "proposalConflictMode";
+            type: {
+              option: {
+                defined: "ProposalConflictMode";
+              };
+            };
+          },
+          {
+            name: "keeperRewardLamports";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "decisionRule";
+            type: {
+              option: {
+                defined: "DecisionRule";
+              };
+            };
+          },
+          {
+            name: "minQuoteVolume";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "delegatedProposersToAdd";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "delegatedProposersToRemove";
+            type: {
+              vec: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "UpdateDaoParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "passThresholdBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "slotsPerProposal";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "twapInitialObservation";
+            type: {
+              option: "u128";
+            };
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate";
+            type: {
+              option: "u128";
+            };
+          },
+          {
+            name: "minQuoteFutarchicLiquidity";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "minBaseFutarchicLiquidity";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "UpgradeProgramParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bufferHash";
+            docs: [
+              "The SHA-256 hash of the program's ELF, i.e. of the buffer's data after",
+              "its metadata. Because this is stored in the proposal's instruction, it's",
+              "pinned when the proposal is created."
+            ];
+            type: {
+              array: ["u8", 32];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "DelegatedProposer";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pubkey";
+            type: "publicKey";
+          },
+          {
+            name: "lastSlotProposed";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "TwapSnapshot";
+      docs: [
+        "The state of a spot market's TWAP oracle at some point, so that we can",
+        "compute the TWAP between then and a later reading."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "spotMarket";
+            type: {
+              defined: "SpotMarket";
+            };
           },
           {
-            name: "proposer";
+            name: "aggregator";
+            type: "u128";
+          },
+          {
+            name: "time";
+            docs: ["A slot for AMMs, or a unix timestamp for Raydium pools"];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "ProposalAccount";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pubkey";
             type: "publicKey";
           },
           {
-            name: "descriptionUrl";
-            type: "string";
+            name: "isSigner";
+            type: "bool";
           },
           {
-            name: "slotEnqueued";
-            type: "u64";
+            name: "isWritable";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "ProposalInstruction";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "programId";
+            type: "publicKey";
           },
           {
-            name: "state";
+            name: "accounts";
             type: {
-              defined: "ProposalState";
+              vec: {
+                defined: "ProposalAccount";
+              };
             };
           },
           {
-            name: "instruction";
-            type: {
-              defined: "ProposalInstruction";
-            };
+            name: "data";
+            type: "bytes";
+          }
+        ];
+      };
+    },
+    {
+      name: "ProposalConflictMode";
+      docs: [
+        "What to do when a new proposal writes to an account that a trading proposal",
+        "also writes to. Both could pass and execute, with outcomes that only made",
+        "sense in isolation."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Ignore";
           },
           {
-            name: "passAmm";
-            type: "publicKey";
+            name: "Flag";
           },
           {
-            name: "failAmm";
-            type: "publicKey";
+            name: "Reject";
+          }
+        ];
+      };
+    },
+    {
+      name: "DecisionRule";
+      docs: ["How a proposal's markets decide whether it passes."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "PassOverFail";
           },
           {
-            name: "baseVault";
-            type: "publicKey";
+            name: "PassOverFailAndSpot";
+            fields: [
+              {
+                name: "spotMarket";
+                type: {
+                  defined: "SpotMarket";
+                };
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "SpotMarket";
+      docs: [
+        "A market that trades the DAO's token against its USDC outside of",
+        "proposals."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Amm";
+            fields: [
+              {
+                name: "amm";
+                type: "publicKey";
+              }
+            ];
           },
           {
-            name: "quoteVault";
-            type: "publicKey";
+            name: "Raydium";
+            fields: [
+              {
+                name: "observationState";
+                type: "publicKey";
+              },
+              {
+                name: "tokenMintIsToken0";
+                type: "bool";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "ProposalState";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Pending";
           },
           {
-            name: "dao";
-            type: "publicKey";
+            name: "Passed";
           },
           {
-            name: "passLpTokensLocked";
-            type: "u64";
+            name: "Failed";
           },
           {
-            name: "failLpTokensLocked";
-            type: "u64";
+            name: "Executed";
+          }
+        ];
+      };
+    },
+    {
+      name: "ProposalCategory";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "General";
           },
           {
-            name: "nonce";
-            docs: [
-              "We need to include a per-proposer nonce to prevent some weird proposal",
-              "front-running edge cases. Using a `u64` means that proposers are unlikely",
-              "to run into collisions, even if they generate nonces randomly - I've run",
-              "the math :D"
-            ];
-            type: "u64";
+            name: "Treasury";
+          },
+          {
+            name: "Governance";
+          },
+          {
+            name: "ProgramUpgrade";
+          }
+        ];
+      };
+    }
+  ];
+  events: [
+    {
+      name: "InitializeDaoEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "usdcMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "treasury";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "passThresholdBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "slotsPerProposal";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "twapInitialObservation";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "twapMaxObservationChangePerUpdate";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "minQuoteFutarchicLiquidity";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minBaseFutarchicLiquidity";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "UpdateDaoEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "passThresholdBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "slotsPerProposal";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "twapInitialObservation";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "twapMaxObservationChangePerUpdate";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "minQuoteFutarchicLiquidity";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minBaseFutarchicLiquidity";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "delegatedProposers";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "delegatedProposerCooldownSlots";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "delegatedProposerMinQuoteFutarchicLiquidity";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "delegatedProposerMinBaseFutarchicLiquidity";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "maxConcurrentProposals";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "maxConcurrentProposalsPerProposer";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "proposalConflictMode";
+          type: {
+            defined: "ProposalConflictMode";
+          };
+          index: false;
+        },
+        {
+          name: "keeperRewardLamports";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "decisionRule";
+          type: {
+            defined: "DecisionRule";
+          };
+          index: false;
+        },
+        {
+          name: "minQuoteVolume";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MigrateDaoEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "payer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "seqNum";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "InitializeProposalEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "question";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "quoteVault";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "baseVault";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "passAmm";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "failAmm";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "passLpMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "failLpMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "proposer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "nonce";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "number";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "passLpTokensLocked";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "failLpTokensLocked";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "pdaBump";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "instruction";
+          type: {
+            defined: "ProposalInstruction";
+          };
+          index: false;
+        },
+        {
+          name: "descriptionUrl";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "title";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "contentHash";
+          type: {
+            array: ["u8", 32];
+          };
+          index: false;
+        },
+        {
+          name: "category";
+          type: {
+            defined: "ProposalCategory";
+          };
+          index: false;
+        },
+        {
+          name: "isDelegatedProposer";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "conflictingProposals";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "passPosition";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "failPosition";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FinalizeProposalEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "passMarketTwap";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "failMarketTwap";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "threshold";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "spotMarketTwap";
+          type: {
+            option: "u128";
+          };
+          index: false;
+        },
+        {
+          name: "spotThreshold";
+          type: {
+            option: "u128";
+          };
+          index: false;
+        },
+        {
+          name: "passMarketQuoteVolume";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "failMarketQuoteVolume";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "passMarketPausedSlots";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "failMarketPausedSlots";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "state";
+          type: {
+            defined: "ProposalState";
+          };
+          index: false;
+        },
+        {
+          name: "keeper";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "keeperReward";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ExecuteProposalEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "keeper";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "keeperReward";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CrankProposalTwapsEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "keeper";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "keeperReward";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CloseProposalEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "proposer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "state";
+          type: {
+            defined: "ProposalState";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "InitializeStreamEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "stream";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "startTimestamp";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "endTimestamp";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "nonce";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WithdrawFromStreamEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "stream";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "totalWithdrawn";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "seqNum";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CancelStreamEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "stream";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "unvestedAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "seqNum";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "UpgradeProgramEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "program";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "buffer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "bufferHash";
+          type: {
+            array: ["u8", 32];
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "InitializeTreasuryOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "order";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amm";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "startSlot";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "endSlot";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "maxInputPerSlice";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "limitPrice";
+          type: {
+            option: "u128";
+          };
+          index: false;
+        },
+        {
+          name: "nonce";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CrankTreasuryOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "order";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "inputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "outputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "totalInputSpent";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "totalOutputReceived";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "seqNum";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CancelTreasuryOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "order";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "dao";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "unspentInputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "seqNum";
+          type: "u64";
+          index: false;
+        }
+      ];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "AmmTooOld";
+      msg: "Amms must have been created within 5 minutes (counted in slots) of proposal initialization";
+    },
+    {
+      code: 6001;
+      name: "InvalidInitialObservation";
+      msg: "An amm has an `initial_observation` that doesn't match the `dao`'s config";
+    },
+    {
+      code: 6002;
+      name: "InvalidMaxObservationChange";
+      msg: "An amm has a `max_observation_change_per_update` that doesn't match the `dao`'s config";
+    },
+    {
+      code: 6003;
+      name: "InvalidSettlementAuthority";
+      msg: "One of the vaults has an invalid `settlement_authority`";
+    },
+    {
+      code: 6004;
+      name: "ProposalTooYoung";
+      msg: "Proposal is too young to be executed or rejected";
+    },
+    {
+      code: 6005;
+      name: "MarketsTooYoung";
+      msg: "Markets too young for proposal to be finalized. TWAP might need to be cranked";
+    },
+    {
+      code: 6006;
+      name: "ProposalAlreadyFinalized";
+      msg: "This proposal has already been finalized";
+    },
+    {
+      code: 6007;
+      name: "InvalidVaultNonce";
+      msg: "A conditional vault has an invalid nonce. A nonce should encode the proposal number";
+    },
+    {
+      code: 6008;
+      name: "ProposalNotPassed";
+      msg: "This proposal can't be executed because it isn't in the passed state";
+    },
+    {
+      code: 6009;
+      name: "InsufficientLpTokenBalance";
+      msg: "The proposer has fewer pass or fail LP tokens than they requested to lock";
+    },
+    {
+      code: 6010;
+      name: "InsufficientLpTokenLock";
+      msg: "The LP tokens passed in have less liquidity than the DAO's `min_quote_futarchic_liquidity` or `min_base_futachic_liquidity`";
+    },
+    {
+      code: 6011;
+      name: "StreamAmountZero";
+      msg: "A stream must pay out a non-zero amount";
+    },
+    {
+      code: 6012;
+      name: "InvalidStreamDuration";
+      msg: "A stream's `end_timestamp` must be after its `start_timestamp`";
+    },
+    {
+      code: 6013;
+      name: "InsufficientTreasuryBalance";
+      msg: "The treasury doesn't have enough tokens to do this";
+    },
+    {
+      code: 6014;
+      name: "NothingToWithdraw";
+      msg: "Nothing has vested in this stream since the last withdrawal";
+    },
+    {
+      code: 6015;
+      name: "StreamAlreadyCancelled";
+      msg: "This stream has already been cancelled";
+    },
+    {
+      code: 6016;
+      name: "InvalidUpgradeAuthority";
+      msg: "The program's or buffer's upgrade authority isn't the DAO's treasury";
+    },
+    {
+      code: 6017;
+      name: "InvalidBuffer";
+      msg: "The buffer account isn't an initialized BPF loader buffer";
+    },
+    {
+      code: 6018;
+      name: "BufferHashMismatch";
+      msg: "The buffer's contents don't match the hash in the proposal";
+    },
+    {
+      code: 6019;
+      name: "OrderAmountZero";
+      msg: "A treasury order must swap a non-zero amount in non-zero slices";
+    },
+    {
+      code: 6020;
+      name: "InvalidOrderDuration";
+      msg: "A treasury order's `end_slot` must be after its `start_slot`";
+    },
+    {
+      code: 6021;
+      name: "InvalidOrderAmm";
+      msg: "Treasury orders can only trade the DAO's token against its USDC";
+    },
+    {
+      code: 6022;
+      name: "InvalidOrderInputAccount";
+      msg: "The treasury token account doesn't hold the order's input token";
+    },
+    {
+      code: 6023;
+      name: "InvalidOrderOutputAccount";
+      msg: "The treasury token account doesn't hold the order's output token";
+    },
+    {
+      code: 6024;
+      name: "InvalidLimitPrice";
+      msg: "The limit price would make the order's minimum output overflow or divide by zero";
+    },
+    {
+      code: 6025;
+      name: "OrderCancelled";
+      msg: "This treasury order has been cancelled";
+    },
+    {
+      code: 6026;
+      name: "OrderAlreadyCrankedThisSlot";
+      msg: "This treasury order has already been cranked this slot";
+    },
+    {
+      code: 6027;
+      name: "NothingToCrank";
+      msg: "No input has been released since this order was last cranked";
+    },
+    {
+      code: 6028;
+      name: "TooManyDelegatedProposers";
+      msg: "A DAO can't have more than `MAX_DELEGATED_PROPOSERS` delegated proposers";
+    },
+    {
+      code: 6029;
+      name: "DelegatedProposerCooldown";
+      msg: "This delegated proposer has to wait for its cooldown to pass before proposing again";
+    },
+    {
+      code: 6030;
+      name: "InvalidDao";
+      msg: "This account isn't a DAO";
+    },
+    {
+      code: 6031;
+      name: "DaoAlreadyMigrated";
+      msg: "This DAO has already been migrated";
+    },
+    {
+      code: 6032;
+      name: "TooManyActiveProposals";
+      msg: "This DAO already has `max_concurrent_proposals` proposals trading";
+    },
+    {
+      code: 6033;
+      name: "TooManyActiveProposalsForProposer";
+      msg: "This proposer already has `max_concurrent_proposals_per_proposer` proposals trading";
+    },
+    {
+      code: 6034;
+      name: "MissingProposerRecord";
+      msg: "The proposer's record must be passed in to count their trading proposals";
+    },
+    {
+      code: 6035;
+      name: "MissingPendingProposals";
+      msg: "To check for conflicts, every trading proposal of the DAO must be passed in as a remaining account";
+    },
+    {
+      code: 6036;
+      name: "InvalidPendingProposal";
+      msg: "A remaining account isn't a distinct trading proposal of this DAO";
+    },
+    {
+      code: 6037;
+      name: "ConflictingProposal";
+      msg: "This proposal writes to an account that a trading proposal also writes to";
+    },
+    {
+      code: 6038;
+      name: "TitleTooLong";
+      msg: "A proposal's title can't be longer than `MAX_TITLE_LENGTH` bytes";
+    },
+    {
+      code: 6039;
+      name: "DescriptionUrlTooLong";
+      msg: "A proposal's `description_url` can't be longer than `MAX_DESCRIPTION_URL_LENGTH` bytes";
+    },
+    {
+      code: 6040;
+      name: "ProposalNotResolved";
+      msg: "Only executed or failed proposals can be closed";
+    },
+    {
+      code: 6041;
+      name: "InvalidSpotMarket";
+      msg: "The spot market passed in isn't the one in the DAO's decision rule";
+    },
+    {
+      code: 6042;
+      name: "UnsupportedSpotMarket";
+      msg: "The spot market has to be an AMM or a Raydium CPMM pool for the DAO's token and USDC";
+    },
+    {
+      code: 6043;
+      name: "SpotTwapUnavailable";
+      msg: "The spot market's TWAP hasn't been updated since the proposal was created";
+    },
+    {
+      code: 6044;
+      name: "InvalidMarketAdmin";
+      msg: "The pass and fail markets can only be pausable by the DAO's treasury";
+    },
+    {
+      code: 6045;
+      name: "MarketPaused";
+      msg: "The pass and fail markets can't be paused when a proposal is created";
+    },
+    {
+      code: 6046;
+      name: "InvalidPosition";
+      msg: "A position passed in isn't the proposer's or isn't in the right market";
+    }
+  ];
+};
+
+export const IDL: Autocrat = {
+  version: "0.4.0",
+  name: "autocrat",
+  instructions: [
+    {
+      name: "initializeDao",
+      accounts: [
+        {
+          name: "dao",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "usdcMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "InitializeDaoParams",
           },
-          {
-            name: "pdaBump";
-            type: "u8";
+        },
+      ],
+    },
+    {
+      name: "initializeProposal",
+      accounts: [
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "dao",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposerRecord",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only needed if the DAO limits how many proposals each proposer can",
+            "have trading, so that other proposers don't pay rent for one",
+          ],
+        },
+        {
+          name: "question",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteVault",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "baseVault",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "passAmm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "passLpMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "failLpMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "failAmm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "spotAmm",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only needed if the DAO's decision rule compares against a spot AMM",
+          ],
+        },
+        {
+          name: "spotRaydiumObservation",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Raydium pool, and checked against the rule in `validate`"],
+        },
+        {
+          name: "passLpUserAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "failLpUserAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "passLpVaultAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "failLpVaultAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "passPosition",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "A position to lock on top of `pass_lp_tokens_to_lock`. Its liquidity",
+            "counts towards the DAO's minimum.",
+          ],
+        },
+        {
+          name: "failPosition",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "proposer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ammProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ammEventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "InitializeProposalParams",
           },
-          {
-            name: "question";
-            type: "publicKey";
-          }
-        ];
-      };
-    }
-  ];
-  types: [
+        },
+      ],
+    },
+    {
+      name: "finalizeProposal",
+      accounts: [
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "passAmm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "failAmm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "spotAmm",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only needed if the proposal was created under a decision rule that",
+            "compares against a spot AMM",
+          ],
+        },
+        {
+          name: "spotRaydiumObservation",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "that compares against a Raydium pool, and checked against the",
+            "proposal's snapshot",
+          ],
+        },
+        {
+          name: "dao",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposerRecord",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only needed if the proposal was counted in the proposer's record",
+          ],
+        },
+        {
+          name: "question",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "passLpUserAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "failLpUserAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "passLpVaultAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "failLpVaultAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "passPosition",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Only needed if the proposer locked positions"],
+        },
+        {
+          name: "failPosition",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultEventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ammProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ammEventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "keeperPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "keeper",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
     {
-      name: "CommonFields";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "slot";
-            type: "u64";
-          },
-          {
-            name: "unixTimestamp";
-            type: "i64";
-          }
-        ];
-      };
+      name: "executeProposal",
+      accounts: [
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "dao",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "keeperPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "keeper",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      name: "InitializeDaoParams";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "twapInitialObservation";
-            type: "u128";
-          },
-          {
-            name: "twapMaxObservationChangePerUpdate";
-            type: "u128";
-          },
-          {
-            name: "minQuoteFutarchicLiquidity";
-            type: "u64";
-          },
-          {
-            name: "minBaseFutarchicLiquidity";
-            type: "u64";
+      name: "closeProposal",
+      accounts: [
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "crankProposalTwaps",
+      accounts: [
+        {
+          name: "proposal",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "dao",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "passAmm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "failAmm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "keeperPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "keeper",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "ammProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ammEventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "updateDao",
+      accounts: [
+        {
+          name: "dao",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "daoParams",
+          type: {
+            defined: "UpdateDaoParams",
           },
-          {
-            name: "passThresholdBps";
-            type: {
-              option: "u16";
-            };
+        },
+      ],
+    },
+    {
+      name: "updateDaoSettings",
+      accounts: [
+        {
+          name: "dao",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "spotAmm",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only needed if the decision rule is being set to compare against a",
+            "spot AMM",
+          ],
+        },
+        {
+          name: "spotRaydiumPool",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: ["against a Raydium pool, and parsed in `validate`"],
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "UpdateDaoSettingsParams",
           },
-          {
-            name: "slotsPerProposal";
-            type: {
-              option: "u64";
-            };
-          }
-        ];
-      };
+        },
+      ],
     },
     {
-      name: "InitializeProposalParams";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "descriptionUrl";
-            type: "string";
-          },
-          {
-            name: "instruction";
-            type: {
-              defined: "ProposalInstruction";
-            };
-          },
-          {
-            name: "passLpTokensToLock";
-            type: "u64";
-          },
-          {
-            name: "failLpTokensToLock";
-            type: "u64";
-          },
-          {
-            name: "nonce";
-            type: "u64";
-          }
-        ];
-      };
+      name: "migrateDao",
+      accounts: [
+        {
+          name: "dao",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "deserialize as a `Dao`, so we check it by hand in `validate`",
+          ],
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      name: "UpdateDaoParams";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "passThresholdBps";
-            type: {
-              option: "u16";
-            };
-          },
-          {
-            name: "slotsPerProposal";
-            type: {
-              option: "u64";
-            };
-          },
-          {
-            name: "twapInitialObservation";
-            type: {
-              option: "u128";
-            };
-          },
-          {
-            name: "twapMaxObservationChangePerUpdate";
-            type: {
-              option: "u128";
-            };
-          },
-          {
-            name: "minQuoteFutarchicLiquidity";
-            type: {
-              option: "u64";
-            };
+      name: "initializeStream",
+      accounts: [
+        {
+          name: "stream",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "dao",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasuryTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "streamTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "InitializeStreamParams",
           },
-          {
-            name: "minBaseFutarchicLiquidity";
-            type: {
-              option: "u64";
-            };
-          }
-        ];
-      };
+        },
+      ],
     },
     {
-      name: "ProposalAccount";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "pubkey";
-            type: "publicKey";
-          },
-          {
-            name: "isSigner";
-            type: "bool";
-          },
-          {
-            name: "isWritable";
-            type: "bool";
-          }
-        ];
-      };
+      name: "withdrawFromStream",
+      accounts: [
+        {
+          name: "stream",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipient",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "streamTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      name: "ProposalInstruction";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "programId";
-            type: "publicKey";
-          },
-          {
-            name: "accounts";
-            type: {
-              vec: {
-                defined: "ProposalAccount";
-              };
-            };
-          },
-          {
-            name: "data";
-            type: "bytes";
-          }
-        ];
-      };
+      name: "cancelStream",
+      accounts: [
+        {
+          name: "stream",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "dao",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "streamTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      name: "ProposalState";
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "Pending";
-          },
-          {
-            name: "Passed";
-          },
-          {
-            name: "Failed";
-          },
-          {
-            name: "Executed";
-          }
-        ];
-      };
-    }
-  ];
-  events: [
-    {
-      name: "InitializeDaoEvent";
-      fields: [
+      name: "upgradeProgram",
+      accounts: [
         {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
+          name: "dao",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "dao";
-          type: "publicKey";
-          index: false;
+          name: "treasury",
+          isMut: false,
+          isSigner: true,
         },
         {
-          name: "tokenMint";
-          type: "publicKey";
-          index: false;
+          name: "targetProgram",
+          isMut: true,
+          isSigner: false,
+          docs: ["`program_data` belongs to"],
         },
         {
-          name: "usdcMint";
-          type: "publicKey";
-          index: false;
+          name: "programData",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "treasury";
-          type: "publicKey";
-          index: false;
+          name: "buffer",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "passThresholdBps";
-          type: "u16";
-          index: false;
+          name: "spill",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "slotsPerProposal";
-          type: "u64";
-          index: false;
+          name: "rent",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "twapInitialObservation";
-          type: "u128";
-          index: false;
+          name: "clock",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "twapMaxObservationChangePerUpdate";
-          type: "u128";
-          index: false;
+          name: "bpfLoaderUpgradeableProgram",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "minQuoteFutarchicLiquidity";
-          type: "u64";
-          index: false;
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "minBaseFutarchicLiquidity";
-          type: "u64";
-          index: false;
-        }
-      ];
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "UpgradeProgramParams",
+          },
+        },
+      ],
     },
     {
-      name: "UpdateDaoEvent";
-      fields: [
+      name: "initializeTreasuryOrder",
+      accounts: [
         {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
+          name: "order",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "dao";
-          type: "publicKey";
-          index: false;
+          name: "dao",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "passThresholdBps";
-          type: "u16";
-          index: false;
+          name: "treasury",
+          isMut: false,
+          isSigner: true,
         },
         {
-          name: "slotsPerProposal";
-          type: "u64";
-          index: false;
+          name: "amm",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "twapInitialObservation";
-          type: "u128";
-          index: false;
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "twapMaxObservationChangePerUpdate";
-          type: "u128";
-          index: false;
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "minQuoteFutarchicLiquidity";
-          type: "u64";
-          index: false;
+          name: "orderBaseAccount",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "minBaseFutarchicLiquidity";
-          type: "u64";
-          index: false;
-        }
-      ];
+          name: "orderQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryInputAccount",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Holds the order's input, so it's a quote account for buys and a base",
+            "account for sells",
+          ],
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "InitializeTreasuryOrderParams",
+          },
+        },
+      ],
     },
     {
-      name: "InitializeProposalEvent";
-      fields: [
+      name: "crankTreasuryOrder",
+      accounts: [
         {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
+          name: "order",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "proposal";
-          type: "publicKey";
-          index: false;
+          name: "dao",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "dao";
-          type: "publicKey";
-          index: false;
+          name: "treasury",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "question";
-          type: "publicKey";
-          index: false;
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+          docs: [
+            "Pays for the order's trader record on the AMM the first time the order",
+            "is cranked, since the order itself can't pay",
+          ],
         },
         {
-          name: "quoteVault";
-          type: "publicKey";
-          index: false;
+          name: "traderRecord",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "baseVault";
-          type: "publicKey";
-          index: false;
+          name: "orderBaseAccount",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "passAmm";
-          type: "publicKey";
-          index: false;
+          name: "orderQuoteAccount",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "failAmm";
-          type: "publicKey";
-          index: false;
+          name: "treasuryOutputAccount",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Receives the order's output, so it's a base account for buys and a",
+            "quote account for sells",
+          ],
         },
         {
-          name: "passLpMint";
-          type: "publicKey";
-          index: false;
+          name: "ammBaseVault",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "failLpMint";
-          type: "publicKey";
-          index: false;
+          name: "ammQuoteVault",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "proposer";
-          type: "publicKey";
-          index: false;
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "nonce";
-          type: "u64";
-          index: false;
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "number";
-          type: "u32";
-          index: false;
+          name: "ammProgram",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "passLpTokensLocked";
-          type: "u64";
-          index: false;
+          name: "ammEventAuthority",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "failLpTokensLocked";
-          type: "u64";
-          index: false;
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "pdaBump";
-          type: "u8";
-          index: false;
+          name: "program",
+          isMut: false,
+          isSigner: false,
         },
-        {
-          name: "instruction";
-          type: {
-            defined: "ProposalInstruction";
-          };
-          index: false;
-        }
-      ];
+      ],
+      args: [],
     },
     {
-      name: "FinalizeProposalEvent";
-      fields: [
+      name: "cancelTreasuryOrder",
+      accounts: [
         {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
+          name: "order",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "proposal";
-          type: "publicKey";
-          index: false;
+          name: "dao",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "dao";
-          type: "publicKey";
-          index: false;
+          name: "treasury",
+          isMut: false,
+          isSigner: true,
         },
         {
-          name: "passMarketTwap";
-          type: "u128";
-          index: false;
+          name: "orderBaseAccount",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "failMarketTwap";
-          type: "u128";
-          index: false;
+          name: "orderQuoteAccount",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "threshold";
-          type: "u128";
-          index: false;
+          name: "treasuryBaseAccount",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "state";
-          type: {
-            defined: "ProposalState";
-          };
-          index: false;
-        }
-      ];
-    },
-    {
-      name: "ExecuteProposalEvent";
-      fields: [
+          name: "treasuryQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
         {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "proposal";
-          type: "publicKey";
-          index: false;
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "dao";
-          type: "publicKey";
-          index: false;
-        }
-      ];
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "AmmTooOld";
-      msg: "Amms must have been created within 5 minutes (counted in slots) of proposal initialization";
-    },
-    {
-      code: 6001;
-      name: "InvalidInitialObservation";
-      msg: "An amm has an `initial_observation` that doesn't match the `dao`'s config";
-    },
-    {
-      code: 6002;
-      name: "InvalidMaxObservationChange";
-      msg: "An amm has a `max_observation_change_per_update` that doesn't match the `dao`'s config";
-    },
-    {
-      code: 6003;
-      name: "InvalidSettlementAuthority";
-      msg: "One of the vaults has an invalid `settlement_authority`";
-    },
-    {
-      code: 6004;
-      name: "ProposalTooYoung";
-      msg: "Proposal is too young to be executed or rejected";
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
+  ],
+  accounts: [
     {
-      code: 6005;
-      name: "MarketsTooYoung";
-      msg: "Markets too young for proposal to be finalized. TWAP might need to be cranked";
+      name: "dao",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "treasuryPdaBump",
+            type: "u8",
+          },
+          {
+            name: "treasury",
+            type: "publicKey",
+          },
+          {
+            name: "tokenMint",
+            type: "publicKey",
+          },
+          {
+            name: "usdcMint",
+            type: "publicKey",
+          },
+          {
+            name: "proposalCount",
+            type: "u32",
+          },
+          {
+            name: "passThresholdBps",
+            type: "u16",
+          },
+          {
+            name: "slotsPerProposal",
+            type: "u64",
+          },
+          {
+            name: "twapInitialObservation",
+            docs: [
+              "For manipulation-resistance the TWAP is a time-weighted average observation,",
+              "where observation tries to approximate price but can only move by",
+              "`twap_max_observation_change_per_update` per update. Because it can only move",
+              "a little bit per update, you need to check that it has a good initial observation.",
+              "Otherwise, an attacker could create a very high initial observation in the pass",
+              "market and a very low one in the fail market to force the proposal to pass.",
+              "",
+              "We recommend setting an initial observation around the spot price of the token,",
+              "and max observation change per update around 2% the spot price of the token.",
+              "For example, if the spot price of META is $400, we'd recommend setting an initial",
+              "observation of 400 (converted into the AMM prices) and a max observation change per",
+              "update of 8 (also converted into the AMM prices). Observations can be updated once",
+              "a minute, so 2% allows the proposal market to reach double the spot price or 0",
+              "in 50 minutes.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate",
+            type: "u128",
+          },
+          {
+            name: "minQuoteFutarchicLiquidity",
+            docs: [
+              "As an anti-spam measure and to help liquidity, you need to lock up some liquidity",
+              "in both futarchic markets in order to create a proposal.",
+              "",
+              "For example, for META, we can use a `min_quote_futarchic_liquidity` of",
+              "5000 * 1_000_000 (5000 USDC) and a `min_base_futarchic_liquidity` of",
+              "10 * 1_000_000_000 (10 META).",
+            ],
+            type: "u64",
+          },
+          {
+            name: "minBaseFutarchicLiquidity",
+            type: "u64",
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+          {
+            name: "delegatedProposers",
+            docs: [
+              "Delegated proposers can create proposals with a smaller LP lock than",
+              "everyone else, but only once every `delegated_proposer_cooldown_slots`.",
+              "This lets a DAO hand out proposal rights to trusted contributors who",
+              "don't have much liquidity. Setting the delegated minimums to 0 lets",
+              "them propose without locking any LP at all.",
+            ],
+            type: {
+              vec: {
+                defined: "DelegatedProposer",
+              },
+            },
+          },
+          {
+            name: "delegatedProposerCooldownSlots",
+            type: "u64",
+          },
+          {
+            name: "delegatedProposerMinQuoteFutarchicLiquidity",
+            type: "u64",
+          },
+          {
+            name: "delegatedProposerMinBaseFutarchicLiquidity",
+            type: "u64",
+          },
+          {
+            name: "activeProposalCount",
+            docs: ["The number of proposals that are currently trading."],
+            type: "u32",
+          },
+          {
+            name: "maxConcurrentProposals",
+            docs: [
+              "To keep proposals from splitting liquidity and attention, a DAO can cap",
+              "how many proposals can trade at once, both in total and per proposer.",
+              "0 means no limit.",
+            ],
+            type: "u32",
+          },
+          {
+            name: "maxConcurrentProposalsPerProposer",
+            type: "u32",
+          },
+          {
+            name: "proposalConflictMode",
+            type: {
+              defined: "ProposalConflictMode",
+            },
+          },
+          {
+            name: "keeperRewardLamports",
+            docs: [
+              "Keepers who crank a proposal's TWAPs, finalize it, or execute it get",
+              "paid this many lamports from the DAO's keeper pool, a system account at",
+              'the PDA [b"keeper_pool", dao]. Anyone can fund the pool by transferring',
+              "SOL to it. To keep keepers from farming the pool, at most one reward is",
+              "paid per slot. 0 disables rewards.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "lastKeeperRewardSlot",
+            type: "u64",
+          },
+          {
+            name: "decisionRule",
+            type: {
+              defined: "DecisionRule",
+            },
+          },
+          {
+            name: "minQuoteVolume",
+            docs: [
+              "A proposal can only pass if both of its markets have traded at least",
+              "this much quote volume while it was trading. Otherwise it fails by default, so that a",
+              "proposal nobody traded can't pass on drifting observations. 0 disables",
+              "the check.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6006;
-      name: "ProposalAlreadyFinalized";
-      msg: "This proposal has already been finalized";
+      name: "proposal",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "number",
+            type: "u32",
+          },
+          {
+            name: "proposer",
+            type: "publicKey",
+          },
+          {
+            name: "descriptionUrl",
+            type: "string",
+          },
+          {
+            name: "slotEnqueued",
+            type: "u64",
+          },
+          {
+            name: "state",
+            type: {
+              defined: "ProposalState",
+            },
+          },
+          {
+            name: "instruction",
+            type: {
+              defined: "ProposalInstruction",
+            },
+          },
+          {
+            name: "passAmm",
+            type: "publicKey",
+          },
+          {
+            name: "failAmm",
+            type: "publicKey",
+          },
+          {
+            name: "baseVault",
+            type: "publicKey",
+          },
+          {
+            name: "quoteVault",
+            type: "publicKey",
+          },
+          {
+            name: "dao",
+            type: "publicKey",
+          },
+          {
+            name: "passLpTokensLocked",
+            type: "u64",
+          },
+          {
+            name: "failLpTokensLocked",
+            type: "u64",
+          },
+          {
+            name: "nonce",
+            docs: [
+              "We need to include a per-proposer nonce to prevent some weird proposal",
+              "front-running edge cases. Using a `u64` means that proposers are unlikely",
+              "to run into collisions, even if they generate nonces randomly - I've run",
+              "the math :D",
+            ],
+            type: "u64",
+          },
+          {
+            name: "pdaBump",
+            type: "u8",
+          },
+          {
+            name: "question",
+            type: "publicKey",
+          },
+          {
+            name: "countedInProposerRecord",
+            docs: [
+              "Whether this proposal was counted in the proposer's `ProposerRecord`,",
+              "so that finalizing it knows whether to uncount it.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "writableAccounts",
+            docs: [
+              "The accounts `instruction` writes to, used to detect conflicting",
+              "proposals.",
+            ],
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "title",
+            type: "string",
+          },
+          {
+            name: "contentHash",
+            docs: [
+              "A hash of the proposal's description (e.g. the SHA-256 of its markdown),",
+              "so that indexers can check the description at `description_url` hasn't",
+              "been edited since the markets opened.",
+            ],
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "category",
+            type: {
+              defined: "ProposalCategory",
+            },
+          },
+          {
+            name: "spotTwapSnapshot",
+            docs: [
+              "Set when the DAO's decision rule compares against a spot market, so",
+              "that we can compute the spot TWAP over the proposal's lifetime.",
+            ],
+            type: {
+              option: {
+                defined: "TwapSnapshot",
+              },
+            },
+          },
+          {
+            name: "passQuoteVolumeAtStart",
+            docs: [
+              "The markets' cumulative quote volume when the proposal was initialized.",
+              "The DAO's `min_quote_volume` applies to the volume traded after this,",
+              "so that trades made before the proposal existed don't count.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "failQuoteVolumeAtStart",
+            type: "u128",
+          },
+          {
+            name: "passPosition",
+            docs: [
+              "AMM positions the proposer locked on top of their LP tokens, which",
+              "are unlocked when the proposal is finalized",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "failPosition",
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
     },
     {
-      code: 6007;
-      name: "InvalidVaultNonce";
-      msg: "A conditional vault has an invalid nonce. A nonce should encode the proposal number";
+      name: "proposerRecord",
+      docs: [
+        "Tracks how many proposals a proposer has trading in a DAO, so that the DAO",
+        "can enforce `max_concurrent_proposals_per_proposer`.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "dao",
+            type: "publicKey",
+          },
+          {
+            name: "proposer",
+            type: "publicKey",
+          },
+          {
+            name: "activeProposalCount",
+            type: "u32",
+          },
+          {
+            name: "pdaBump",
+            type: "u8",
+          },
+        ],
+      },
     },
     {
-      code: 6008;
-      name: "ProposalNotPassed";
-      msg: "This proposal can't be executed because it isn't in the passed state";
+      name: "stream",
+      docs: [
+        "A stream pays `total_amount` of a token from a DAO's treasury to a",
+        "recipient, vesting linearly between `start_timestamp` and `end_timestamp`.",
+        "",
+        "Streams are created by a proposal (the treasury must sign), so that a DAO",
+        "can pay a contributor over time without needing a lump-sum transfer or a",
+        "new proposal for every payment. A later proposal can cancel the stream,",
+        "which sends the unvested tokens back to the treasury. Tokens that had",
+        "already vested at cancellation can still be withdrawn by the recipient.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "dao",
+            type: "publicKey",
+          },
+          {
+            name: "recipient",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "totalAmount",
+            type: "u64",
+          },
+          {
+            name: "withdrawnAmount",
+            type: "u64",
+          },
+          {
+            name: "startTimestamp",
+            type: "i64",
+          },
+          {
+            name: "endTimestamp",
+            type: "i64",
+          },
+          {
+            name: "cancelledTimestamp",
+            docs: [
+              "Set when a proposal cancels the stream. Nothing vests after this time.",
+            ],
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "nonce",
+            type: "u64",
+          },
+          {
+            name: "pdaBump",
+            type: "u8",
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      code: 6009;
-      name: "InsufficientLpTokenBalance";
-      msg: "The proposer has fewer pass or fail LP tokens than they requested to lock";
+      name: "treasuryOrder",
+      docs: [
+        "A treasury order buys or sells a DAO's token against an AMM in slices",
+        "between `start_slot` and `end_slot`, so that a DAO can do a buyback or a",
+        "token sale without executing one large market order.",
+        "",
+        "Orders are created by a proposal, which moves the input tokens from the",
+        "treasury into the order. From then on, anyone can crank the order. Each",
+        "crank swaps whatever input has been released by the schedule, up to",
+        "`max_input_per_slice`, and sends the output to the treasury. A later",
+        "proposal can cancel the order to get back the input that hasn't been",
+        "swapped.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "dao",
+            type: "publicKey",
+          },
+          {
+            name: "amm",
+            type: "publicKey",
+          },
+          {
+            name: "swapType",
+            docs: [
+              "`Buy` spends quote tokens to buy back the DAO's token, `Sell` sells the",
+              "DAO's token for quote tokens.",
+            ],
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "totalInputAmount",
+            type: "u64",
+          },
+          {
+            name: "inputAmountSpent",
+            type: "u64",
+          },
+          {
+            name: "outputAmountReceived",
+            type: "u64",
+          },
+          {
+            name: "startSlot",
+            type: "u64",
+          },
+          {
+            name: "endSlot",
+            type: "u64",
+          },
+          {
+            name: "maxInputPerSlice",
+            type: "u64",
+          },
+          {
+            name: "limitPrice",
+            docs: [
+              "The worst price the order will trade at, in the same units as AMM",
+              "prices. For buys this is a maximum and for sells it's a minimum.",
+            ],
+            type: {
+              option: "u128",
+            },
+          },
+          {
+            name: "lastSliceSlot",
+            type: "u64",
+          },
+          {
+            name: "cancelled",
+            type: "bool",
+          },
+          {
+            name: "nonce",
+            type: "u64",
+          },
+          {
+            name: "pdaBump",
+            type: "u8",
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+        ],
+      },
     },
+  ],
+  types: [
     {
-      code: 6010;
-      name: "InsufficientLpTokenLock";
-      msg: "The LP tokens passed in have less liquidity than the DAO's `min_quote_futarchic_liquidity` or `min_base_futachic_liquidity`";
-    }
-  ];
-};
-
-export const IDL: Autocrat = {
-  version: "0.4.0",
-  name: "autocrat",
-  instructions: [
-    {
-      name: "initializeDao",
-      accounts: [
-        {
-          name: "dao",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenMint",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "usdcMint",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "program",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "params",
-          type: {
-            defined: "InitializeDaoParams",
+      name: "CommonFields",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "slot",
+            type: "u64",
           },
-        },
-      ],
+          {
+            name: "unixTimestamp",
+            type: "i64",
+          },
+        ],
+      },
     },
     {
-      name: "initializeProposal",
-      accounts: [
-        {
-          name: "proposal",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "dao",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "question",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "quoteVault",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "baseVault",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "passAmm",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "passLpMint",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "failLpMint",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "failAmm",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "passLpUserAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "failLpUserAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "passLpVaultAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "failLpVaultAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "proposer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "program",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "params",
-          type: {
-            defined: "InitializeProposalParams",
+      name: "InitializeDaoParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "twapInitialObservation",
+            type: "u128",
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate",
+            type: "u128",
+          },
+          {
+            name: "minQuoteFutarchicLiquidity",
+            type: "u64",
+          },
+          {
+            name: "minBaseFutarchicLiquidity",
+            type: "u64",
           },
-        },
-      ],
+          {
+            name: "passThresholdBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "slotsPerProposal",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "finalizeProposal",
-      accounts: [
-        {
-          name: "proposal",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "passAmm",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "failAmm",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "dao",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "question",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "treasury",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "passLpUserAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "failLpUserAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "passLpVaultAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "failLpVaultAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "vaultProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "vaultEventAuthority",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "program",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "InitializeProposalParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "descriptionUrl",
+            type: "string",
+          },
+          {
+            name: "title",
+            type: "string",
+          },
+          {
+            name: "contentHash",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "category",
+            type: {
+              defined: "ProposalCategory",
+            },
+          },
+          {
+            name: "instruction",
+            type: {
+              defined: "ProposalInstruction",
+            },
+          },
+          {
+            name: "passLpTokensToLock",
+            type: "u64",
+          },
+          {
+            name: "failLpTokensToLock",
+            type: "u64",
+          },
+          {
+            name: "nonce",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "executeProposal",
-      accounts: [
-        {
-          name: "proposal",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "dao",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "program",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "InitializeStreamParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "recipient",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "startTimestamp",
+            type: "i64",
+          },
+          {
+            name: "endTimestamp",
+            type: "i64",
+          },
+          {
+            name: "nonce",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "updateDao",
-      accounts: [
-        {
-          name: "dao",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "treasury",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "program",
-          isMut: false,
-          isSigner: false,
-        },
+      name: "InitializeTreasuryOrderParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapType",
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "startSlot",
+            type: "u64",
+          },
+          {
+            name: "endSlot",
+            type: "u64",
+          },
+          {
+            name: "maxInputPerSlice",
+            type: "u64",
+          },
+          {
+            name: "limitPrice",
+            type: {
+              option: "u128",
+            },
+          },
+          {
+            name: "nonce",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "UpdateDaoSettingsParams",
+      docs: [
+        "Settings that were added after `update_dao`'s params were fixed. They're",
+        "updated separately so that existing `update_dao` proposals keep working.",
       ],
-      args: [
-        {
-          name: "daoParams",
-          type: {
-            defined: "UpdateDaoParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "delegatedProposerCooldownSlots",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "delegatedProposerMinQuoteFutarchicLiquidity",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "delegatedProposerMinBaseFutarchicLiquidity",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "maxConcurrentProposals",
+            type: {
+              option: "u32",
+            },
           },
-        },
-      ],
-    },
-  ],
-  accounts: [
-    {
-      name: "dao",
-      type: {
-        kind: "struct",
-        fields: [
           {
-            name: "treasuryPdaBump",
-            type: "u8",
+            name: "maxConcurrentProposalsPerProposer",
+            type: {
+              option: "u32",
+            },
           },
           {
-            name: "treasury",
-            type: "publicKey",
+            name: "proposalConflictMode",
+            type: {
+              option: {
+                defined: "ProposalConflictMode",
+              },
+            },
           },
           {
-            name: "tokenMint",
-            type: "publicKey",
+            name: "keeperRewardLamports",
+            type: {
+              option: "u64",
+            },
           },
           {
-            name: "usdcMint",
-            type: "publicKey",
+            name: "decisionRule",
+            type: {
+              option: {
+                defined: "DecisionRule",
+              },
+            },
           },
           {
-            name: "proposalCount",
-            type: "u32",
+            name: "minQuoteVolume",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "delegatedProposersToAdd",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "delegatedProposersToRemove",
+            type: {
+              vec: "publicKey",
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "UpdateDaoParams",
+      type: {
+        kind: "struct",
+        fields: [
           {
             name: "passThresholdBps",
-            type: "u16",
+            type: {
+              option: "u16",
+            },
           },
           {
             name: "slotsPerProposal",
-            type: "u64",
+            type: {
+              option: "u64",
+            },
           },
           {
             name: "twapInitialObservation",
-            docs: [
-              "For manipulation-resistance the TWAP is a time-weighted average observation,",
-              "where observation tries to approximate price but can only move by",
-              "`twap_max_observation_change_per_update` per update. Because it can only move",
-              "a little bit per update, you need to check that it has a good initial observation.",
-              "Otherwise, an attacker could create a very high initial observation in the pass",
-              "market and a very low one in the fail market to force the proposal to pass.",
-              "",
-              "We recommend setting an initial observation around the spot price of the token,",
-              "and max observation change per update around 2% the spot price of the token.",
-              "For example, if the spot price of META is $400, we'd recommend setting an initial",
-              "observation of 400 (converted into the AMM prices) and a max observation change per",
-              "update of 8 (also converted into the AMM prices). Observations can be updated once",
-              "a minute, so 2% allows the proposal market to reach double the spot price or 0",
-              "in 50 minutes.",
-            ],
-            type: "u128",
+            type: {
+              option: "u128",
+            },
           },
           {
             name: "twapMaxObservationChangePerUpdate",
-            type: "u128",
+            type: {
+              option: "u128",
+            },
           },
           {
             name: "minQuoteFutarchicLiquidity",
-            docs: [
-              "As an anti-spam measure and to help liquidity, you need to lock up some liquidity",
-              "in both futarchic markets in order to create a proposal.",
-              "",
-              "For example, for META, we can use a `min_quote_futarchic_liquidity` of",
-              "5000 * 1_000_000 (5000 USDC) and a `min_base_futarchic_liquidity` of",
-              "10 * 1_000_000_000 (10 META).",
-            ],
-            type: "u64",
+            type: {
+              option: "u64",
+            },
           },
           {
             name: "minBaseFutarchicLiquidity",
-            type: "u64",
-          },
-          {
-            name: "seqNum",
-            type: "u64",
+            type: {
+              option: "u64",
+            },
           },
         ],
       },
     },
     {
-      name: "proposal",
+      name: "UpgradeProgramParams",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "number",
-            type: "u32",
+            name: "bufferHash",
+            docs: [
+              "The SHA-256 hash of the program's ELF, i.e. of the buffer's data after",
+              "its metadata. Because this is stored in the proposal's instruction, it's",
+              "pinned when the proposal is created.",
+            ],
+            type: {
+              array: ["u8", 32],
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "DelegatedProposer",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "proposer",
+            name: "pubkey",
             type: "publicKey",
           },
           {
-            name: "descriptionUrl",
-            type: "string",
-          },
-          {
-            name: "slotEnqueued",
+            name: "lastSlotProposed",
             type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "TwapSnapshot",
+      docs: [
+        "The state of a spot market's TWAP oracle at some point, so that we can",
+        "compute the TWAP between then and a later reading.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "state",
+            name: "spotMarket",
             type: {
-              defined: "ProposalState",
+              defined: "SpotMarket",
             },
           },
           {
-            name: "instruction",
-            type: {
-              defined: "ProposalInstruction",
-            },
+            name: "aggregator",
+            type: "u128",
           },
           {
-            name: "passAmm",
-            type: "publicKey",
+            name: "time",
+            docs: ["A slot for AMMs, or a unix timestamp for Raydium pools"],
+            type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "ProposalAccount",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "failAmm",
+            name: "pubkey",
             type: "publicKey",
           },
           {
-            name: "baseVault",
-            type: "publicKey",
+            name: "isSigner",
+            type: "bool",
           },
           {
-            name: "quoteVault",
-            type: "publicKey",
+            name: "isWritable",
+            type: "bool",
           },
+        ],
+      },
+    },
+    {
+      name: "ProposalInstruction",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "dao",
+            name: "programId",
             type: "publicKey",
           },
           {
-            name: "passLpTokensLocked",
-            type: "u64",
+            name: "accounts",
+            type: {
+              vec: {
+                defined: "ProposalAccount",
+              },
+            },
           },
           {
-            name: "failLpTokensLocked",
-            type: "u64",
+            name: "data",
+            type: "bytes",
           },
+        ],
+      },
+    },
+    {
+      name: "ProposalConflictMode",
+      docs: [
+        "What to do when a new proposal writes to an account that a trading proposal",
+        "also writes to. Both could pass and execute, with outcomes that only made",
+        "sense in isolation.",
+      ],
+      type: {
+        kind: "enum",
+        variants: [
           {
-            name: "nonce",
-            docs: [
-              "We need to include a per-proposer nonce to prevent some weird proposal",
-              "front-running edge cases. Using a `u64` means that proposers are unlikely",
-              "to run into collisions, even if they generate nonces randomly - I've run",
-              "the math :D",
-            ],
-            type: "u64",
+            name: "Ignore",
           },
           {
-            name: "pdaBump",
-            type: "u8",
+            name: "Flag",
           },
           {
-            name: "question",
-            type: "publicKey",
+            name: "Reject",
           },
         ],
       },
     },
-  ],
-  types: [
     {
-      name: "CommonFields",
+      name: "DecisionRule",
+      docs: ["How a proposal's markets decide whether it passes."],
       type: {
-        kind: "struct",
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "slot",
-            type: "u64",
+            name: "PassOverFail",
           },
           {
-            name: "unixTimestamp",
-            type: "i64",
+            name: "PassOverFailAndSpot",
+            fields: [
+              {
+                name: "spotMarket",
+                type: {
+                  defined: "SpotMarket",
+                },
+              },
+            ],
           },
         ],
       },
     },
     {
-      name: "InitializeDaoParams",
+      name: "SpotMarket",
+      docs: [
+        "A market that trades the DAO's token against its USDC outside of",
+        "proposals.",
+      ],
       type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "twapInitialObservation",
-            type: "u128",
-          },
+        kind: "enum",
+        variants: [
           {
-            name: "twapMaxObservationChangePerUpdate",
-            type: "u128",
+            name: "Amm",
+            fields: [
+              {
+                name: "amm",
+                type: "publicKey",
+              },
+            ],
           },
           {
-            name: "minQuoteFutarchicLiquidity",
-            type: "u64",
+            name: "Raydium",
+            fields: [
+              {
+                name: "observationState",
+                type: "publicKey",
+              },
+              {
+                name: "tokenMintIsToken0",
+                type: "bool",
+              },
+            ],
           },
+        ],
+      },
+    },
+    {
+      name: "ProposalState",
+      type: {
+        kind: "enum",
+        variants: [
           {
-            name: "minBaseFutarchicLiquidity",
-            type: "u64",
+            name: "Pending",
           },
           {
-            name: "passThresholdBps",
-            type: {
-              option: "u16",
-            },
+            name: "Passed",
           },
           {
-            name: "slotsPerProposal",
-            type: {
-              option: "u64",
-            },
+            name: "Failed",
+          },
+          {
+            name: "Executed",
           },
         ],
       },
     },
     {
-      name: "InitializeProposalParams",
+      name: "ProposalCategory",
       type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "descriptionUrl",
-            type: "string",
-          },
+        kind: "enum",
+        variants: [
           {
-            name: "instruction",
-            type: {
-              defined: "ProposalInstruction",
-            },
+            name: "General",
           },
           {
-            name: "passLpTokensToLock",
-            type: "u64",
+            name: "Treasury",
           },
           {
-            name: "failLpTokensToLock",
-            type: "u64",
+            name: "Governance",
           },
           {
-            name: "nonce",
-            type: "u64",
+            name: "ProgramUpgrade",
           },
         ],
       },
     },
+  ],
+  events: [
     {
-      name: "UpdateDaoParams",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "passThresholdBps",
-            type: {
-              option: "u16",
-            },
+      name: "InitializeDaoEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "dao",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "usdcMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "treasury",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "passThresholdBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "slotsPerProposal",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "twapInitialObservation",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "twapMaxObservationChangePerUpdate",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "minQuoteFutarchicLiquidity",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "minBaseFutarchicLiquidity",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "UpdateDaoEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "dao",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "passThresholdBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "slotsPerProposal",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "twapInitialObservation",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "twapMaxObservationChangePerUpdate",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "minQuoteFutarchicLiquidity",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "minBaseFutarchicLiquidity",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "delegatedProposers",
+          type: {
+            vec: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "delegatedProposerCooldownSlots",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "delegatedProposerMinQuoteFutarchicLiquidity",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "delegatedProposerMinBaseFutarchicLiquidity",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "maxConcurrentProposals",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "maxConcurrentProposalsPerProposer",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "proposalConflictMode",
+          type: {
+            defined: "ProposalConflictMode",
+          },
+          index: false,
+        },
+        {
+          name: "keeperRewardLamports",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "decisionRule",
+          type: {
+            defined: "DecisionRule",
+          },
+          index: false,
+        },
+        {
+          name: "minQuoteVolume",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "MigrateDaoEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "dao",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "payer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "seqNum",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "InitializeProposalEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "proposal",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "dao",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "question",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "quoteVault",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "baseVault",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "passAmm",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "failAmm",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "passLpMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "failLpMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "proposer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "nonce",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "number",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "passLpTokensLocked",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "failLpTokensLocked",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "pdaBump",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "instruction",
+          type: {
+            defined: "ProposalInstruction",
           },
-          {
-            name: "slotsPerProposal",
-            type: {
-              option: "u64",
-            },
+          index: false,
+        },
+        {
+          name: "descriptionUrl",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "title",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "contentHash",
+          type: {
+            array: ["u8", 32],
           },
-          {
-            name: "twapInitialObservation",
-            type: {
-              option: "u128",
-            },
+          index: false,
+        },
+        {
+          name: "category",
+          type: {
+            defined: "ProposalCategory",
           },
-          {
-            name: "twapMaxObservationChangePerUpdate",
-            type: {
-              option: "u128",
-            },
+          index: false,
+        },
+        {
+          name: "isDelegatedProposer",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "conflictingProposals",
+          type: {
+            vec: "publicKey",
           },
-          {
-            name: "minQuoteFutarchicLiquidity",
-            type: {
-              option: "u64",
-            },
+          index: false,
+        },
+        {
+          name: "passPosition",
+          type: {
+            option: "publicKey",
           },
-          {
-            name: "minBaseFutarchicLiquidity",
-            type: {
-              option: "u64",
-            },
+          index: false,
+        },
+        {
+          name: "failPosition",
+          type: {
+            option: "publicKey",
           },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
     {
-      name: "ProposalAccount",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "pubkey",
-            type: "publicKey",
-          },
-          {
-            name: "isSigner",
-            type: "bool",
-          },
-          {
-            name: "isWritable",
-            type: "bool",
+      name: "FinalizeProposalEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-        ],
-      },
-    },
-    {
-      name: "ProposalInstruction",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "programId",
-            type: "publicKey",
+          index: false,
+        },
+        {
+          name: "proposal",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "dao",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "passMarketTwap",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "failMarketTwap",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "threshold",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "spotMarketTwap",
+          type: {
+            option: "u128",
           },
-          {
-            name: "accounts",
-            type: {
-              vec: {
-                defined: "ProposalAccount",
-              },
-            },
+          index: false,
+        },
+        {
+          name: "spotThreshold",
+          type: {
+            option: "u128",
           },
-          {
-            name: "data",
-            type: "bytes",
+          index: false,
+        },
+        {
+          name: "passMarketQuoteVolume",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "failMarketQuoteVolume",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "passMarketPausedSlots",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "failMarketPausedSlots",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "state",
+          type: {
+            defined: "ProposalState",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "keeper",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "keeperReward",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "ProposalState",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Pending",
-          },
-          {
-            name: "Passed",
-          },
-          {
-            name: "Failed",
-          },
-          {
-            name: "Executed",
+      name: "ExecuteProposalEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "proposal",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "dao",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "keeper",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "keeperReward",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
-  ],
-  events: [
     {
-      name: "InitializeDaoEvent",
+      name: "CrankProposalTwapsEvent",
       fields: [
         {
           name: "common",
@@ -1644,59 +5747,63 @@ export const IDL: Autocrat = {
           index: false,
         },
         {
-          name: "dao",
-          type: "publicKey",
-          index: false,
-        },
-        {
-          name: "tokenMint",
+          name: "proposal",
           type: "publicKey",
           index: false,
         },
         {
-          name: "usdcMint",
+          name: "dao",
           type: "publicKey",
           index: false,
         },
         {
-          name: "treasury",
+          name: "keeper",
           type: "publicKey",
           index: false,
         },
         {
-          name: "passThresholdBps",
-          type: "u16",
+          name: "keeperReward",
+          type: "u64",
           index: false,
         },
+      ],
+    },
+    {
+      name: "CloseProposalEvent",
+      fields: [
         {
-          name: "slotsPerProposal",
-          type: "u64",
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
           index: false,
         },
         {
-          name: "twapInitialObservation",
-          type: "u128",
+          name: "proposal",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "twapMaxObservationChangePerUpdate",
-          type: "u128",
+          name: "dao",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "minQuoteFutarchicLiquidity",
-          type: "u64",
+          name: "proposer",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "minBaseFutarchicLiquidity",
-          type: "u64",
+          name: "state",
+          type: {
+            defined: "ProposalState",
+          },
           index: false,
         },
       ],
     },
     {
-      name: "UpdateDaoEvent",
+      name: "InitializeStreamEvent",
       fields: [
         {
           name: "common",
@@ -1705,45 +5812,87 @@ export const IDL: Autocrat = {
           },
           index: false,
         },
+        {
+          name: "stream",
+          type: "publicKey",
+          index: false,
+        },
         {
           name: "dao",
           type: "publicKey",
           index: false,
         },
         {
-          name: "passThresholdBps",
-          type: "u16",
+          name: "recipient",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "slotsPerProposal",
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
           type: "u64",
           index: false,
         },
         {
-          name: "twapInitialObservation",
-          type: "u128",
+          name: "startTimestamp",
+          type: "i64",
           index: false,
         },
         {
-          name: "twapMaxObservationChangePerUpdate",
-          type: "u128",
+          name: "endTimestamp",
+          type: "i64",
           index: false,
         },
         {
-          name: "minQuoteFutarchicLiquidity",
+          name: "nonce",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WithdrawFromStreamEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "stream",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "recipient",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
           type: "u64",
           index: false,
         },
         {
-          name: "minBaseFutarchicLiquidity",
+          name: "totalWithdrawn",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "seqNum",
           type: "u64",
           index: false,
         },
       ],
     },
     {
-      name: "InitializeProposalEvent",
+      name: "CancelStreamEvent",
       fields: [
         {
           name: "common",
@@ -1753,7 +5902,7 @@ export const IDL: Autocrat = {
           index: false,
         },
         {
-          name: "proposal",
+          name: "stream",
           type: "publicKey",
           index: false,
         },
@@ -1763,81 +5912,119 @@ export const IDL: Autocrat = {
           index: false,
         },
         {
-          name: "question",
-          type: "publicKey",
+          name: "unvestedAmount",
+          type: "u64",
           index: false,
         },
         {
-          name: "quoteVault",
-          type: "publicKey",
+          name: "seqNum",
+          type: "u64",
           index: false,
         },
+      ],
+    },
+    {
+      name: "UpgradeProgramEvent",
+      fields: [
         {
-          name: "baseVault",
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "dao",
           type: "publicKey",
           index: false,
         },
         {
-          name: "passAmm",
+          name: "program",
           type: "publicKey",
           index: false,
         },
         {
-          name: "failAmm",
+          name: "buffer",
           type: "publicKey",
           index: false,
         },
         {
-          name: "passLpMint",
+          name: "bufferHash",
+          type: {
+            array: ["u8", 32],
+          },
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "InitializeTreasuryOrderEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "order",
           type: "publicKey",
           index: false,
         },
         {
-          name: "failLpMint",
+          name: "dao",
           type: "publicKey",
           index: false,
         },
         {
-          name: "proposer",
+          name: "amm",
           type: "publicKey",
           index: false,
         },
         {
-          name: "nonce",
-          type: "u64",
+          name: "swapType",
+          type: {
+            defined: "SwapType",
+          },
           index: false,
         },
         {
-          name: "number",
-          type: "u32",
+          name: "amount",
+          type: "u64",
           index: false,
         },
         {
-          name: "passLpTokensLocked",
+          name: "startSlot",
           type: "u64",
           index: false,
         },
         {
-          name: "failLpTokensLocked",
+          name: "endSlot",
           type: "u64",
           index: false,
         },
         {
-          name: "pdaBump",
-          type: "u8",
+          name: "maxInputPerSlice",
+          type: "u64",
           index: false,
         },
         {
-          name: "instruction",
+          name: "limitPrice",
           type: {
-            defined: "ProposalInstruction",
+            option: "u128",
           },
           index: false,
         },
+        {
+          name: "nonce",
+          type: "u64",
+          index: false,
+        },
       ],
     },
     {
-      name: "FinalizeProposalEvent",
+      name: "CrankTreasuryOrderEvent",
       fields: [
         {
           name: "common",
@@ -1847,7 +6034,7 @@ export const IDL: Autocrat = {
           index: false,
         },
         {
-          name: "proposal",
+          name: "order",
           type: "publicKey",
           index: false,
         },
@@ -1857,31 +6044,34 @@ export const IDL: Autocrat = {
           index: false,
         },
         {
-          name: "passMarketTwap",
-          type: "u128",
+          name: "inputAmount",
+          type: "u64",
           index: false,
         },
         {
-          name: "failMarketTwap",
-          type: "u128",
+          name: "outputAmount",
+          type: "u64",
           index: false,
         },
         {
-          name: "threshold",
-          type: "u128",
+          name: "totalInputSpent",
+          type: "u64",
           index: false,
         },
         {
-          name: "state",
-          type: {
-            defined: "ProposalState",
-          },
+          name: "totalOutputReceived",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "seqNum",
+          type: "u64",
           index: false,
         },
       ],
     },
     {
-      name: "ExecuteProposalEvent",
+      name: "CancelTreasuryOrderEvent",
       fields: [
         {
           name: "common",
@@ -1891,7 +6081,7 @@ export const IDL: Autocrat = {
           index: false,
         },
         {
-          name: "proposal",
+          name: "order",
           type: "publicKey",
           index: false,
         },
@@ -1900,6 +6090,16 @@ export const IDL: Autocrat = {
           type: "publicKey",
           index: false,
         },
+        {
+          name: "unspentInputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "seqNum",
+          type: "u64",
+          index: false,
+        },
       ],
     },
   ],
@@ -1959,5 +6159,185 @@ export const IDL: Autocrat = {
       name: "InsufficientLpTokenLock",
       msg: "The LP tokens passed in have less liquidity than the DAO's `min_quote_futarchic_liquidity` or `min_base_futachic_liquidity`",
     },
+    {
+      code: 6011,
+      name: "StreamAmountZero",
+      msg: "A stream must pay out a non-zero amount",
+    },
+    {
+      code: 6012,
+      name: "InvalidStreamDuration",
+      msg: "A stream's `end_timestamp` must be after its `start_timestamp`",
+    },
+    {
+      code: 6013,
+      name: "InsufficientTreasuryBalance",
+      msg: "The treasury doesn't have enough tokens to do this",
+    },
+    {
+      code: 6014,
+      name: "NothingToWithdraw",
+      msg: "Nothing has vested in this stream since the last withdrawal",
+    },
+    {
+      code: 6015,
+      name: "StreamAlreadyCancelled",
+      msg: "This stream has already been cancelled",
+    },
+    {
+      code: 6016,
+      name: "InvalidUpgradeAuthority",
+      msg: "The program's or buffer's upgrade authority isn't the DAO's treasury",
+    },
+    {
+      code: 6017,
+      name: "InvalidBuffer",
+      msg: "The buffer account isn't an initialized BPF loader buffer",
+    },
+    {
+      code: 6018,
+      name: "BufferHashMismatch",
+      msg: "The buffer's contents don't match the hash in the proposal",
+    },
+    {
+      code: 6019,
+      name: "OrderAmountZero",
+      msg: "A treasury order must swap a non-zero amount in non-zero slices",
+    },
+    {
+      code: 6020,
+      name: "InvalidOrderDuration",
+      msg: "A treasury order's `end_slot` must be after its `start_slot`",
+    },
+    {
+      code: 6021,
+      name: "InvalidOrderAmm",
+      msg: "Treasury orders can only trade the DAO's token against its USDC",
+    },
+    {
+      code: 6022,
+      name: "InvalidOrderInputAccount",
+      msg: "The treasury token account doesn't hold the order's input token",
+    },
+    {
+      code: 6023,
+      name: "InvalidOrderOutputAccount",
+      msg: "The treasury token account doesn't hold the order's output token",
+    },
+    {
+      code: 6024,
+      name: "InvalidLimitPrice",
+      msg: "The limit price would make the order's minimum output overflow or divide by zero",
+    },
+    {
+      code: 6025,
+      name: "OrderCancelled",
+      msg: "This treasury order has been cancelled",
+    },
+    {
+      code: 6026,
+      name: "OrderAlreadyCrankedThisSlot",
+      msg: "This treasury order has already been cranked this slot",
+    },
+    {
+      code: 6027,
+      name: "NothingToCrank",
+      msg: "No input has been released since this order was last cranked",
+    },
+    {
+      code: 6028,
+      name: "TooManyDelegatedProposers",
+      msg: "A DAO can't have more than `MAX_DELEGATED_PROPOSERS` delegated proposers",
+    },
+    {
+      code: 6029,
+      name: "DelegatedProposerCooldown",
+      msg: "This delegated proposer has to wait for its cooldown to pass before proposing again",
+    },
+    {
+      code: 6030,
+      name: "InvalidDao",
+      msg: "This account isn't a DAO",
+    },
+    {
+      code: 6031,
+      name: "DaoAlreadyMigrated",
+      msg: "This DAO has already been migrated",
+    },
+    {
+      code: 6032,
+      name: "TooManyActiveProposals",
+      msg: "This DAO already has `max_concurrent_proposals` proposals trading",
+    },
+    {
+      code: 6033,
+      name: "TooManyActiveProposalsForProposer",
+      msg: "This proposer already has `max_concurrent_proposals_per_proposer` proposals trading",
+    },
+    {
+      code: 6034,
+      name: "MissingProposerRecord",
+      msg: "The proposer's record must be passed in to count their trading proposals",
+    },
+    {
+      code: 6035,
+      name: "MissingPendingProposals",
+      msg: "To check for conflicts, every trading proposal of the DAO must be passed in as a remaining account",
+    },
+    {
+      code: 6036,
+      name: "InvalidPendingProposal",
+      msg: "A remaining account isn't a distinct trading proposal of this DAO",
+    },
+    {
+      code: 6037,
+      name: "ConflictingProposal",
+      msg: "This proposal writes to an account that a trading proposal also writes to",
+    },
+    {
+      code: 6038,
+      name: "TitleTooLong",
+      msg: "A proposal's title can't be longer than `MAX_TITLE_LENGTH` bytes",
+    },
+    {
+      code: 6039,
+      name: "DescriptionUrlTooLong",
+      msg: "A proposal's `description_url` can't be longer than `MAX_DESCRIPTION_URL_LENGTH` bytes",
+    },
+    {
+      code: 6040,
+      name: "ProposalNotResolved",
+      msg: "Only executed or failed proposals can be closed",
+    },
+    {
+      code: 6041,
+      name: "InvalidSpotMarket",
+      msg: "The spot market passed in isn't the one in the DAO's decision rule",
+    },
+    {
+      code: 6042,
+      name: "UnsupportedSpotMarket",
+      msg: "The spot market has to be an AMM or a Raydium CPMM pool for the DAO's token and USDC",
+    },
+    {
+      code: 6043,
+      name: "SpotTwapUnavailable",
+      msg: "The spot market's TWAP hasn't been updated since the proposal was created",
+    },
+    {
+      code: 6044,
+      name: "InvalidMarketAdmin",
+      msg: "The pass and fail markets can only be pausable by the DAO's treasury",
+    },
+    {
+      code: 6045,
+      name: "MarketPaused",
+      msg: "The pass and fail markets can't be paused when a proposal is created",
+    },
+    {
+      code: 6046,
+      name: "InvalidPosition",
+      msg: "A position passed in isn't the proposer's or isn't in the right market",
+    },
   ],
 };
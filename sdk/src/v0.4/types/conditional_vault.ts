@@ -10,6 +10,11 @@ export type ConditionalVault = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "questionRecord";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "payer";
           isMut: true;
@@ -86,6 +91,17 @@ export type ConditionalVault = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "questionRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["so we only count this vault if it does"];
+        },
+        {
+          name: "vaultRecord";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "underlyingTokenMint";
           isMut: false;
@@ -334,6 +350,97 @@ export type ConditionalVault = {
           };
         }
       ];
+    },
+    {
+      name: "closeConditionalVault";
+      accounts: [
+        {
+          name: "question";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "questionRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["and their vaults weren't counted in one"];
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultUnderlyingTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payerUnderlyingTokenAccount";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Receives any underlying tokens left over from rounding in redemptions"
+          ];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeQuestion";
+      accounts: [
+        {
+          name: "question";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "questionRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     }
   ];
   accounts: [
@@ -424,6 +531,58 @@ export type ConditionalVault = {
           }
         ];
       };
+    },
+    {
+      name: "questionRecord";
+      docs: [
+        "Lets a question be closed once it's resolved and all of its vaults have",
+        "been closed. Kept separate from `Question` so that questions created",
+        "before closing existed still deserialize. Those questions don't have a",
+        "record, so they can't be closed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "payer";
+            docs: [
+              "Receives the question's and this record's rent when they're closed."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "openVaultCount";
+            type: "u32";
+          },
+          {
+            name: "pdaBump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "vaultRecord";
+      docs: [
+        "Lets a vault be closed once all of its conditional tokens are gone. Like",
+        "`QuestionRecord`, vaults created before closing existed don't have one."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "payer";
+            docs: [
+              "Receives the vault's and this record's rent when they're closed."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "pdaBump";
+            type: "u8";
+          }
+        ];
+      };
     }
   ];
   types: [
@@ -832,6 +991,65 @@ export type ConditionalVault = {
           index: false;
         }
       ];
+    },
+    {
+      name: "CloseConditionalVaultEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "vault";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "question";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "payer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leftoverUnderlyingAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "seqNum";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CloseQuestionEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "question";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "payer";
+          type: "publicKey";
+          index: false;
+        }
+      ];
     }
   ];
   errors: [
@@ -914,6 +1132,21 @@ export type ConditionalVault = {
       code: 6015;
       name: "ConditionalTokenMetadataAlreadySet";
       msg: "Conditional token metadata already set";
+    },
+    {
+      code: 6016;
+      name: "QuestionNotResolved";
+      msg: "Question needs to be resolved before it or its vaults can be closed";
+    },
+    {
+      code: 6017;
+      name: "ConditionalTokensOutstanding";
+      msg: "A vault can't be closed while any of its conditional tokens are outstanding";
+    },
+    {
+      code: 6018;
+      name: "QuestionHasOpenVaults";
+      msg: "A question can't be closed while any of its vaults are open";
     }
   ];
 };
@@ -930,6 +1163,11 @@ export const IDL: ConditionalVault = {
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "questionRecord",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "payer",
           isMut: true,
@@ -1006,6 +1244,17 @@ export const IDL: ConditionalVault = {
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "questionRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["so we only count this vault if it does"],
+        },
+        {
+          name: "vaultRecord",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "underlyingTokenMint",
           isMut: false,
@@ -1255,6 +1504,97 @@ export const IDL: ConditionalVault = {
         },
       ],
     },
+    {
+      name: "closeConditionalVault",
+      accounts: [
+        {
+          name: "question",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "questionRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["and their vaults weren't counted in one"],
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultUnderlyingTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payerUnderlyingTokenAccount",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Receives any underlying tokens left over from rounding in redemptions",
+          ],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeQuestion",
+      accounts: [
+        {
+          name: "question",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "questionRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
   ],
   accounts: [
     {
@@ -1345,6 +1685,58 @@ export const IDL: ConditionalVault = {
         ],
       },
     },
+    {
+      name: "questionRecord",
+      docs: [
+        "Lets a question be closed once it's resolved and all of its vaults have",
+        "been closed. Kept separate from `Question` so that questions created",
+        "before closing existed still deserialize. Those questions don't have a",
+        "record, so they can't be closed.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "payer",
+            docs: [
+              "Receives the question's and this record's rent when they're closed.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "openVaultCount",
+            type: "u32",
+          },
+          {
+            name: "pdaBump",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "vaultRecord",
+      docs: [
+        "Lets a vault be closed once all of its conditional tokens are gone. Like",
+        "`QuestionRecord`, vaults created before closing existed don't have one.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "payer",
+            docs: [
+              "Receives the vault's and this record's rent when they're closed.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "pdaBump",
+            type: "u8",
+          },
+        ],
+      },
+    },
   ],
   types: [
     {
@@ -1753,6 +2145,65 @@ export const IDL: ConditionalVault = {
         },
       ],
     },
+    {
+      name: "CloseConditionalVaultEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "vault",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "question",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "payer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "leftoverUnderlyingAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "seqNum",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "CloseQuestionEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "question",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "payer",
+          type: "publicKey",
+          index: false,
+        },
+      ],
+    },
   ],
   errors: [
     {
@@ -1835,5 +2286,20 @@ export const IDL: ConditionalVault = {
       name: "ConditionalTokenMetadataAlreadySet",
       msg: "Conditional token metadata already set",
     },
+    {
+      code: 6016,
+      name: "QuestionNotResolved",
+      msg: "Question needs to be resolved before it or its vaults can be closed",
+    },
+    {
+      code: 6017,
+      name: "ConditionalTokensOutstanding",
+      msg: "A vault can't be closed while any of its conditional tokens are outstanding",
+    },
+    {
+      code: 6018,
+      name: "QuestionHasOpenVaults",
+      msg: "A question can't be closed while any of its vaults are open",
+    },
   ],
 };
@@ -28,12 +28,19 @@ export type ConditionalVault =
 export type InitializeDaoParams =
   IdlTypes<AutocratProgram>["InitializeDaoParams"];
 export type UpdateDaoParams = IdlTypes<AutocratProgram>["UpdateDaoParams"];
+export type UpdateDaoSettingsParams =
+  IdlTypes<AutocratProgram>["UpdateDaoSettingsParams"];
+export type ProposalCategory = IdlTypes<AutocratProgram>["ProposalCategory"];
 export type ProposalInstruction =
   IdlTypes<AutocratProgram>["ProposalInstruction"];
+export type SpotMarket = IdlTypes<AutocratProgram>["SpotMarket"];
 
 export type Dao = IdlAccounts<AutocratProgram>["dao"];
 export type Proposal = IdlAccounts<AutocratProgram>["proposal"];
 export type Amm = IdlAccounts<AmmProgram>["amm"];
+export type OrderBook = IdlAccounts<AmmProgram>["orderBook"];
+export type Batch = IdlAccounts<AmmProgram>["batch"];
+export type Position = IdlAccounts<AmmProgram>["position"];
 export type Launch = IdlAccounts<LaunchpadProgram>["launch"];
 export type FundingRecord = IdlAccounts<LaunchpadProgram>["fundingRecord"];
 
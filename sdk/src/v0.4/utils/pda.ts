@@ -59,6 +59,26 @@ export const getVaultAddr = (
   );
 };
 
+export const getQuestionRecordAddr = (
+  programId: PublicKey,
+  question: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("question_record"), question.toBuffer()],
+    programId
+  );
+};
+
+export const getVaultRecordAddr = (
+  programId: PublicKey,
+  vault: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("vault_record"), vault.toBuffer()],
+    programId
+  );
+};
+
 export const getConditionalTokenMintAddr = (
   programId: PublicKey,
   vault: PublicKey,
@@ -127,6 +147,31 @@ export const getProposalAddr = (
   );
 };
 
+export const getProposerRecordAddr = (
+  programId: PublicKey,
+  dao: PublicKey,
+  proposer: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("proposer_record"),
+      dao.toBuffer(),
+      proposer.toBuffer(),
+    ],
+    programId
+  );
+};
+
+export const getKeeperPoolAddr = (
+  programId: PublicKey,
+  dao: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("keeper_pool"), dao.toBuffer()],
+    programId
+  );
+};
+
 export const getAmmAddr = (
   programId: PublicKey,
   baseMint: PublicKey,
@@ -142,6 +187,83 @@ export const getAmmAddr = (
   );
 };
 
+export const getGlobalConfigAddr = (
+  programId: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("global_config")],
+    programId
+  );
+};
+
+export const getTraderRecordAddr = (
+  programId: PublicKey,
+  amm: PublicKey,
+  trader: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("trader_record"),
+      amm.toBuffer(),
+      trader.toBuffer(),
+    ],
+    programId
+  );
+};
+
+export const getBatchAddr = (
+  programId: PublicKey,
+  amm: PublicKey,
+  batchId: BN
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("batch"),
+      amm.toBuffer(),
+      batchId.toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+};
+
+export const getBatchOrderAddr = (
+  programId: PublicKey,
+  batch: PublicKey,
+  user: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("batch_order"), batch.toBuffer(), user.toBuffer()],
+    programId
+  );
+};
+
+export const getOrderBookAddr = (
+  programId: PublicKey,
+  amm: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("order_book"), amm.toBuffer()],
+    programId
+  );
+};
+
+export const getPositionAddr = (
+  programId: PublicKey,
+  amm: PublicKey,
+  owner: PublicKey,
+  nonce: BN
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("position"),
+      amm.toBuffer(),
+      owner.toBuffer(),
+      nonce.toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+};
+
 export const getAmmLpMintAddr = (
   programId: PublicKey,
   amm: PublicKey
@@ -2,6 +2,21 @@ import initializeAmm from "./unit/initializeAmm.test.js";
 import addLiquidity from "./unit/addLiquidity.test.js";
 import swap from "./unit/swap.test.js";
 import removeLiquidity from "./unit/removeLiquidity.test.js";
+import zapIn from "./unit/zapIn.test.js";
+import zapOut from "./unit/zapOut.test.js";
+import placeLimitOrder from "./unit/placeLimitOrder.test.js";
+import fillOrders from "./unit/fillOrders.test.js";
+import removeLimitOrder from "./unit/removeLimitOrder.test.js";
+import cancelExpiredLimitOrder from "./unit/cancelExpiredLimitOrder.test.js";
+import closeAmm from "./unit/closeAmm.test.js";
+import submitBatchOrder from "./unit/submitBatchOrder.test.js";
+import clearBatch from "./unit/clearBatch.test.js";
+import claimBatchOrder from "./unit/claimBatchOrder.test.js";
+import pauseAmm from "./unit/pauseAmm.test.js";
+import flashLoan from "./unit/flashLoan.test.js";
+import createPosition from "./unit/createPosition.test.js";
+import lockPosition from "./unit/lockPosition.test.js";
+import closePosition from "./unit/closePosition.test.js";
 import ammLifecycle from "./integration/ammLifecycle.test.js";
 import crankThatTwap from "./unit/crankThatTwap.test.js";
 
@@ -11,5 +26,20 @@ export default function suite() {
   describe("#swap", swap);
   describe("#crank_that_twap", crankThatTwap);
   describe("#remove_liquidity", removeLiquidity);
+  describe("#zap_in", zapIn);
+  describe("#zap_out", zapOut);
+  describe("#place_limit_order", placeLimitOrder);
+  describe("#fill_orders", fillOrders);
+  describe("#remove_limit_order", removeLimitOrder);
+  describe("#cancel_expired_limit_order", cancelExpiredLimitOrder);
+  describe("#close_amm", closeAmm);
+  describe("#submit_batch_order", submitBatchOrder);
+  describe("#clear_batch", clearBatch);
+  describe("#claim_batch_order", claimBatchOrder);
+  describe("#pause_amm", pauseAmm);
+  describe("#flash_loan", flashLoan);
+  describe("#create_position", createPosition);
+  describe("#lock_position", lockPosition);
+  describe("#close_position", closePosition);
   it("AMM lifecycle", ammLifecycle);
 }
@@ -2,6 +2,7 @@ import {
   AmmClient,
   getAmmAddr,
   getAmmLpMintAddr,
+  getPositionAddr,
 } from "@metadaoproject/futarchy/v0.4";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { assert } from "chai";
@@ -76,7 +77,9 @@ export default function suite() {
       )
     );
 
-    assert.equal(lpMint.amount.toString(), (5000 * 10 ** 6).toString());
+    // `MINIMUM_LIQUIDITY` LP tokens are locked on the first deposit
+    assert.equal(storedAmm.lockedLpTokens.toString(), "1000");
+    assert.equal(lpMint.amount.toString(), (5000 * 10 ** 6 - 1000).toString());
   });
 
   it("adds liquidity after it's already been added", async function () {
@@ -103,4 +106,88 @@ export default function suite() {
 
     // assert.equal(lpMint.amount.toString(), (7500 * 10 ** 6).toString());
   });
+
+  it("mints into a position and records what was deposited", async function () {
+    const [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+    const [position] = getPositionAddr(
+      ammClient.program.programId,
+      amm,
+      this.payer.publicKey,
+      new BN(0)
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(5000 * 10 ** 6),
+        new BN(6 * 10 ** 9),
+        new BN(0),
+        this.payer.publicKey,
+        position
+      )
+      .preInstructions([
+        await ammClient.createPositionIx(amm, new BN(0)).instruction(),
+      ])
+      .rpc();
+
+    const storedPosition = await ammClient.getPosition(position);
+    assert.equal(
+      storedPosition.lpTokens.toString(),
+      (5000 * 10 ** 6 - 1000).toString()
+    );
+    assert.equal(
+      storedPosition.baseDeposited.toString(),
+      (6 * 10 ** 9).toString()
+    );
+    assert.equal(
+      storedPosition.quoteDeposited.toString(),
+      (5000 * 10 ** 6).toString()
+    );
+
+    await this.assertBalance(lpMint, position, 5000 * 10 ** 6 - 1000);
+    await this.assertBalance(lpMint, this.payer.publicKey, 0);
+  });
+
+  it("fails when the position isn't the user's", async function () {
+    const otherUser = Keypair.generate();
+    const [position] = getPositionAddr(
+      ammClient.program.programId,
+      amm,
+      otherUser.publicKey,
+      new BN(0)
+    );
+
+    await ammClient
+      .createPositionIx(amm, new BN(0), otherUser.publicKey)
+      .preInstructions([
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: this.payer.publicKey,
+          toPubkey: otherUser.publicKey,
+          lamports: anchor.web3.LAMPORTS_PER_SOL,
+        }),
+      ])
+      .signers([otherUser])
+      .rpc();
+
+    const callbacks = expectError(
+      "ConstraintRaw",
+      "deposited into someone else's position"
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(5000 * 10 ** 6),
+        new BN(6 * 10 ** 9),
+        new BN(0),
+        this.payer.publicKey,
+        position
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
 }
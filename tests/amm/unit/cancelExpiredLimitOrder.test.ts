@@ -0,0 +1,141 @@
+import { AmmClient, getOrderBookAddr } from "@metadaoproject/futarchy/v0.4";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+// AMM prices are scaled by 1e12, and the pool starts at 1 USDC atom per META
+// atom, or 1,000 USDC per META
+const PRICE_SCALE = new BN(10).pow(new BN(12));
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let orderBook: PublicKey;
+  let owner: Keypair;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+    [orderBook] = getOrderBookAddr(ammClient.program.programId, amm);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    await ammClient.initializeOrderBookIx(amm, META, USDC).rpc();
+
+    // someone other than the payer owns the order, so that the payer can
+    // cancel it on their behalf
+    owner = Keypair.generate();
+    await this.createTokenAccount(META, owner.publicKey);
+    await this.createTokenAccount(USDC, owner.publicKey);
+    await this.mintTo(META, owner.publicKey, this.payer, 1 * 10 ** 9);
+
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        PRICE_SCALE.muln(2),
+        new BN(10),
+        owner.publicKey
+      )
+      .preInstructions([
+        // for the owner's trader record
+        SystemProgram.transfer({
+          fromPubkey: this.payer.publicKey,
+          toPubkey: owner.publicKey,
+          lamports: LAMPORTS_PER_SOL,
+        }),
+      ])
+      .signers([owner])
+      .rpc();
+  });
+
+  it("refunds an expired order to its owner", async function () {
+    await this.advanceBySlots(11n);
+
+    await ammClient
+      .cancelExpiredLimitOrderIx(amm, META, USDC, new BN(0), owner.publicKey)
+      .rpc();
+
+    const storedOrderBook = await ammClient.getOrderBook(orderBook);
+    assert.equal(storedOrderBook.orders.length, 0);
+
+    await this.assertBalance(META, owner.publicKey, 1 * 10 ** 9);
+    await this.assertBalance(META, orderBook, 0);
+  });
+
+  it("fails before the order expires", async function () {
+    await this.advanceBySlots(10n);
+
+    const callbacks = expectError(
+      "LimitOrderNotExpired",
+      "cancelled an order that hadn't expired"
+    );
+
+    await ammClient
+      .cancelExpiredLimitOrderIx(amm, META, USDC, new BN(0), owner.publicKey)
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the owner passed in isn't the order's", async function () {
+    await this.advanceBySlots(11n);
+
+    const callbacks = expectError(
+      "ConstraintOwner",
+      "refunded an order to someone who doesn't own it"
+    );
+
+    await ammClient
+      .cancelExpiredLimitOrderIx(
+        amm,
+        META,
+        USDC,
+        new BN(0),
+        this.payer.publicKey
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
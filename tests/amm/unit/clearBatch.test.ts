@@ -0,0 +1,205 @@
+import {
+  AmmClient,
+  PriceMath,
+  getAmmAddr,
+  getBatchAddr,
+  getBatchOrderAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+const BATCH_AUCTION_SLOTS = 10n;
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let batchId: BN;
+  let batch: PublicKey;
+  let batchOrder: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    const [twapFirstObservation, twapMaxObservationChangePerUpdate] =
+      PriceMath.getAmmPrices(9, 6, 500, 10);
+
+    await ammClient
+      .initializeAmmIx(
+        META,
+        USDC,
+        twapFirstObservation,
+        twapMaxObservationChangePerUpdate,
+        { batchAuctionSlots: new BN(BATCH_AUCTION_SLOTS.toString()) }
+      )
+      .rpc();
+    [amm] = getAmmAddr(ammClient.program.programId, META, USDC);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    batchId = await getCurrentBatchId(this.banksClient);
+    [batch] = getBatchAddr(ammClient.program.programId, amm, batchId);
+    [batchOrder] = getBatchOrderAddr(
+      ammClient.program.programId,
+      batch,
+      this.payer.publicKey
+    );
+  });
+
+  async function getCurrentBatchId(banksClient: any): Promise<BN> {
+    const { slot } = await banksClient.getClock();
+    const { createdAtSlot } = await ammClient.getAmm(amm);
+    const slotsSinceCreation = slot - BigInt(createdAtSlot.toString());
+
+    return new BN((slotsSinceCreation / BATCH_AUCTION_SLOTS).toString());
+  }
+
+  it("clears a batch at one price once it ends", async function () {
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        batchId,
+        { buy: {} },
+        new BN(100 * 10 ** 6),
+        new BN(0)
+      )
+      .rpc();
+
+    await this.advanceBySlots(BATCH_AUCTION_SLOTS);
+
+    await ammClient.clearBatchIx(amm, batchId, [batchOrder]).rpc();
+
+    const storedBatch = await ammClient.getBatch(batch);
+    assert.isNotNull(storedBatch.clearingPrice);
+    assert.isTrue(storedBatch.baseOwed.gtn(0));
+    assert.equal(storedBatch.quoteOwed.toString(), "0");
+
+    // only buys were submitted, so all of them trade against the reserves
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.equal(
+      storedAmm.quoteAmount.toString(),
+      (10_100 * 10 ** 6).toString()
+    );
+    // what's owed is capped at what the pool gave up
+    assert.isTrue(
+      storedAmm.baseAmount.lte(new BN(10 * 10 ** 9).sub(storedBatch.baseOwed))
+    );
+    assert.equal(storedAmm.swapCount.toString(), "1");
+  });
+
+  it("refunds orders that would clear below their minimum output", async function () {
+    // 100 USDC can't buy 1 META at ~1,000 USDC per META
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        batchId,
+        { buy: {} },
+        new BN(100 * 10 ** 6),
+        new BN(1 * 10 ** 9)
+      )
+      .rpc();
+
+    await this.advanceBySlots(BATCH_AUCTION_SLOTS);
+
+    await ammClient.clearBatchIx(amm, batchId, [batchOrder]).rpc();
+
+    const storedBatchOrder = await ammClient.program.account.batchOrder.fetch(
+      batchOrder
+    );
+    assert.isTrue(storedBatchOrder.refunded);
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.equal(
+      storedAmm.quoteAmount.toString(),
+      (10_000 * 10 ** 6).toString()
+    );
+    assert.equal(storedAmm.swapCount.toString(), "0");
+  });
+
+  it("fails before the batch ends", async function () {
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        batchId,
+        { buy: {} },
+        new BN(100 * 10 ** 6),
+        new BN(0)
+      )
+      .rpc();
+
+    const callbacks = expectError(
+      "BatchNotEnded",
+      "cleared a batch that was still open"
+    );
+
+    await ammClient
+      .clearBatchIx(amm, batchId, [batchOrder])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails without every order in the batch", async function () {
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        batchId,
+        { buy: {} },
+        new BN(100 * 10 ** 6),
+        new BN(0)
+      )
+      .rpc();
+
+    await this.advanceBySlots(BATCH_AUCTION_SLOTS);
+
+    const callbacks = expectError(
+      "InvalidBatchOrders",
+      "cleared a batch without all of its orders"
+    );
+
+    await ammClient
+      .clearBatchIx(amm, batchId, [])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
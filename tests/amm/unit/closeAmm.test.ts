@@ -0,0 +1,183 @@
+import {
+  AmmClient,
+  getAmmLpMintAddr,
+  getOrderBookAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+// AMM prices are scaled by 1e12, and the pool starts at 1 USDC atom per META
+// atom, or 1,000 USDC per META
+const PRICE_SCALE = new BN(10).pow(new BN(12));
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 10_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  async function removeAllLiquidity() {
+    await ammClient
+      .removeLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6 - 1000),
+        new BN(0),
+        new BN(0)
+      )
+      .rpc();
+  }
+
+  it("closes a drained AMM and sends the locked reserves to its payer", async function () {
+    await removeAllLiquidity();
+
+    await ammClient.closeAmmIx(amm, META, USDC).rpc();
+
+    assert.isNull(await ammClient.fetchAmm(amm));
+
+    // the LPs' withdrawals plus the locked reserves are everything deposited
+    await this.assertBalance(META, this.payer.publicKey, 100 * 10 ** 9);
+    await this.assertBalance(USDC, this.payer.publicKey, 10_000 * 10 ** 6);
+  });
+
+  it("refunds the orders left in the AMM's order book", async function () {
+    const [orderBook] = getOrderBookAddr(ammClient.program.programId, amm);
+    await ammClient.initializeOrderBookIx(amm, META, USDC).rpc();
+
+    const owner = Keypair.generate();
+    await this.createTokenAccount(META, owner.publicKey);
+    await this.createTokenAccount(USDC, owner.publicKey);
+    await this.mintTo(META, owner.publicKey, this.payer, 1 * 10 ** 9);
+
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        PRICE_SCALE.muln(2),
+        new BN(100),
+        owner.publicKey
+      )
+      .preInstructions([
+        // for the owner's trader record
+        SystemProgram.transfer({
+          fromPubkey: this.payer.publicKey,
+          toPubkey: owner.publicKey,
+          lamports: LAMPORTS_PER_SOL,
+        }),
+      ])
+      .signers([owner])
+      .rpc();
+
+    await removeAllLiquidity();
+
+    await ammClient.closeAmmIx(amm, META, USDC, [owner.publicKey]).rpc();
+
+    assert.isNull(await ammClient.fetchAmm(amm));
+
+    await this.assertBalance(META, owner.publicKey, 1 * 10 ** 9);
+    await this.assertBalance(META, orderBook, 0);
+
+    const storedOrderBook = await ammClient.getOrderBook(orderBook);
+    assert.equal(storedOrderBook.orders.length, 0);
+  });
+
+  it("fails when an order's owner isn't passed in", async function () {
+    await ammClient.initializeOrderBookIx(amm, META, USDC).rpc();
+
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        PRICE_SCALE.muln(2),
+        new BN(100)
+      )
+      .rpc();
+
+    await removeAllLiquidity();
+
+    const callbacks = expectError(
+      "MissingOrderOwnerAccount",
+      "closed an AMM without refunding an order"
+    );
+
+    // someone else's accounts, so the order's owner is missing
+    const other = Keypair.generate().publicKey;
+    await this.createTokenAccount(META, other);
+    await this.createTokenAccount(USDC, other);
+
+    await ammClient
+      .closeAmmIx(amm, META, USDC, [other])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails while LP tokens are outstanding", async function () {
+    const callbacks = expectError(
+      "LpTokensOutstanding",
+      "closed an AMM that still had liquidity"
+    );
+
+    await ammClient
+      .closeAmmIx(amm, META, USDC)
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+
+    const [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+    await this.assertBalance(
+      lpMint,
+      this.payer.publicKey,
+      10_000 * 10 ** 6 - 1000
+    );
+  });
+}
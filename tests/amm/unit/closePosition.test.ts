@@ -0,0 +1,120 @@
+import {
+  AmmClient,
+  getAmmLpMintAddr,
+  getPositionAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let lpMint: PublicKey;
+  let position: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+    [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+    [position] = getPositionAddr(
+      ammClient.program.programId,
+      amm,
+      this.payer.publicKey,
+      new BN(0)
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0),
+        this.payer.publicKey,
+        position
+      )
+      .preInstructions([
+        await ammClient.createPositionIx(amm, new BN(0)).instruction(),
+      ])
+      .rpc();
+  });
+
+  it("gives the LP tokens back and closes the position", async function () {
+    await ammClient.closePositionIx(amm, position).rpc();
+
+    assert.isNull(
+      await ammClient.program.account.position.fetchNullable(position)
+    );
+
+    await this.assertBalance(
+      lpMint,
+      this.payer.publicKey,
+      10_000 * 10 ** 6 - 1000
+    );
+  });
+
+  it("fails while the position is locked", async function () {
+    const locker = Keypair.generate();
+
+    await ammClient
+      .lockPositionIx(amm, position, locker.publicKey)
+      .signers([locker])
+      .rpc();
+
+    const callbacks = expectError(
+      "PositionLocked",
+      "closed a locked position"
+    );
+
+    await ammClient
+      .closePositionIx(amm, position)
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+
+    await this.assertBalance(lpMint, position, 10_000 * 10 ** 6 - 1000);
+  });
+
+  it("fails when the signer isn't the owner", async function () {
+    const notOwner = Keypair.generate();
+    await this.createTokenAccount(lpMint, notOwner.publicKey);
+
+    const callbacks = expectError(
+      "ConstraintHasOne",
+      "closed someone else's position"
+    );
+
+    await ammClient
+      .closePositionIx(amm, position, notOwner.publicKey)
+      .signers([notOwner])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
@@ -0,0 +1,125 @@
+import {
+  AmmClient,
+  getAmmLpMintAddr,
+  getPositionAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let lpMint: PublicKey;
+  let position: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+    [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+    [position] = getPositionAddr(
+      ammClient.program.programId,
+      amm,
+      this.payer.publicKey,
+      new BN(0)
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0),
+        this.payer.publicKey,
+        position
+      )
+      .preInstructions([
+        await ammClient.createPositionIx(amm, new BN(0)).instruction(),
+      ])
+      .rpc();
+  });
+
+  it("creates an empty position for the owner", async function () {
+    const [secondPosition] = getPositionAddr(
+      ammClient.program.programId,
+      amm,
+      this.payer.publicKey,
+      new BN(1)
+    );
+
+    const { slot } = await this.banksClient.getClock();
+
+    await ammClient.createPositionIx(amm, new BN(1)).rpc();
+
+    const storedPosition = await ammClient.getPosition(secondPosition);
+    assert.ok(storedPosition.amm.equals(amm));
+    assert.ok(storedPosition.owner.equals(this.payer.publicKey));
+    assert.equal(storedPosition.nonce.toString(), "1");
+    assert.equal(storedPosition.lpTokens.toString(), "0");
+    assert.equal(storedPosition.baseDeposited.toString(), "0");
+    assert.equal(storedPosition.quoteDeposited.toString(), "0");
+    assert.equal(storedPosition.depositSlot.toString(), slot.toString());
+    assert.isNull(storedPosition.locker);
+
+    await this.assertBalance(lpMint, secondPosition, 0);
+  });
+
+  it("adds later deposits to the position", async function () {
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(5_000 * 10 ** 6),
+        new BN(6 * 10 ** 9),
+        new BN(1),
+        this.payer.publicKey,
+        position
+      )
+      .rpc();
+
+    // the second deposit's base is rounded up in the pool's favor
+    const storedPosition = await ammClient.getPosition(position);
+    assert.equal(
+      storedPosition.quoteDeposited.toString(),
+      (15_000 * 10 ** 6).toString()
+    );
+    assert.equal(
+      storedPosition.baseDeposited.toString(),
+      (15 * 10 ** 9 + 1).toString()
+    );
+    assert.equal(
+      storedPosition.lpTokens.toString(),
+      (15_000 * 10 ** 6 - 1000).toString()
+    );
+
+    await this.assertBalance(lpMint, position, 15_000 * 10 ** 6 - 1000);
+  });
+}
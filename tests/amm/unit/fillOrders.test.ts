@@ -0,0 +1,208 @@
+import { AmmClient, getOrderBookAddr } from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+
+// AMM prices are scaled by 1e12, and the pool starts at 1 USDC atom per META
+// atom, or 1,000 USDC per META
+const PRICE_SCALE = new BN(10).pow(new BN(12));
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let orderBook: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+    [orderBook] = getOrderBookAddr(ammClient.program.programId, amm);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    await ammClient.initializeOrderBookIx(amm, META, USDC).rpc();
+  });
+
+  it("fills a sell order the pool has crossed and pays out its owner", async function () {
+    // at least 500 USDC per META
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        PRICE_SCALE.divn(2),
+        new BN(100)
+      )
+      .rpc();
+
+    const usdcStart = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient
+      .fillOrdersIx(amm, META, USDC, [
+        getAssociatedTokenAddressSync(USDC, this.payer.publicKey),
+      ])
+      .rpc();
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.equal(storedAmm.baseAmount.toString(), (11 * 10 ** 9).toString());
+    assert.equal(storedAmm.swapCount.toString(), "1");
+
+    // the order was paid out, so it's gone from the book
+    const storedOrderBook = await ammClient.getOrderBook(orderBook);
+    assert.equal(storedOrderBook.orders.length, 0);
+
+    // the payer is both the owner and the filler, so it gets the bounty too
+    const usdcReceived =
+      (await this.getTokenBalance(USDC, this.payer.publicKey)) - usdcStart;
+    assert.equal(
+      usdcReceived.toString(),
+      new BN(10_000 * 10 ** 6).sub(storedAmm.quoteAmount).toString()
+    );
+    assert.isTrue(usdcReceived > BigInt(500 * 10 ** 6));
+
+    await this.assertBalance(META, orderBook, 0);
+    await this.assertBalance(USDC, orderBook, 0);
+  });
+
+  it("fills a buy order the pool has crossed", async function () {
+    // at most 2,000 USDC per META
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        USDC,
+        { buy: {} },
+        new BN(500 * 10 ** 6),
+        PRICE_SCALE.muln(2),
+        new BN(100)
+      )
+      .rpc();
+
+    const metaStart = await this.getTokenBalance(META, this.payer.publicKey);
+
+    await ammClient
+      .fillOrdersIx(amm, META, USDC, [
+        getAssociatedTokenAddressSync(META, this.payer.publicKey),
+      ])
+      .rpc();
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.equal(
+      storedAmm.quoteAmount.toString(),
+      (10_500 * 10 ** 6).toString()
+    );
+
+    const metaReceived =
+      (await this.getTokenBalance(META, this.payer.publicKey)) - metaStart;
+    assert.equal(
+      metaReceived.toString(),
+      new BN(10 * 10 ** 9).sub(storedAmm.baseAmount).toString()
+    );
+  });
+
+  it("keeps filled orders whose owner's account isn't passed in", async function () {
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        PRICE_SCALE.divn(2),
+        new BN(100)
+      )
+      .rpc();
+
+    await ammClient.fillOrdersIx(amm, META, USDC).rpc();
+
+    const [order] = (await ammClient.getOrderBook(orderBook)).orders;
+    assert.isNotNull(order.outputAmount);
+
+    // the order's output, net of the bounty, waits in the order book
+    await this.assertBalance(USDC, orderBook, order.outputAmount.toNumber());
+    await this.assertBalance(META, orderBook, 0);
+  });
+
+  it("leaves orders the pool hasn't crossed", async function () {
+    // at least 2,000 USDC per META
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        PRICE_SCALE.muln(2),
+        new BN(100)
+      )
+      .rpc();
+
+    const callbacks = expectError(
+      "NoOrdersFilled",
+      "filled an order past its limit price"
+    );
+
+    await ammClient
+      .fillOrdersIx(amm, META, USDC)
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+
+    const [order] = (await ammClient.getOrderBook(orderBook)).orders;
+    assert.isNull(order.outputAmount);
+  });
+
+  it("doesn't fill expired orders", async function () {
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        PRICE_SCALE.divn(2),
+        new BN(10)
+      )
+      .rpc();
+
+    await this.advanceBySlots(11n);
+
+    const callbacks = expectError("NoOrdersFilled", "filled an expired order");
+
+    await ammClient
+      .fillOrdersIx(amm, META, USDC)
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
@@ -0,0 +1,187 @@
+import { AmmClient } from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  function repayIx() {
+    return ammClient.repayFlashLoanIx(amm, META, USDC).instruction();
+  }
+
+  it("lends out reserves and adds the fees to them on repayment", async function () {
+    await ammClient
+      .flashLoanIx(
+        amm,
+        META,
+        USDC,
+        new BN(1 * 10 ** 9),
+        new BN(1_000 * 10 ** 6)
+      )
+      .postInstructions([await repayIx()])
+      .rpc();
+
+    // 10 bps of each loan
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.isNull(storedAmm.flashLoan);
+    assert.equal(
+      storedAmm.baseAmount.toString(),
+      (10 * 10 ** 9 + 1 * 10 ** 6).toString()
+    );
+    assert.equal(
+      storedAmm.quoteAmount.toString(),
+      (10_000 * 10 ** 6 + 1 * 10 ** 6).toString()
+    );
+
+    await this.assertBalance(META, amm, 10 * 10 ** 9 + 1 * 10 ** 6);
+    await this.assertBalance(USDC, amm, 10_000 * 10 ** 6 + 1 * 10 ** 6);
+    await this.assertBalance(
+      META,
+      this.payer.publicKey,
+      90 * 10 ** 9 - 1 * 10 ** 6
+    );
+  });
+
+  it("allows swapping between the loan and its repayment", async function () {
+    const swapIx = await ammClient
+      .swapIx(amm, META, USDC, { sell: {} }, new BN(1 * 10 ** 9), new BN(1))
+      .instruction();
+
+    await ammClient
+      .flashLoanIx(amm, META, USDC, new BN(0), new BN(5_000 * 10 ** 6))
+      .postInstructions([swapIx, await repayIx()])
+      .rpc();
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.isNull(storedAmm.flashLoan);
+    assert.equal(storedAmm.swapCount.toString(), "1");
+
+    // the vaults still back the reserves exactly
+    await this.assertBalance(META, amm, storedAmm.baseAmount.toNumber());
+    await this.assertBalance(USDC, amm, storedAmm.quoteAmount.toNumber());
+  });
+
+  it("allows two loans one after the other", async function () {
+    const secondLoanIx = await ammClient
+      .flashLoanIx(amm, META, USDC, new BN(0), new BN(1_000 * 10 ** 6))
+      .instruction();
+
+    await ammClient
+      .flashLoanIx(amm, META, USDC, new BN(0), new BN(1_000 * 10 ** 6))
+      .postInstructions([await repayIx(), secondLoanIx, await repayIx()])
+      .rpc();
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.isNull(storedAmm.flashLoan);
+    assert.equal(
+      storedAmm.quoteAmount.toString(),
+      (10_000 * 10 ** 6 + 2 * 10 ** 6).toString()
+    );
+  });
+
+  it("fails when a second loan is taken before the first is repaid", async function () {
+    const secondLoanIx = await ammClient
+      .flashLoanIx(amm, META, USDC, new BN(0), new BN(1_000 * 10 ** 6))
+      .instruction();
+
+    const callbacks = expectError(
+      "FlashLoanOutstanding",
+      "took out a loan on top of an outstanding one"
+    );
+
+    await ammClient
+      .flashLoanIx(amm, META, USDC, new BN(0), new BN(1_000 * 10 ** 6))
+      .postInstructions([secondLoanIx, await repayIx(), await repayIx()])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the loan isn't repaid", async function () {
+    const callbacks = expectError(
+      "FlashLoanNotRepaid",
+      "took out a loan without repaying it"
+    );
+
+    await ammClient
+      .flashLoanIx(amm, META, USDC, new BN(0), new BN(1_000 * 10 ** 6))
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+
+    await this.assertBalance(USDC, amm, 10_000 * 10 ** 6);
+  });
+
+  it("fails when the repayment doesn't have the AMM where expected", async function () {
+    const misorderedRepayIx = await repayIx();
+    const [repayer, ammMeta] = misorderedRepayIx.keys;
+    misorderedRepayIx.keys[0] = ammMeta;
+    misorderedRepayIx.keys[1] = repayer;
+
+    const callbacks = expectError(
+      "FlashLoanNotRepaid",
+      "accepted a repayment for some other account"
+    );
+
+    await ammClient
+      .flashLoanIx(amm, META, USDC, new BN(0), new BN(1_000 * 10 ** 6))
+      .postInstructions([misorderedRepayIx])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails to borrow more than the reserves", async function () {
+    const callbacks = expectError(
+      "InvalidFlashLoanAmount",
+      "lent out more than the AMM has"
+    );
+
+    await ammClient
+      .flashLoanIx(amm, META, USDC, new BN(11 * 10 ** 9), new BN(0))
+      .postInstructions([await repayIx()])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
@@ -0,0 +1,162 @@
+import {
+  AmmClient,
+  getAmmLpMintAddr,
+  getPositionAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let lpMint: PublicKey;
+  let position: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+    [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+    [position] = getPositionAddr(
+      ammClient.program.programId,
+      amm,
+      this.payer.publicKey,
+      new BN(0)
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0),
+        this.payer.publicKey,
+        position
+      )
+      .preInstructions([
+        await ammClient.createPositionIx(amm, new BN(0)).instruction(),
+      ])
+      .rpc();
+  });
+
+  it("locks and unlocks a position", async function () {
+    const locker = Keypair.generate();
+
+    await ammClient
+      .lockPositionIx(amm, position, locker.publicKey)
+      .signers([locker])
+      .rpc();
+
+    let storedPosition = await ammClient.getPosition(position);
+    assert.ok(storedPosition.locker.equals(locker.publicKey));
+
+    await ammClient
+      .unlockPositionIx(amm, position, locker.publicKey)
+      .signers([locker])
+      .rpc();
+
+    storedPosition = await ammClient.getPosition(position);
+    assert.isNull(storedPosition.locker);
+  });
+
+  it("fails when the position is already locked", async function () {
+    const locker = Keypair.generate();
+    const otherLocker = Keypair.generate();
+
+    await ammClient
+      .lockPositionIx(amm, position, locker.publicKey)
+      .signers([locker])
+      .rpc();
+
+    const callbacks = expectError(
+      "PositionLocked",
+      "locked a position that was already locked"
+    );
+
+    await ammClient
+      .lockPositionIx(amm, position, otherLocker.publicKey)
+      .signers([otherLocker])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the signer isn't the owner", async function () {
+    const notOwner = Keypair.generate();
+    const locker = Keypair.generate();
+
+    const callbacks = expectError(
+      "ConstraintHasOne",
+      "locked someone else's position"
+    );
+
+    await ammClient
+      .lockPositionIx(amm, position, locker.publicKey, notOwner.publicKey)
+      .signers([notOwner, locker])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails to unlock when the signer isn't the locker", async function () {
+    const locker = Keypair.generate();
+    const notLocker = Keypair.generate();
+
+    await ammClient
+      .lockPositionIx(amm, position, locker.publicKey)
+      .signers([locker])
+      .rpc();
+
+    const callbacks = expectError(
+      "InvalidLocker",
+      "someone other than the locker unlocked the position"
+    );
+
+    await ammClient
+      .unlockPositionIx(amm, position, notLocker.publicKey)
+      .signers([notLocker])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails to unlock a position that isn't locked", async function () {
+    const locker = Keypair.generate();
+
+    const callbacks = expectError(
+      "PositionNotLocked",
+      "unlocked a position that wasn't locked"
+    );
+
+    await ammClient
+      .unlockPositionIx(amm, position, locker.publicKey)
+      .signers([locker])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
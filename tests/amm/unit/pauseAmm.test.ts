@@ -0,0 +1,190 @@
+import {
+  AmmClient,
+  PriceMath,
+  getAmmAddr,
+  getAmmLpMintAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    const [twapFirstObservation, twapMaxObservationChangePerUpdate] =
+      PriceMath.getAmmPrices(9, 6, 500, 10);
+
+    await ammClient
+      .initializeAmmIx(
+        META,
+        USDC,
+        twapFirstObservation,
+        twapMaxObservationChangePerUpdate,
+        { admin: this.payer.publicKey }
+      )
+      .rpc();
+    [amm] = getAmmAddr(ammClient.program.programId, META, USDC);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  it("halts swaps and deposits but not withdrawals", async function () {
+    const { slot } = await this.banksClient.getClock();
+
+    await ammClient.pauseAmmIx(amm).rpc();
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.equal(storedAmm.pausedAtSlot.toString(), slot.toString());
+
+    let callbacks = expectError("AmmPaused", "swapped while paused");
+    await ammClient
+      .swapIx(amm, META, USDC, { sell: {} }, new BN(1 * 10 ** 9), new BN(1))
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+
+    callbacks = expectError("AmmPaused", "added liquidity while paused");
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(1_000 * 10 ** 6),
+        new BN(2 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+
+    await ammClient
+      .removeLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(1_000 * 10 ** 6),
+        new BN(0),
+        new BN(0)
+      )
+      .rpc();
+
+    const [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+    await this.assertBalance(
+      lpMint,
+      this.payer.publicKey,
+      9_000 * 10 ** 6 - 1000
+    );
+  });
+
+  it("resumes trading and skips the paused slots once unpaused", async function () {
+    await ammClient.pauseAmmIx(amm).rpc();
+
+    await this.advanceBySlots(100n);
+
+    await ammClient.unpauseAmmIx(amm).rpc();
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.isNull(storedAmm.pausedAtSlot);
+    assert.equal(storedAmm.totalPausedSlots.toString(), "100");
+
+    await ammClient
+      .swapIx(amm, META, USDC, { sell: {} }, new BN(1 * 10 ** 9), new BN(1))
+      .rpc();
+  });
+
+  it("fails when the AMM is already paused", async function () {
+    await ammClient.pauseAmmIx(amm).rpc();
+
+    await this.advanceBySlots(1n);
+
+    const callbacks = expectError("AmmPaused", "paused an AMM twice");
+
+    await ammClient
+      .pauseAmmIx(amm)
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails to unpause an AMM that isn't paused", async function () {
+    const callbacks = expectError(
+      "AmmNotPaused",
+      "unpaused an AMM that wasn't paused"
+    );
+
+    await ammClient
+      .unpauseAmmIx(amm)
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the signer isn't the admin", async function () {
+    const notAdmin = Keypair.generate();
+
+    const callbacks = expectError(
+      "InvalidAdmin",
+      "someone other than the admin paused the AMM"
+    );
+
+    await ammClient
+      .pauseAmmIx(amm, notAdmin.publicKey)
+      .signers([notAdmin])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the AMM has no admin", async function () {
+    const otherMeta = await this.createMint(this.payer.publicKey, 9);
+
+    const adminlessAmm = await ammClient.createAmm(
+      Keypair.generate().publicKey,
+      otherMeta,
+      USDC,
+      500
+    );
+
+    const callbacks = expectError(
+      "InvalidAdmin",
+      "paused an AMM that can't be paused"
+    );
+
+    await ammClient
+      .pauseAmmIx(adminlessAmm)
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
@@ -0,0 +1,251 @@
+import {
+  AmmClient,
+  getOrderBookAddr,
+  getTraderRecordAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+// AMM prices are scaled by 1e12, and the pool starts at 1 USDC atom per META
+// atom, or 1,000 USDC per META
+const PRICE_SCALE = new BN(10).pow(new BN(12));
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let orderBook: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+    [orderBook] = getOrderBookAddr(ammClient.program.programId, amm);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    await ammClient.initializeOrderBookIx(amm, META, USDC).rpc();
+  });
+
+  it("escrows a sell order's input in the order book", async function () {
+    const { slot } = await this.banksClient.getClock();
+
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        PRICE_SCALE.muln(2),
+        new BN(100)
+      )
+      .rpc();
+
+    const storedOrderBook = await ammClient.getOrderBook(orderBook);
+    assert.equal(storedOrderBook.orders.length, 1);
+    assert.equal(storedOrderBook.nextOrderId.toString(), "1");
+
+    const [order] = storedOrderBook.orders;
+    assert.equal(order.id.toString(), "0");
+    assert.ok(order.owner.equals(this.payer.publicKey));
+    assert.exists(order.swapType.sell);
+    assert.equal(order.inputAmount.toString(), (1 * 10 ** 9).toString());
+    assert.equal(order.limitPrice.toString(), PRICE_SCALE.muln(2).toString());
+    assert.isNull(order.outputAmount);
+    assert.equal(order.expiresAtSlot.toString(), (slot + 100n).toString());
+
+    await this.assertBalance(META, this.payer.publicKey, 89 * 10 ** 9);
+    await this.assertBalance(META, orderBook, 1 * 10 ** 9);
+  });
+
+  it("escrows a buy order's input in the order book", async function () {
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        USDC,
+        { buy: {} },
+        new BN(500 * 10 ** 6),
+        PRICE_SCALE.divn(2),
+        new BN(100)
+      )
+      .rpc();
+
+    const [order] = (await ammClient.getOrderBook(orderBook)).orders;
+    assert.exists(order.swapType.buy);
+
+    await this.assertBalance(USDC, this.payer.publicKey, 9_500 * 10 ** 6);
+    await this.assertBalance(USDC, orderBook, 500 * 10 ** 6);
+  });
+
+  it("counts the order's owner as a trader", async function () {
+    for (let i = 0; i < 2; i++) {
+      await ammClient
+        .placeLimitOrderIx(
+          amm,
+          META,
+          { sell: {} },
+          new BN(1 * 10 ** 9 + i),
+          PRICE_SCALE.muln(2),
+          new BN(100)
+        )
+        .rpc();
+    }
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.equal(storedAmm.traderCount.toString(), "1");
+
+    const traderRecord = await ammClient.program.account.traderRecord.fetch(
+      getTraderRecordAddr(
+        ammClient.program.programId,
+        amm,
+        this.payer.publicKey
+      )[0]
+    );
+    assert.ok(traderRecord.trader.equals(this.payer.publicKey));
+  });
+
+  it("fails when the order never or only far in the future expires", async function () {
+    for (const expiresInSlots of [new BN(0), new BN(150 * 60 * 24 + 1)]) {
+      const callbacks = expectError(
+        "InvalidLimitOrderExpiry",
+        "placed an order with an invalid expiry"
+      );
+
+      await ammClient
+        .placeLimitOrderIx(
+          amm,
+          META,
+          { sell: {} },
+          new BN(1 * 10 ** 9),
+          PRICE_SCALE.muln(2),
+          expiresInSlots
+        )
+        .rpc()
+        .then(callbacks[0], callbacks[1]);
+    }
+  });
+
+  it("fails when the input mint doesn't match the side", async function () {
+    const callbacks = expectError(
+      "InvalidLimitOrder",
+      "placed a sell order with quote tokens"
+    );
+
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        USDC,
+        { sell: {} },
+        new BN(500 * 10 ** 6),
+        PRICE_SCALE.muln(2),
+        new BN(100)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the order is too small", async function () {
+    const callbacks = expectError(
+      "LimitOrderTooSmall",
+      "placed an order for less than the minimum share of the reserves"
+    );
+
+    // 0.1% of the base reserve is 0.01 META
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(0.001 * 10 ** 9),
+        PRICE_SCALE.muln(2),
+        new BN(100)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the owner already has too many orders", async function () {
+    for (let i = 0; i < 4; i++) {
+      await ammClient
+        .placeLimitOrderIx(
+          amm,
+          META,
+          { sell: {} },
+          new BN(1 * 10 ** 9 + i),
+          PRICE_SCALE.muln(2),
+          new BN(100)
+        )
+        .rpc();
+    }
+
+    const callbacks = expectError(
+      "TooManyLimitOrders",
+      "placed more orders than one owner is allowed"
+    );
+
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1 * 10 ** 9 + 4),
+        PRICE_SCALE.muln(2),
+        new BN(100)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the user doesn't have enough tokens", async function () {
+    const callbacks = expectError(
+      "InsufficientBalance",
+      "placed an order for more than the user has"
+    );
+
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1_000 * 10 ** 9),
+        PRICE_SCALE.muln(2),
+        new BN(100)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
@@ -0,0 +1,128 @@
+import { AmmClient, getOrderBookAddr } from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+// AMM prices are scaled by 1e12, and the pool starts at 1 USDC atom per META
+// atom, or 1,000 USDC per META
+const PRICE_SCALE = new BN(10).pow(new BN(12));
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let orderBook: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+    [orderBook] = getOrderBookAddr(ammClient.program.programId, amm);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    await ammClient.initializeOrderBookIx(amm, META, USDC).rpc();
+
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        PRICE_SCALE.divn(2),
+        new BN(100)
+      )
+      .rpc();
+  });
+
+  it("returns an unfilled order's input", async function () {
+    await ammClient.removeLimitOrderIx(amm, META, USDC, new BN(0)).rpc();
+
+    const storedOrderBook = await ammClient.getOrderBook(orderBook);
+    assert.equal(storedOrderBook.orders.length, 0);
+
+    await this.assertBalance(META, this.payer.publicKey, 90 * 10 ** 9);
+    await this.assertBalance(META, orderBook, 0);
+  });
+
+  it("returns a filled order's output", async function () {
+    await ammClient.fillOrdersIx(amm, META, USDC).rpc();
+
+    const [order] = (await ammClient.getOrderBook(orderBook)).orders;
+    const usdcStart = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient.removeLimitOrderIx(amm, META, USDC, new BN(0)).rpc();
+
+    const storedOrderBook = await ammClient.getOrderBook(orderBook);
+    assert.equal(storedOrderBook.orders.length, 0);
+
+    await this.assertBalance(
+      USDC,
+      this.payer.publicKey,
+      Number(usdcStart) + order.outputAmount.toNumber()
+    );
+    await this.assertBalance(USDC, orderBook, 0);
+  });
+
+  it("fails when the order isn't the user's", async function () {
+    const otherUser = Keypair.generate();
+    await this.createTokenAccount(META, otherUser.publicKey);
+    await this.createTokenAccount(USDC, otherUser.publicKey);
+
+    const callbacks = expectError(
+      "ConstraintOwner",
+      "removed someone else's order"
+    );
+
+    await ammClient
+      .removeLimitOrderIx(amm, META, USDC, new BN(0), otherUser.publicKey)
+      .signers([otherUser])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the order doesn't exist", async function () {
+    const callbacks = expectError(
+      "LimitOrderNotFound",
+      "removed an order that was never placed"
+    );
+
+    await ammClient
+      .removeLimitOrderIx(amm, META, USDC, new BN(1))
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
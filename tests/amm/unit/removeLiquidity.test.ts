@@ -160,4 +160,40 @@ export default function suite() {
       ammStart.quoteAmount.toNumber()
     );
   });
+
+  it("leaves the locked minimum liquidity's reserves in the pool", async function () {
+    const ammStart = await ammClient.getAmm(amm);
+
+    const userLpAccount = await getAccount(
+      this.banksClient,
+      getAssociatedTokenAddressSync(ammStart.lpMint, this.payer.publicKey)
+    );
+
+    await ammClient
+      .removeLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(userLpAccount.amount.toString()),
+        new BN(0),
+        new BN(0)
+      )
+      .rpc();
+
+    const lpMintEnd = await getMint(this.banksClient, ammStart.lpMint);
+    const ammEnd = await ammClient.getAmm(amm);
+
+    assert.equal(lpMintEnd.supply.toString(), "0");
+    assert.equal(ammEnd.lockedLpTokens.toString(), "1000");
+
+    // the pool started with 1000 USDC and 2 META, and the 1000 locked LP
+    // tokens are 1 / 1,000,000 of the LP supply
+    assert.equal(ammEnd.quoteAmount.toString(), "1000");
+    assert.equal(ammEnd.baseAmount.toString(), "2000");
+
+    // so the pool can still be traded against
+    await ammClient
+      .swapIx(amm, META, USDC, { buy: {} }, new BN(100), new BN(1))
+      .rpc();
+  });
 }
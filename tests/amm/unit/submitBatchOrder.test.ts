@@ -0,0 +1,218 @@
+import {
+  AmmClient,
+  PriceMath,
+  getAmmAddr,
+  getBatchAddr,
+  getBatchOrderAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+const BATCH_AUCTION_SLOTS = 10n;
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    const [twapFirstObservation, twapMaxObservationChangePerUpdate] =
+      PriceMath.getAmmPrices(9, 6, 500, 10);
+
+    await ammClient
+      .initializeAmmIx(
+        META,
+        USDC,
+        twapFirstObservation,
+        twapMaxObservationChangePerUpdate,
+        { batchAuctionSlots: new BN(BATCH_AUCTION_SLOTS.toString()) }
+      )
+      .rpc();
+    [amm] = getAmmAddr(ammClient.program.programId, META, USDC);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  async function getCurrentBatchId(banksClient: any): Promise<BN> {
+    const { slot } = await banksClient.getClock();
+    const { createdAtSlot } = await ammClient.getAmm(amm);
+    const slotsSinceCreation = slot - BigInt(createdAtSlot.toString());
+
+    return new BN((slotsSinceCreation / BATCH_AUCTION_SLOTS).toString());
+  }
+
+  it("adds to the user's order in the current batch", async function () {
+    const batchId = await getCurrentBatchId(this.banksClient);
+
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        batchId,
+        { buy: {} },
+        new BN(100 * 10 ** 6),
+        new BN(0)
+      )
+      .rpc();
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        batchId,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    const [batch] = getBatchAddr(ammClient.program.programId, amm, batchId);
+    const storedBatch = await ammClient.getBatch(batch);
+    assert.equal(storedBatch.id.toString(), batchId.toString());
+    assert.equal(
+      storedBatch.totalQuoteIn.toString(),
+      (100 * 10 ** 6).toString()
+    );
+    assert.equal(storedBatch.totalBaseIn.toString(), (1 * 10 ** 9).toString());
+    assert.equal(storedBatch.unclaimedOrderCount, 1);
+    assert.isNull(storedBatch.clearingPrice);
+    assert.ok(storedBatch.payer.equals(this.payer.publicKey));
+
+    const storedBatchOrder = await ammClient.program.account.batchOrder.fetch(
+      getBatchOrderAddr(
+        ammClient.program.programId,
+        batch,
+        this.payer.publicKey
+      )[0]
+    );
+    assert.equal(
+      storedBatchOrder.quoteIn.toString(),
+      (100 * 10 ** 6).toString()
+    );
+    assert.equal(storedBatchOrder.baseIn.toString(), (1 * 10 ** 9).toString());
+
+    // the input sits in the vaults but stays out of the reserves until the
+    // batch clears
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.equal(
+      storedAmm.quoteAmount.toString(),
+      (10_000 * 10 ** 6).toString()
+    );
+    assert.equal(storedAmm.baseAmount.toString(), (10 * 10 ** 9).toString());
+    await this.assertBalance(USDC, amm, 10_100 * 10 ** 6);
+    await this.assertBalance(META, amm, 11 * 10 ** 9);
+
+    assert.equal(storedAmm.unclaimedBatchOrders.toString(), "1");
+    assert.equal(storedAmm.traderCount.toString(), "1");
+  });
+
+  it("fails when the batch isn't the current one", async function () {
+    const batchId = await getCurrentBatchId(this.banksClient);
+
+    const callbacks = expectError(
+      "BatchClosed",
+      "submitted an order to a batch other than the current one"
+    );
+
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        batchId.addn(1),
+        { buy: {} },
+        new BN(100 * 10 ** 6),
+        new BN(0)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the AMM isn't in batch auction mode", async function () {
+    const otherMeta = await this.createMint(this.payer.publicKey, 9);
+    await this.createTokenAccount(otherMeta, this.payer.publicKey);
+
+    const continuousAmm = await ammClient.createAmm(
+      Keypair.generate().publicKey,
+      otherMeta,
+      USDC,
+      500
+    );
+
+    const callbacks = expectError(
+      "NotInBatchAuctionMode",
+      "submitted a batch order to an AMM without batches"
+    );
+
+    await ammClient
+      .submitBatchOrderIx(
+        continuousAmm,
+        otherMeta,
+        USDC,
+        new BN(0),
+        { buy: {} },
+        new BN(100 * 10 ** 6),
+        new BN(0)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the user doesn't have enough tokens", async function () {
+    const batchId = await getCurrentBatchId(this.banksClient);
+
+    const callbacks = expectError(
+      "InsufficientBalance",
+      "submitted an order for more than the user has"
+    );
+
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        batchId,
+        { buy: {} },
+        new BN(1_000_000 * 10 ** 6),
+        new BN(0)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
@@ -3,6 +3,7 @@ import {
   AmmMath,
   getAmmAddr,
   getAmmLpMintAddr,
+  getTraderRecordAddr,
 } from "@metadaoproject/futarchy/v0.4";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { assert } from "chai";
@@ -147,6 +148,26 @@ export default function suite() {
     });
   });
 
+  it("counts a trader once no matter how many swaps they make", async function () {
+    await ammClient.swap(amm, { sell: {} }, 1, 1);
+    await ammClient.swap(amm, { sell: {} }, 0.5, 1);
+    await ammClient.swap(amm, { buy: {} }, 100, 0.0001);
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.equal(storedAmm.swapCount.toString(), "3");
+    assert.equal(storedAmm.traderCount.toString(), "1");
+
+    const traderRecord = await ammClient.program.account.traderRecord.fetch(
+      getTraderRecordAddr(
+        ammClient.program.programId,
+        amm,
+        this.payer.publicKey
+      )[0]
+    );
+    assert.ok(traderRecord.amm.equals(amm));
+    assert.ok(traderRecord.trader.equals(this.payer.publicKey));
+  });
+
   it("swap base to quote and back, should not be profitable", async function () {
     const permissionlessAmmStart = await ammClient.program.account.amm.fetch(
       amm
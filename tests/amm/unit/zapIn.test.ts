@@ -0,0 +1,250 @@
+import {
+  AmmClient,
+  getAmmLpMintAddr,
+  getPositionAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  it("zaps in with only quote tokens", async function () {
+    const [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+
+    const ammStart = await ammClient.getAmm(amm);
+    const lpStart = await this.getTokenBalance(lpMint, this.payer.publicKey);
+    const metaStart = await this.getTokenBalance(META, this.payer.publicKey);
+    const usdcStart = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient
+      .zapInIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(1_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc();
+
+    const ammEnd = await ammClient.getAmm(amm);
+    const lpEnd = await this.getTokenBalance(lpMint, this.payer.publicKey);
+    const metaEnd = await this.getTokenBalance(META, this.payer.publicKey);
+    const usdcEnd = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    const usdcSpent = usdcStart - usdcEnd;
+
+    // everything the user spent ends up in the pool, and no more than the input
+    assert.isTrue(lpEnd > lpStart);
+    assert.isTrue(usdcSpent <= BigInt(1_000 * 10 ** 6));
+    assert.isTrue(usdcSpent > BigInt(999 * 10 ** 6));
+    assert.equal(
+      ammEnd.quoteAmount.sub(ammStart.quoteAmount).toString(),
+      usdcSpent.toString()
+    );
+    assert.equal(
+      ammEnd.baseAmount.sub(ammStart.baseAmount).toString(),
+      (-(metaEnd - metaStart)).toString()
+    );
+
+    assert.equal(ammEnd.swapCount.toString(), "1");
+    assert.equal(ammEnd.traderCount.toString(), "1");
+    assert.equal(
+      ammEnd.seqNum.toString(),
+      ammStart.seqNum.addn(1).toString()
+    );
+  });
+
+  it("zaps in with only base tokens", async function () {
+    const [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+
+    const ammStart = await ammClient.getAmm(amm);
+    const metaStart = await this.getTokenBalance(META, this.payer.publicKey);
+
+    await ammClient
+      .zapInIx(
+        amm,
+        META,
+        USDC,
+        { sell: {} },
+        new BN(1 * 10 ** 9),
+        new BN(1)
+      )
+      .rpc();
+
+    const ammEnd = await ammClient.getAmm(amm);
+    const metaEnd = await this.getTokenBalance(META, this.payer.publicKey);
+
+    assert.isTrue(metaStart - metaEnd <= BigInt(1 * 10 ** 9));
+    assert.isTrue(ammEnd.baseAmount.gt(ammStart.baseAmount));
+    assert.isTrue(ammEnd.quoteAmount.gte(ammStart.quoteAmount));
+    assert.isTrue(
+      (await this.getTokenBalance(lpMint, this.payer.publicKey)) >
+        BigInt(10_000 * 10 ** 6 - 1000)
+    );
+  });
+
+  it("mints into a position and records what was deposited", async function () {
+    const [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+    const [position] = getPositionAddr(
+      ammClient.program.programId,
+      amm,
+      this.payer.publicKey,
+      new BN(0)
+    );
+
+    const lpStart = await this.getTokenBalance(lpMint, this.payer.publicKey);
+    const metaStart = await this.getTokenBalance(META, this.payer.publicKey);
+    const usdcStart = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient
+      .zapInIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(1_000 * 10 ** 6),
+        new BN(1),
+        this.payer.publicKey,
+        position
+      )
+      .preInstructions([
+        await ammClient.createPositionIx(amm, new BN(0)).instruction(),
+      ])
+      .rpc();
+
+    const metaEnd = await this.getTokenBalance(META, this.payer.publicKey);
+    const usdcEnd = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    const storedPosition = await ammClient.getPosition(position);
+    assert.isTrue(storedPosition.lpTokens.gtn(0));
+    await this.assertBalance(
+      lpMint,
+      position,
+      storedPosition.lpTokens.toNumber()
+    );
+    await this.assertBalance(lpMint, this.payer.publicKey, Number(lpStart));
+
+    // part of the input was swapped into base tokens first, so the position
+    // records the two sides of the deposit rather than the input
+    const usdcSpent = new BN((usdcStart - usdcEnd).toString());
+    assert.isTrue(storedPosition.quoteDeposited.gtn(0));
+    assert.isTrue(storedPosition.quoteDeposited.lt(usdcSpent));
+    assert.isTrue(storedPosition.baseDeposited.gtn(0));
+
+    // and whatever base didn't fit in the deposit was sent back
+    assert.isTrue(metaEnd >= metaStart);
+  });
+
+  it("fails when the user would get fewer LP tokens than they want", async function () {
+    const callbacks = expectError(
+      "AddLiquiditySlippageExceeded",
+      "zapped in even though the LP tokens were below the minimum"
+    );
+
+    await ammClient
+      .zapInIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(1_000 * 10 ** 6),
+        new BN(1_000 * 10 ** 6)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the pool has no liquidity", async function () {
+    const otherMeta = await this.createMint(this.payer.publicKey, 9);
+    await this.createTokenAccount(otherMeta, this.payer.publicKey);
+
+    const emptyAmm = await ammClient.createAmm(
+      Keypair.generate().publicKey,
+      otherMeta,
+      USDC,
+      500
+    );
+
+    const callbacks = expectError(
+      "NoReserves",
+      "zapped into a pool without liquidity"
+    );
+
+    await ammClient
+      .zapInIx(
+        emptyAmm,
+        otherMeta,
+        USDC,
+        { buy: {} },
+        new BN(1_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the user doesn't have enough tokens", async function () {
+    const callbacks = expectError(
+      "InsufficientBalance",
+      "zapped in more than the user has"
+    );
+
+    await ammClient
+      .zapInIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(1_000_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
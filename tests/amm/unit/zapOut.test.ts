@@ -0,0 +1,156 @@
+import { AmmClient, getAmmLpMintAddr } from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let lpMint: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 10_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+    [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  it("zaps out into only quote tokens", async function () {
+    const ammStart = await ammClient.getAmm(amm);
+    const metaStart = await this.getTokenBalance(META, this.payer.publicKey);
+    const usdcStart = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient
+      .zapOutIx(
+        amm,
+        META,
+        USDC,
+        { sell: {} },
+        new BN(1_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc();
+
+    const ammEnd = await ammClient.getAmm(amm);
+    const usdcReceived =
+      (await this.getTokenBalance(USDC, this.payer.publicKey)) - usdcStart;
+
+    // the withdrawn base tokens are swapped back into the pool
+    await this.assertBalance(META, this.payer.publicKey, Number(metaStart));
+    assert.equal(ammEnd.baseAmount.toString(), ammStart.baseAmount.toString());
+    assert.equal(
+      ammStart.quoteAmount.sub(ammEnd.quoteAmount).toString(),
+      usdcReceived.toString()
+    );
+
+    // a tenth of the pool's quote and less than another tenth from the swap
+    assert.isTrue(usdcReceived > BigInt(1_000 * 10 ** 6));
+    assert.isTrue(usdcReceived < BigInt(2_000 * 10 ** 6));
+
+    await this.assertBalance(
+      lpMint,
+      this.payer.publicKey,
+      9_000 * 10 ** 6 - 1000
+    );
+
+    assert.equal(ammEnd.swapCount.toString(), "1");
+    assert.equal(ammEnd.traderCount.toString(), "1");
+  });
+
+  it("zaps out into only base tokens", async function () {
+    const usdcStart = await this.getTokenBalance(USDC, this.payer.publicKey);
+    const metaStart = await this.getTokenBalance(META, this.payer.publicKey);
+
+    await ammClient
+      .zapOutIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(1_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc();
+
+    const metaReceived =
+      (await this.getTokenBalance(META, this.payer.publicKey)) - metaStart;
+
+    await this.assertBalance(USDC, this.payer.publicKey, Number(usdcStart));
+    assert.isTrue(metaReceived > BigInt(1 * 10 ** 9));
+    assert.isTrue(metaReceived < BigInt(2 * 10 ** 9));
+  });
+
+  it("fails when the user would get less than they want", async function () {
+    const callbacks = expectError(
+      "SwapSlippageExceeded",
+      "zapped out even though the output was below the minimum"
+    );
+
+    await ammClient
+      .zapOutIx(
+        amm,
+        META,
+        USDC,
+        { sell: {} },
+        new BN(1_000 * 10 ** 6),
+        new BN(2_000 * 10 ** 6)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails when the user doesn't have enough LP tokens", async function () {
+    const callbacks = expectError(
+      "InsufficientBalance",
+      "zapped out more LP tokens than the user has"
+    );
+
+    await ammClient
+      .zapOutIx(
+        amm,
+        META,
+        USDC,
+        { sell: {} },
+        new BN(10_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
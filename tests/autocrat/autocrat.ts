@@ -192,6 +192,9 @@ export default function suite() {
       assert(storedDao.usdcMint.equals(USDC));
       assert.equal(storedDao.proposalCount, 0);
       assert.equal(storedDao.passThresholdBps, 300);
+      assert.equal(storedDao.seqNum.toString(), "0");
+      assert.deepEqual(storedDao.delegatedProposers, []);
+      assert.deepEqual(storedDao.decisionRule, { passOverFail: {} });
 
       treasuryMetaAccount = await createAssociatedTokenAccount(
         banksClient,
@@ -236,6 +239,20 @@ export default function suite() {
     });
   });
 
+  describe("#migrate_dao", async function () {
+    it("doesn't migrate an up-to-date DAO", async function () {
+      const callbacks = expectError(
+        "DaoAlreadyMigrated",
+        "migrated a DAO that was already migrated"
+      );
+
+      await autocratClient
+        .migrateDaoIx(dao)
+        .rpc()
+        .then(callbacks[0], callbacks[1]);
+    });
+  });
+
   describe("#initialize_proposal", async function () {
     it("initializes proposals", async function () {
       const accounts = [